@@ -0,0 +1,27 @@
+pub mod part1;
+pub mod part2;
+
+use crate::error::Error;
+use crate::parse;
+use crate::solution::Solution;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i64>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    // O(N) time, O(N) space
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(parse::integers(text)?)
+    }
+
+    fn part1(&self, changes: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(changes))
+    }
+
+    fn part2(&self, changes: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(changes)
+    }
+}
@@ -0,0 +1,61 @@
+use crate::error::Error;
+use std::collections::HashSet;
+
+/// Returns the first frequency reached twice while applying the changes over
+/// and over.  If a full pass over the changes has a net drift of zero, the
+/// starting frequency is repeated at the end of the first pass; otherwise,
+/// the search ends once a pass produces nothing new.
+// O(N * P) time, O(N * P) space, where P is the number of passes
+pub fn solve(changes: &[i64]) -> Result<i64, Error> {
+    if changes.is_empty() {
+        return Err(Error::EmptyInput);
+    }
+    let drift: i64 = changes.iter().sum();
+    let mut frequency = 0;
+    let mut seen = HashSet::new();
+    seen.insert(frequency);
+    let first: Vec<i64> = changes
+        .iter()
+        .map(|change| {
+            frequency += change;
+            frequency
+        })
+        .collect();
+    if let Some(&repeat) = first.iter().find(|&&f| !seen.insert(f)) {
+        return Ok(repeat);
+    }
+    if drift == 0 {
+        return Ok(0);
+    }
+    // After the first pass, every frequency is a first-pass frequency shifted
+    // by a multiple of the drift.  The first repeat is the pair of first-pass
+    // frequencies in the same residue class, separated by the fewest passes.
+    // The starting frequency counts as seen, too.
+    let mut best: Option<(i64, usize, i64)> = None; // (passes, index, value)
+    for (i, &start) in first.iter().enumerate() {
+        for &target in first.iter().chain(Some(&0)) {
+            let gap = target - start;
+            if target != start && gap % drift == 0 && gap / drift > 0 {
+                let candidate = (gap / drift, i, target);
+                if best.is_none_or(|b| candidate < b) {
+                    best = Some(candidate);
+                }
+            }
+        }
+    }
+    best.map(|(_, _, value)| value).ok_or(Error::NoSolution)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn samples() {
+        assert_eq!(0, solve(&[1, -1]).unwrap());
+        assert_eq!(10, solve(&[3, 3, 4, -2, -4]).unwrap());
+        assert_eq!(5, solve(&[-6, 3, 8, 5, -6]).unwrap());
+        assert_eq!(14, solve(&[7, 7, -2, -7, -4]).unwrap());
+        assert!(solve(&[1, 1]).is_err());
+    }
+}
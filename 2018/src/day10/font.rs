@@ -0,0 +1,147 @@
+//! The letters lights spell out in the sky.  Each is six columns wide and ten
+//! rows tall, and letters are two columns apart.  Only the letters that have
+//! been seen in puzzle input are known.
+
+use super::Sky;
+use crate::error::Error;
+
+const WIDTH: i64 = 6;
+const HEIGHT: i64 = 10;
+const SPACING: i64 = 2;
+
+#[rustfmt::skip]
+const GLYPHS: &[(char, [&str; HEIGHT as usize])] = &[
+    (
+        'B',
+        [
+            "#####.",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#####.",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#####.",
+        ],
+    ),
+    (
+        'F',
+        [
+            "######",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#####.",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+        ],
+    ),
+    (
+        'G',
+        [
+            ".####.",
+            "#....#",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#..###",
+            "#....#",
+            "#....#",
+            "#...##",
+            ".###.#",
+        ],
+    ),
+    (
+        'H',
+        [
+            "#....#",
+            "#....#",
+            "#....#",
+            "#....#",
+            "######",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#....#",
+        ],
+    ),
+    (
+        'N',
+        [
+            "#....#",
+            "##...#",
+            "##...#",
+            "#.#..#",
+            "#.#..#",
+            "#..#.#",
+            "#..#.#",
+            "#...##",
+            "#...##",
+            "#....#",
+        ],
+    ),
+    (
+        'P',
+        [
+            "#####.",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#####.",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+        ],
+    ),
+    (
+        'R',
+        [
+            "#####.",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#####.",
+            "#..#..",
+            "#...#.",
+            "#...#.",
+            "#....#",
+            "#....#",
+        ],
+    ),
+];
+
+/// Returns the letter drawn in the sky starting at the specified column, if
+/// it is one we know.
+fn recognize(sky: &Sky, left: i64) -> Option<char> {
+    let matches = |rows: &[&str]| {
+        rows.iter().zip(0..).all(|(row, y)| {
+            row.bytes()
+                .zip(left..)
+                .all(|(pixel, x)| (pixel == b'#') == sky.is_lit(x, y))
+        })
+    };
+    GLYPHS
+        .iter()
+        .find(|(_, rows)| matches(rows))
+        .map(|&(letter, _)| letter)
+}
+
+/// Returns the message spelled out in the specified sky.
+pub fn read(sky: &Sky) -> Result<String, Error> {
+    let unreadable = || Error::internal(format!("unrecognized message:\n{}", sky));
+    if sky.height() != HEIGHT || (sky.width() + SPACING) % (WIDTH + SPACING) != 0 {
+        return Err(unreadable());
+    }
+    let count = (sky.width() + SPACING) / (WIDTH + SPACING);
+    (0..count)
+        .map(|i| recognize(sky, i * (WIDTH + SPACING)).ok_or_else(unreadable))
+        .collect()
+}
@@ -0,0 +1,70 @@
+use crate::error::ParseError;
+use crate::parse;
+use std::str::FromStr;
+
+/// A point of light, such as `position=< 9,  1> velocity=< 0,  2>`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Light {
+    pub position: (i64, i64),
+    pub velocity: (i64, i64),
+}
+
+impl Light {
+    /// Returns where this light will be after the specified number of seconds.
+    pub fn at(&self, time: i64) -> (i64, i64) {
+        let (x, y) = self.position;
+        let (dx, dy) = self.velocity;
+        (x + dx * time, y + dy * time)
+    }
+}
+
+/// Parses a pair written as `<X, Y>`, following the specified label.
+fn parse_pair<'a>(s: &'a str, label: &str) -> Result<((i64, i64), &'a str), ParseError> {
+    let bad = || {
+        let what = format!("bad light: expected {}=<X, Y>", label);
+        ParseError::new(what).with_span(0..s.len())
+    };
+    let rest = s
+        .strip_prefix(label)
+        .and_then(|rest| rest.strip_prefix("=<"))
+        .ok_or_else(bad)?;
+    let (pair, rest) = match rest.find('>') {
+        Some(i) => (&rest[..i], &rest[(i + 1)..]),
+        None => return Err(bad()),
+    };
+    let (x, y) = match pair.find(',') {
+        Some(i) => (&pair[..i], &pair[(i + 1)..]),
+        None => return Err(bad()),
+    };
+    let number = |part| parse::integer(part).map_err(|err| err.within(s, part));
+    Ok(((number(x)?, number(y)?), rest))
+}
+
+impl FromStr for Light {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (position, rest) = parse_pair(s, "position")?;
+        let trimmed = rest.trim_start();
+        let (velocity, _) =
+            parse_pair(trimmed, "velocity").map_err(|err| err.within(s, trimmed))?;
+        Ok(Light { position, velocity })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_light() {
+        let light: Light = "position=<-3, 11> velocity=< 1, -2>".parse().unwrap();
+        assert_eq!((-3, 11), light.position);
+        assert_eq!((1, -2), light.velocity);
+        assert_eq!((-1, 7), light.at(2));
+        let err = "position=<-3, 11> velocity=< 1, x2>"
+            .parse::<Light>()
+            .unwrap_err();
+        assert_eq!(Some(32..34), err.span());
+    }
+}
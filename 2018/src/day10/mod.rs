@@ -0,0 +1,64 @@
+mod font;
+mod light;
+mod sky;
+
+pub mod part1;
+pub mod part2;
+
+pub use light::Light;
+pub use sky::Sky;
+
+use crate::error::Error;
+use crate::parse;
+use crate::solution::Solution;
+
+/// Returns the time at which the lights are closest together vertically,
+/// which is when they spell out a message.  The height of the lights'
+/// bounding box shrinks until then, and grows afterward.
+// O(N log T) time, O(1) space, where T is the time of the message
+fn converge(lights: &[Light]) -> Result<i64, Error> {
+    if lights.is_empty() {
+        return Err(Error::EmptyInput);
+    }
+    let height = |time: i64| {
+        let ys = lights.iter().map(|light| light.at(time).1);
+        ys.clone().max().unwrap_or(0) - ys.min().unwrap_or(0)
+    };
+    // Gallop forward while the height keeps shrinking, then binary search for
+    // the turning point within the last stride.
+    let mut step = 1;
+    while height(step) < height(step / 2) {
+        step *= 2;
+    }
+    let (mut low, mut high) = (step / 4, step);
+    while low < high {
+        let middle = (low + high) / 2;
+        if height(middle + 1) < height(middle) {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+    Ok(low)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Light>;
+    type Output1 = String;
+    type Output2 = i64;
+
+    // O(N) time, O(N) space
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(parse::lines(text, str::parse)?)
+    }
+
+    fn part1(&self, lights: &Self::Input) -> Result<Self::Output1, Error> {
+        part1::solve(lights)
+    }
+
+    fn part2(&self, lights: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(lights)
+    }
+}
@@ -0,0 +1,38 @@
+use super::{converge, font, Light, Sky};
+use crate::error::Error;
+
+/// Returns the message the lights spell out when they converge.
+// O(N log T) time, O(N) space, where T is the time of the message
+pub fn solve(lights: &[Light]) -> Result<String, Error> {
+    let sky = Sky::at(lights, converge(lights)?);
+    font::read(&sky)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day10::Day10;
+    use crate::solution::Solution;
+    use std::fs;
+
+    const SAMPLE_MESSAGE: &str = "\
+#...#..###
+#...#...#.
+#...#...#.
+#####...#.
+#...#...#.
+#...#...#.
+#...#...#.
+#...#..###
+";
+
+    #[test]
+    fn sample() {
+        let text = fs::read_to_string("tests/day10/sample1").unwrap();
+        let lights = Day10.parse(&text).unwrap();
+        let sky = Sky::at(&lights, converge(&lights).unwrap());
+        assert_eq!(SAMPLE_MESSAGE, sky.to_string());
+        // The sample is drawn in a smaller font than real input.
+        assert!(solve(&lights).is_err());
+    }
+}
@@ -0,0 +1,8 @@
+use super::{converge, Light};
+use crate::error::Error;
+
+/// Returns the number of seconds until the message appears.
+// O(N log T) time, O(1) space, where T is the time of the message
+pub fn solve(lights: &[Light]) -> Result<i64, Error> {
+    converge(lights)
+}
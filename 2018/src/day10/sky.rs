@@ -0,0 +1,57 @@
+use super::Light;
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+
+/// The lights in the sky at a moment in time.
+pub struct Sky {
+    points: HashSet<(i64, i64)>,
+    left: i64,
+    top: i64,
+    width: i64,
+    height: i64,
+}
+
+impl Sky {
+    /// Returns the lights as they will be at the specified time.  There must
+    /// be at least one light.
+    pub fn at(lights: &[Light], time: i64) -> Sky {
+        let points: HashSet<_> = lights.iter().map(|light| light.at(time)).collect();
+        let xs = points.iter().map(|p| p.0);
+        let ys = points.iter().map(|p| p.1);
+        let (left, right) = (xs.clone().min().unwrap_or(0), xs.max().unwrap_or(0));
+        let (top, bottom) = (ys.clone().min().unwrap_or(0), ys.max().unwrap_or(0));
+        Sky {
+            points,
+            left,
+            top,
+            width: right - left + 1,
+            height: bottom - top + 1,
+        }
+    }
+
+    pub fn height(&self) -> i64 {
+        self.height
+    }
+
+    pub fn width(&self) -> i64 {
+        self.width
+    }
+
+    /// Returns true if there is a light at the specified column and row,
+    /// relative to the top left corner of the sky.
+    pub fn is_lit(&self, column: i64, row: i64) -> bool {
+        self.points.contains(&(self.left + column, self.top + row))
+    }
+}
+
+impl Display for Sky {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for row in 0..self.height {
+            let line: String = (0..self.width)
+                .map(|column| if self.is_lit(column, row) { '#' } else { '.' })
+                .collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
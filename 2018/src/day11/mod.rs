@@ -0,0 +1,57 @@
+mod table;
+
+pub mod part1;
+pub mod part2;
+
+pub use table::PowerTable;
+
+use crate::error::Error;
+use crate::parse;
+use crate::solution::Solution;
+use std::fmt::{self, Display, Formatter};
+
+/// The width and height of the grid of fuel cells.
+pub const EXTENT: usize = 300;
+
+/// The top left fuel cell of a square, optionally with the square's size.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Square {
+    pub x: usize,
+    pub y: usize,
+    pub size: Option<usize>,
+}
+
+impl Display for Square {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)?;
+        if let Some(size) = self.size {
+            write!(f, ",{}", size)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = PowerTable;
+    type Output1 = Square;
+    type Output2 = Square;
+
+    // O(E²) time, O(E²) space, where E is the extent of the grid
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        let serial = parse::lines(text, parse::integer)?
+            .into_iter()
+            .next()
+            .ok_or(Error::EmptyInput)?;
+        Ok(PowerTable::new(serial))
+    }
+
+    fn part1(&self, table: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(table))
+    }
+
+    fn part2(&self, table: &Self::Input) -> Result<Self::Output2, Error> {
+        Ok(part2::solve(table))
+    }
+}
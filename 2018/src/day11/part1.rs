@@ -0,0 +1,25 @@
+use super::{PowerTable, Square};
+
+/// The size of the squares to consider.
+const SIZE: usize = 3;
+
+/// Returns the top left cell of the 3x3 square having the most power.
+// O(E²) time, O(1) space, where E is the extent of the grid
+pub fn solve(table: &PowerTable) -> Square {
+    let (square, _) = table.best(SIZE);
+    Square {
+        size: None,
+        ..square
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn samples() {
+        assert_eq!("33,45", solve(&PowerTable::new(18)).to_string());
+        assert_eq!("21,61", solve(&PowerTable::new(42)).to_string());
+    }
+}
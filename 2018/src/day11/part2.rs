@@ -0,0 +1,15 @@
+use super::{PowerTable, Square, EXTENT};
+
+/// Returns the top left cell and size of the square, of any size, having the
+/// most power.
+// O(E³) time, O(1) space, where E is the extent of the grid
+pub fn solve(table: &PowerTable) -> Square {
+    let mut best = table.best(1);
+    for size in 2..=EXTENT {
+        let candidate = table.best(size);
+        if candidate.1 > best.1 {
+            best = candidate;
+        }
+    }
+    best.0
+}
@@ -0,0 +1,94 @@
+use super::{Square, EXTENT};
+use crate::grid::{Grid, Position};
+
+/// Returns the power level of the fuel cell at the specified 1-based
+/// coordinates, in a grid with the specified serial number.
+pub fn cell_power(x: usize, y: usize, serial: i64) -> i64 {
+    let rack = x as i64 + 10;
+    let power = (rack * y as i64 + serial) * rack;
+    power / 100 % 10 - 5
+}
+
+/// A summed-area table of fuel cell power levels: each entry holds the total
+/// power of the rectangle from the top left corner through that cell, so the
+/// power of any square takes constant time to compute.
+pub struct PowerTable {
+    sums: Grid<i64>, // (EXTENT + 1)², with a zero first row and column
+}
+
+impl PowerTable {
+    pub fn new(serial: i64) -> PowerTable {
+        let mut sums = Grid::new(EXTENT + 1, EXTENT + 1, 0);
+        for y in 1..=EXTENT {
+            for x in 1..=EXTENT {
+                sums[Position::new(y, x)] = cell_power(x, y, serial) + PowerTable::sum(&sums, x, y);
+            }
+        }
+        PowerTable { sums }
+    }
+
+    /// Returns the total power of the rectangle from the top left corner
+    /// through the specified cell, less that cell.
+    fn sum(sums: &Grid<i64>, x: usize, y: usize) -> i64 {
+        let at = |y, x| sums[Position::new(y, x)];
+        at(y - 1, x) + at(y, x - 1) - at(y - 1, x - 1)
+    }
+
+    /// Returns the total power of the square of the specified size whose top
+    /// left cell is at the specified 1-based coordinates.  The square must
+    /// fit in the grid.
+    pub fn power(&self, x: usize, y: usize, size: usize) -> i64 {
+        let (x0, y0, x1, y1) = (x - 1, y - 1, x + size - 1, y + size - 1);
+        let at = |y, x| self.sums[Position::new(y, x)];
+        at(y1, x1) - at(y0, x1) - at(y1, x0) + at(y0, x0)
+    }
+
+    /// Returns the square of the specified size having the most power, and
+    /// that power.  Ties go to the square nearest the top, then the left.
+    pub fn best(&self, size: usize) -> (Square, i64) {
+        let mut best = (
+            Square {
+                x: 1,
+                y: 1,
+                size: Some(size),
+            },
+            i64::MIN,
+        );
+        for y in 1..=(EXTENT + 1 - size) {
+            for x in 1..=(EXTENT + 1 - size) {
+                let power = self.power(x, y, size);
+                if power > best.1 {
+                    best = (
+                        Square {
+                            x,
+                            y,
+                            size: Some(size),
+                        },
+                        power,
+                    );
+                }
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cell_powers() {
+        assert_eq!(4, cell_power(3, 5, 8));
+        assert_eq!(-5, cell_power(122, 79, 57));
+        assert_eq!(0, cell_power(217, 196, 39));
+        assert_eq!(4, cell_power(101, 153, 71));
+    }
+
+    #[test]
+    fn square_powers() {
+        assert_eq!(29, PowerTable::new(18).power(33, 45, 3));
+        assert_eq!(113, PowerTable::new(18).power(90, 269, 16));
+        assert_eq!(119, PowerTable::new(42).power(232, 251, 12));
+    }
+}
@@ -0,0 +1,95 @@
+mod pots;
+
+pub mod part1;
+pub mod part2;
+
+pub use pots::{Pots, Rules};
+
+use crate::error::{Error, ParseError};
+use crate::parse;
+use crate::solution::Solution;
+
+/// The initial state of the pots, and the rules by which they change.
+pub struct Tunnel {
+    pub pots: Pots,
+    pub rules: Rules,
+}
+
+fn parse_plant(c: char) -> Result<bool, ParseError> {
+    match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err(ParseError::new(format!("bad pot: {:?}", c))),
+    }
+}
+
+/// Parses a row of pots, such as `#..##`, locating errors within it.
+fn parse_plants(s: &str) -> Result<Vec<bool>, ParseError> {
+    s.char_indices()
+        .map(|(i, c)| parse_plant(c).map_err(|err| err.with_span(i..(i + c.len_utf8()))))
+        .collect()
+}
+
+/// Parses a rule, such as `..#.# => #`.
+fn parse_rule(s: &str) -> Result<(usize, bool), ParseError> {
+    match s.split(" => ").collect::<Vec<&str>>()[..] {
+        [pattern, result] if pattern.len() == 5 && result.len() == 1 => {
+            let plants = parse_plants(pattern).map_err(|err| err.within(s, pattern))?;
+            let result = parse_plants(result).map_err(|err| err.within(s, result))?;
+            Ok((pots::pattern(plants), result[0]))
+        }
+        _ => {
+            let what = "bad rule: expected LLCRR => N";
+            Err(ParseError::new(what).with_span(0..s.len()))
+        }
+    }
+}
+
+fn parse_tunnel(text: &str) -> Result<Tunnel, ParseError> {
+    let paragraphs = parse::paragraphs(text);
+    let (state, rules) = match &paragraphs[..] {
+        [state, rules] if state.lines.len() == 1 => (state, rules),
+        _ => {
+            let what = "expected initial state, blank line, and rules";
+            return Err(ParseError::new(what));
+        }
+    };
+    let line = state.lines[0];
+    let plants = line.strip_prefix("initial state: ").ok_or_else(|| {
+        ParseError::new("expected initial state: PLANTS").on_line(state.first_line, line)
+    })?;
+    let plants = parse_plants(plants)
+        .map_err(|err| err.within(line, plants).on_line(state.first_line, line))?;
+    let mut table = [false; 32];
+    for (pattern, result) in rules.parse_lines(parse_rule)? {
+        table[pattern] = result;
+    }
+    if table[0] {
+        return Err(ParseError::new("empty pots must stay empty"));
+    }
+    Ok(Tunnel {
+        pots: Pots::new(plants),
+        rules: Rules(table),
+    })
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Tunnel;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    // O(N) time, O(N) space
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(parse_tunnel(text)?)
+    }
+
+    fn part1(&self, tunnel: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(tunnel))
+    }
+
+    fn part2(&self, tunnel: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(tunnel)
+    }
+}
@@ -0,0 +1,29 @@
+use super::Tunnel;
+
+/// The number of generations to simulate.
+const GENERATIONS: usize = 20;
+
+/// Returns the sum of the numbers of the pots holding plants after 20
+/// generations.
+// O(G * (N + G)) time, O(N + G) space
+pub fn solve(tunnel: &Tunnel) -> i64 {
+    let mut pots = tunnel.pots.clone();
+    for _ in 0..GENERATIONS {
+        pots = pots.next(&tunnel.rules);
+    }
+    pots.sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::day12::Day12;
+    use crate::solution::Solution;
+    use std::fs;
+
+    #[test]
+    fn sample() {
+        let text = fs::read_to_string("tests/day12/sample1").unwrap();
+        assert_eq!(325, solve(&Day12.parse(&text).unwrap()));
+    }
+}
@@ -0,0 +1,29 @@
+use super::Tunnel;
+use crate::error::Error;
+
+/// The number of generations to simulate.
+const GENERATIONS: i64 = 50_000_000_000;
+
+/// The number of generations to simulate before giving up on the pattern of
+/// plants ever becoming stable.
+const PATIENCE: i64 = 10_000;
+
+/// Returns the sum of the numbers of the pots holding plants after fifty
+/// billion generations.  Simulating that many is out of the question, but
+/// the plants eventually settle into a pattern that merely shifts along the
+/// row each generation; from then on, the sum changes by the same amount
+/// every generation.
+// O(S * (N + S)) time, O(N + S) space, where S is the time to stabilize
+pub fn solve(tunnel: &Tunnel) -> Result<i64, Error> {
+    let mut pots = tunnel.pots.clone();
+    for generation in 0..PATIENCE.min(GENERATIONS) {
+        let next = pots.next(&tunnel.rules);
+        if next.plants == pots.plants {
+            let shift = next.offset - pots.offset;
+            let remaining = GENERATIONS - generation;
+            return Ok(pots.sum() + remaining * shift * pots.count());
+        }
+        pots = next;
+    }
+    Err(Error::NoSolution)
+}
@@ -0,0 +1,64 @@
+/// Returns the index into a rule table of the specified five pots, read as a
+/// binary number with the leftmost pot most significant.
+pub fn pattern<I: IntoIterator<Item = bool>>(plants: I) -> usize {
+    plants
+        .into_iter()
+        .fold(0, |n, plant| n << 1 | plant as usize)
+}
+
+/// Whether a pot holds a plant in the next generation, indexed by the pattern
+/// of plants around it in this one.
+pub struct Rules(pub [bool; 32]);
+
+/// A row of pots, trimmed so the first and last hold plants.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pots {
+    /// The number of the first pot.
+    pub offset: i64,
+    pub plants: Vec<bool>,
+}
+
+impl Pots {
+    pub fn new(plants: Vec<bool>) -> Pots {
+        let mut pots = Pots { offset: 0, plants };
+        pots.trim();
+        pots
+    }
+
+    fn trim(&mut self) {
+        let last = self.plants.iter().rposition(|&p| p).map_or(0, |i| i + 1);
+        self.plants.truncate(last);
+        let first = self.plants.iter().position(|&p| p).unwrap_or(0);
+        self.plants.drain(..first);
+        self.offset += first as i64;
+    }
+
+    /// Returns the pots of the next generation.  Only pots within two of a
+    /// plant can change, since empty pots surrounded by empty pots stay empty.
+    pub fn next(&self, rules: &Rules) -> Pots {
+        let get = |i: i64| {
+            let i = i as usize;
+            i < self.plants.len() && self.plants[i]
+        };
+        let len = self.plants.len() as i64;
+        let plants = (-2..(len + 2))
+            .map(|i| rules.0[pattern(((i - 2)..=(i + 2)).map(|j| j >= 0 && get(j)))])
+            .collect();
+        let mut pots = Pots {
+            offset: self.offset - 2,
+            plants,
+        };
+        pots.trim();
+        pots
+    }
+
+    /// Returns the sum of the numbers of the pots holding plants.
+    pub fn sum(&self) -> i64 {
+        let numbers = (self.offset..).zip(&self.plants);
+        numbers.filter(|(_, &plant)| plant).map(|(n, _)| n).sum()
+    }
+
+    pub fn count(&self) -> i64 {
+        self.plants.iter().filter(|&&plant| plant).count() as i64
+    }
+}
@@ -0,0 +1,26 @@
+pub mod part1;
+pub mod part2;
+
+use crate::error::Error;
+use crate::solution::Solution;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<String>;
+    type Output1 = usize;
+    type Output2 = String;
+
+    // O(N) time, O(N) space
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(text.lines().map(str::to_owned).collect())
+    }
+
+    fn part1(&self, ids: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(ids))
+    }
+
+    fn part2(&self, ids: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(ids)
+    }
+}
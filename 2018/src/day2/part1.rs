@@ -0,0 +1,22 @@
+use std::collections::HashMap;
+
+fn has_count(counts: &HashMap<char, usize>, n: usize) -> bool {
+    counts.values().any(|&count| count == n)
+}
+
+/// Returns the checksum of the specified box IDs: the number of IDs having
+/// some letter exactly twice, times the number having some letter exactly
+/// three times.
+// O(N) time, O(1) space
+pub fn solve(ids: &[String]) -> usize {
+    let (mut twos, mut threes) = (0, 0);
+    for id in ids {
+        let mut counts = HashMap::new();
+        for c in id.chars() {
+            *counts.entry(c).or_insert(0) += 1;
+        }
+        twos += has_count(&counts, 2) as usize;
+        threes += has_count(&counts, 3) as usize;
+    }
+    twos * threes
+}
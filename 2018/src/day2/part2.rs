@@ -0,0 +1,36 @@
+use crate::error::Error;
+use std::collections::HashSet;
+
+/// Returns the letters common to the two IDs that differ in exactly one
+/// position.  Each ID is inserted into a set once per position, with that
+/// position's letter removed; the first collision is the answer.
+// O(N * L²) time, O(N * L²) space, where L is the ID length
+pub fn solve(ids: &[String]) -> Result<String, Error> {
+    let mut seen = HashSet::new();
+    for id in ids {
+        for (i, c) in id.char_indices() {
+            let common = (i, [&id[..i], &id[(i + c.len_utf8())..]].concat());
+            if seen.contains(&common) {
+                return Ok(common.1);
+            }
+            seen.insert(common);
+        }
+    }
+    Err(Error::NoSolution)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sample() {
+        let ids: Vec<String> = [
+            "abcde", "fghij", "klmno", "pqrst", "fguij", "axcye", "wvxyz",
+        ]
+        .iter()
+        .map(|&id| id.to_owned())
+        .collect();
+        assert_eq!("fgij", solve(&ids).unwrap());
+    }
+}
@@ -0,0 +1,76 @@
+use crate::error::ParseError;
+use crate::parse;
+use std::str::FromStr;
+
+/// An elf's claim to a rectangle of fabric, written as `#ID @ X,Y: WxH`.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Claim {
+    pub id: usize,
+    pub left: usize,
+    pub top: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Claim {
+    /// Returns the column and row of each square inch in this claim.
+    pub fn squares(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let columns = self.left..(self.left + self.width);
+        columns.flat_map(move |x| (self.top..(self.top + self.height)).map(move |y| (x, y)))
+    }
+}
+
+/// Splits the specified text at the specified separator, or returns an error
+/// spanning the whole text.
+fn split<'a>(s: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    match s.find(separator) {
+        Some(i) => Ok((&s[..i], &s[(i + separator.len())..])),
+        None => {
+            let what = format!(r#"bad claim: expected "{}""#, separator);
+            Err(ParseError::new(what).with_span(0..s.len()))
+        }
+    }
+}
+
+impl FromStr for Claim {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = |part| parse::integer(part).map_err(|err| err.within(s, part));
+        let locate = |err: ParseError, part| err.within(s, part);
+        let (id, rest) = split(s, " @ ")?;
+        let id = id
+            .strip_prefix('#')
+            .ok_or_else(|| ParseError::new("bad claim: expected \"#\"").with_span(0..1))?;
+        let (corner, size) = split(rest, ": ").map_err(|err| locate(err, rest))?;
+        let (left, top) = split(corner, ",").map_err(|err| locate(err, corner))?;
+        let (width, height) = split(size, "x").map_err(|err| locate(err, size))?;
+        Ok(Claim {
+            id: number(id)?,
+            left: number(left)?,
+            top: number(top)?,
+            width: number(width)?,
+            height: number(height)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_claim() {
+        let claim: Claim = "#123 @ 3,2: 5x4".parse().unwrap();
+        let want = Claim {
+            id: 123,
+            left: 3,
+            top: 2,
+            width: 5,
+            height: 4,
+        };
+        assert_eq!(want, claim);
+        let err = "#123 @ 3,2: 5y4".parse::<Claim>().unwrap_err();
+        assert_eq!(Some(12..15), err.span());
+    }
+}
@@ -0,0 +1,56 @@
+mod claim;
+
+pub mod part1;
+pub mod part2;
+
+pub use claim::Claim;
+
+use crate::error::Error;
+use crate::parse;
+use crate::solution::Solution;
+
+/// The number of claims covering each square inch of fabric.
+struct Coverage {
+    width: usize,
+    counts: Vec<usize>,
+}
+
+impl Coverage {
+    fn new(claims: &[Claim]) -> Coverage {
+        let width = claims.iter().map(|c| c.left + c.width).max().unwrap_or(0);
+        let height = claims.iter().map(|c| c.top + c.height).max().unwrap_or(0);
+        let mut coverage = Coverage {
+            width,
+            counts: vec![0; width * height],
+        };
+        for (x, y) in claims.iter().flat_map(Claim::squares) {
+            coverage.counts[y * width + x] += 1;
+        }
+        coverage
+    }
+
+    fn get(&self, (x, y): (usize, usize)) -> usize {
+        self.counts[y * self.width + x]
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Claim>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    // O(N) time, O(N) space
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(parse::lines(text, str::parse)?)
+    }
+
+    fn part1(&self, claims: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(claims))
+    }
+
+    fn part2(&self, claims: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(claims)
+    }
+}
@@ -0,0 +1,8 @@
+use super::{Claim, Coverage};
+
+/// Returns the number of square inches covered by two or more claims.
+// O(A + W * H) time, O(W * H) space, where A is the total claimed area
+pub fn solve(claims: &[Claim]) -> usize {
+    let coverage = Coverage::new(claims);
+    coverage.counts.iter().filter(|&&count| count > 1).count()
+}
@@ -0,0 +1,13 @@
+use super::{Claim, Coverage};
+use crate::error::Error;
+
+/// Returns the ID of the only claim that overlaps no other.
+// O(A + W * H) time, O(W * H) space, where A is the total claimed area
+pub fn solve(claims: &[Claim]) -> Result<usize, Error> {
+    let coverage = Coverage::new(claims);
+    claims
+        .iter()
+        .find(|claim| claim.squares().all(|square| coverage.get(square) == 1))
+        .map(|claim| claim.id)
+        .ok_or(Error::NoSolution)
+}
@@ -0,0 +1,80 @@
+mod record;
+
+pub mod part1;
+pub mod part2;
+
+pub use record::{Event, Record};
+
+use crate::error::{Error, ParseError};
+use crate::parse;
+use crate::solution::Solution;
+use std::collections::HashMap;
+
+/// The number of minutes in the midnight hour, when guards may sleep.
+const MINUTES: usize = 60;
+
+/// How many times a guard was asleep during each minute of the midnight hour.
+pub type Minutes = [usize; MINUTES];
+
+/// Maps guard IDs to the minutes they've been seen asleep.
+pub type Naps = HashMap<usize, Minutes>;
+
+/// Replays the specified records in chronological order, tallying naps.
+fn tally(mut records: Vec<Record>) -> Result<Naps, ParseError> {
+    records.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+    let mut naps = Naps::new();
+    let mut guard = None;
+    let mut asleep = None;
+    for record in records {
+        let at = |what: &str| ParseError::new(format!("{}: {}", record.timestamp, what));
+        match record.event {
+            Event::Begin(id) => guard = Some(id),
+            Event::Sleep => asleep = Some(record.minute),
+            Event::Wake => {
+                let id = guard.ok_or_else(|| at("no guard on duty"))?;
+                let start = asleep.take().ok_or_else(|| at("woke without sleeping"))?;
+                if record.minute <= start || record.minute > MINUTES {
+                    return Err(at("nap must end later in the midnight hour"));
+                }
+                let minutes = naps.entry(id).or_insert([0; MINUTES]);
+                for count in &mut minutes[start..record.minute] {
+                    *count += 1;
+                }
+            }
+        }
+    }
+    Ok(naps)
+}
+
+/// Returns the minute during which the specified guard was most often
+/// asleep, and how often that was.
+fn sleepiest(minutes: &Minutes) -> (usize, usize) {
+    let mut best = (0, 0);
+    for (minute, &count) in minutes.iter().enumerate() {
+        if count > best.1 {
+            best = (minute, count);
+        }
+    }
+    best
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Naps;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    // O(N log N) time, O(N) space
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(tally(parse::lines(text, str::parse)?)?)
+    }
+
+    fn part1(&self, naps: &Self::Input) -> Result<Self::Output1, Error> {
+        part1::solve(naps)
+    }
+
+    fn part2(&self, naps: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(naps)
+    }
+}
@@ -0,0 +1,14 @@
+use super::{sleepiest, Naps};
+use crate::error::Error;
+
+/// Returns the ID of the guard who slept the most, times the minute that
+/// guard was most often asleep.
+// O(G) time, O(1) space, where G is the number of guards
+pub fn solve(naps: &Naps) -> Result<usize, Error> {
+    let total = |minutes: &[usize]| minutes.iter().sum::<usize>();
+    let (id, minutes) = naps
+        .iter()
+        .max_by_key(|(&id, minutes)| (total(&minutes[..]), id))
+        .ok_or(Error::NoSolution)?;
+    Ok(id * sleepiest(minutes).0)
+}
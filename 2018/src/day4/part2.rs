@@ -0,0 +1,13 @@
+use super::{sleepiest, Naps};
+use crate::error::Error;
+
+/// Returns the ID of the guard most frequently asleep on the same minute,
+/// times that minute.
+// O(G) time, O(1) space, where G is the number of guards
+pub fn solve(naps: &Naps) -> Result<usize, Error> {
+    naps.iter()
+        .map(|(&id, minutes)| (sleepiest(minutes), id))
+        .max_by_key(|&((minute, count), id)| (count, id, minute))
+        .map(|((minute, _), id)| id * minute)
+        .ok_or(Error::NoSolution)
+}
@@ -0,0 +1,87 @@
+use crate::error::{ParseError, ParseErrorKind};
+use crate::parse;
+use std::str::FromStr;
+
+/// Something noted about the guard on duty.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Event {
+    /// The specified guard began a shift.
+    Begin(usize),
+    /// The guard on duty fell asleep.
+    Sleep,
+    /// The guard on duty woke up.
+    Wake,
+}
+
+/// A line of the observers' notes, such as
+/// `[1518-11-01 00:05] falls asleep`.
+#[derive(Debug)]
+pub struct Record {
+    /// The date and time, as written.  These sort chronologically.
+    pub timestamp: String,
+    pub minute: usize,
+    pub event: Event,
+}
+
+fn parse_event(s: &str) -> Result<Event, ParseError> {
+    match s {
+        "falls asleep" => Ok(Event::Sleep),
+        "wakes up" => Ok(Event::Wake),
+        _ => {
+            let id = s
+                .strip_prefix("Guard #")
+                .and_then(|rest| rest.strip_suffix(" begins shift"))
+                .ok_or_else(|| {
+                    let what = format!("bad event: {}", s);
+                    ParseError::with_kind(ParseErrorKind::Unexpected, what).with_span(0..s.len())
+                })?;
+            let id = parse::integer(id).map_err(|err| err.within(s, id))?;
+            Ok(Event::Begin(id))
+        }
+    }
+}
+
+impl FromStr for Record {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (timestamp, event) = match (s.strip_prefix('['), s.find("] ")) {
+            (Some(_), Some(i)) => (&s[1..i], &s[(i + 2)..]),
+            _ => {
+                let what = "bad record: expected [TIMESTAMP] EVENT";
+                return Err(ParseError::new(what).with_span(0..s.len()));
+            }
+        };
+        let minute = match timestamp.rfind(':') {
+            Some(i) => &timestamp[(i + 1)..],
+            None => {
+                let what = "bad timestamp: expected HH:MM";
+                return Err(ParseError::new(what).within(s, timestamp));
+            }
+        };
+        Ok(Record {
+            timestamp: timestamp.to_owned(),
+            minute: parse::integer(minute).map_err(|err| err.within(s, minute))?,
+            event: parse_event(event).map_err(|err| err.within(s, event))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_record() {
+        let record: Record = "[1518-04-10 23:52] Guard #3559 begins shift"
+            .parse()
+            .unwrap();
+        assert_eq!("1518-04-10 23:52", record.timestamp);
+        assert_eq!(52, record.minute);
+        assert_eq!(Event::Begin(3559), record.event);
+        let record: Record = "[1518-05-18 00:35] falls asleep".parse().unwrap();
+        assert_eq!(Event::Sleep, record.event);
+        let err = "[1518-05-18 00:35] falls up".parse::<Record>().unwrap_err();
+        assert_eq!(Some(19..27), err.span());
+    }
+}
@@ -0,0 +1,69 @@
+pub mod part1;
+pub mod part2;
+
+use crate::error::{Error, ParseError, ParseErrorKind};
+use crate::solution::Solution;
+
+/// Returns true if the specified units destroy each other: that is, if they
+/// are the same type, but of opposite polarities.
+fn reacts(a: u8, b: u8) -> bool {
+    a != b && a.eq_ignore_ascii_case(&b)
+}
+
+/// Returns the length of the specified polymer once it has fully reacted,
+/// ignoring any units for which the specified predicate returns false.
+// O(N) time, O(N) space
+fn react<F>(polymer: &[u8], mut keep: F) -> usize
+where
+    F: FnMut(u8) -> bool,
+{
+    let mut stack: Vec<u8> = Vec::with_capacity(polymer.len());
+    for &unit in polymer.iter().filter(|&&unit| keep(unit)) {
+        match stack.last() {
+            Some(&top) if reacts(top, unit) => {
+                stack.pop();
+            }
+            _ => stack.push(unit),
+        }
+    }
+    stack.len()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<u8>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    // O(N) time, O(N) space
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        let polymer = text.trim_end();
+        if let Some(i) = polymer.find(|c: char| !c.is_ascii_alphabetic()) {
+            let what = "bad polymer: expected only letters";
+            let err = ParseError::with_kind(ParseErrorKind::Unexpected, what);
+            return Err(err.on_line(1, polymer).with_span(i..(i + 1)).into());
+        }
+        Ok(polymer.bytes().collect())
+    }
+
+    fn part1(&self, polymer: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(polymer))
+    }
+
+    fn part2(&self, polymer: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(polymer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sample() {
+        let polymer = Day5.parse("dabAcCaCBAcCcaDA\n").unwrap();
+        assert_eq!(10, part1::solve(&polymer));
+        assert_eq!(4, part2::solve(&polymer).unwrap());
+    }
+}
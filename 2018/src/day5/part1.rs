@@ -0,0 +1,7 @@
+use super::react;
+
+/// Returns the number of units remaining after the polymer fully reacts.
+// O(N) time, O(N) space
+pub fn solve(polymer: &[u8]) -> usize {
+    react(polymer, |_| true)
+}
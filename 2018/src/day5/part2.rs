@@ -0,0 +1,13 @@
+use super::react;
+use crate::error::Error;
+
+/// Returns the length of the shortest polymer that can be produced by
+/// removing every unit of a single type, then fully reacting the rest.
+// O(N) time, O(N) space
+pub fn solve(polymer: &[u8]) -> Result<usize, Error> {
+    (b'a'..=b'z')
+        .filter(|unit| polymer.iter().any(|u| u.eq_ignore_ascii_case(unit)))
+        .map(|unit| react(polymer, |u| !u.eq_ignore_ascii_case(&unit)))
+        .min()
+        .ok_or(Error::EmptyInput)
+}
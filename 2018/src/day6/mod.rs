@@ -0,0 +1,70 @@
+pub mod part1;
+pub mod part2;
+
+use crate::error::{Error, ParseError};
+use crate::parse;
+use crate::solution::Solution;
+
+/// A coordinate, written as `X, Y`.
+pub type Point = (i32, i32);
+
+fn parse_point(s: &str) -> Result<Point, ParseError> {
+    match s.find(',') {
+        Some(i) => {
+            let (x, y) = (&s[..i], &s[(i + 1)..]);
+            let number = |part| parse::integer(part).map_err(|err| err.within(s, part));
+            Ok((number(x)?, number(y)?))
+        }
+        None => Err(ParseError::new("bad coordinate: expected X, Y").with_span(0..s.len())),
+    }
+}
+
+fn distance(a: Point, b: Point) -> u32 {
+    (a.0 - b.0).unsigned_abs() + (a.1 - b.1).unsigned_abs()
+}
+
+/// The smallest rectangle containing every point, as inclusive
+/// `(left, top, right, bottom)` coordinates.
+fn bounds(points: &[Point]) -> Result<(i32, i32, i32, i32), Error> {
+    let xs = points.iter().map(|p| p.0);
+    let ys = points.iter().map(|p| p.1);
+    match (xs.clone().min(), ys.clone().min(), xs.max(), ys.max()) {
+        (Some(left), Some(top), Some(right), Some(bottom)) => Ok((left, top, right, bottom)),
+        _ => Err(Error::EmptyInput),
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Point>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    // O(N) time, O(N) space
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(parse::lines(text, parse_point)?)
+    }
+
+    fn part1(&self, points: &Self::Input) -> Result<Self::Output1, Error> {
+        part1::solve(points)
+    }
+
+    fn part2(&self, points: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(points, part2::LIMIT)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SAMPLE: &str = "1, 1\n1, 6\n8, 3\n3, 4\n5, 5\n8, 9\n";
+
+    #[test]
+    fn sample() {
+        let points = Day6.parse(SAMPLE).unwrap();
+        assert_eq!(17, part1::solve(&points).unwrap());
+        assert_eq!(16, part2::solve(&points, 32).unwrap());
+    }
+}
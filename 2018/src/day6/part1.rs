@@ -0,0 +1,39 @@
+use super::{bounds, distance, Point};
+use crate::error::Error;
+
+/// Returns the index of the only point closest to the specified location, or
+/// None if there is a tie.
+fn closest(points: &[Point], location: Point) -> Option<usize> {
+    let mut best = None;
+    let mut tied = false;
+    for (index, &point) in points.iter().enumerate() {
+        let d = distance(point, location);
+        match best {
+            Some((_, min)) if d > min => (),
+            Some((_, min)) if d == min => tied = true,
+            _ => {
+                best = Some((index, d));
+                tied = false;
+            }
+        }
+    }
+    best.filter(|_| !tied).map(|(index, _)| index)
+}
+
+/// Returns the size of the largest finite area of locations closest to a
+/// single point.  Any area reaching the bounding box of all the points
+/// extends forever, and so is disqualified.
+// O(N * W * H) time, O(N) space
+pub fn solve(points: &[Point]) -> Result<usize, Error> {
+    let (left, top, right, bottom) = bounds(points)?;
+    let mut areas = vec![Some(0); points.len()];
+    for y in top..=bottom {
+        for x in left..=right {
+            if let Some(index) = closest(points, (x, y)) {
+                let edge = x == left || x == right || y == top || y == bottom;
+                areas[index] = areas[index].filter(|_| !edge).map(|area| area + 1);
+            }
+        }
+    }
+    areas.into_iter().flatten().max().ok_or(Error::NoSolution)
+}
@@ -0,0 +1,22 @@
+use super::{bounds, distance, Point};
+use crate::error::Error;
+
+/// The total distance within which locations are considered safe.
+pub const LIMIT: u32 = 10_000;
+
+/// Returns the number of locations whose total distance to every point is
+/// less than the specified limit.  Such locations may lie outside the
+/// bounding box of the points, but no farther out than limit / N.
+// O(N * (W + L/N) * (H + L/N)) time, O(1) space
+pub fn solve(points: &[Point], limit: u32) -> Result<usize, Error> {
+    let (left, top, right, bottom) = bounds(points)?;
+    let margin = (limit as usize / points.len()) as i32;
+    let mut size = 0;
+    for y in (top - margin)..=(bottom + margin) {
+        for x in (left - margin)..=(right + margin) {
+            let total: u32 = points.iter().map(|&point| distance(point, (x, y))).sum();
+            size += (total < limit) as usize;
+        }
+    }
+    Ok(size)
+}
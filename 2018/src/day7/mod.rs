@@ -0,0 +1,109 @@
+pub mod part1;
+pub mod part2;
+
+use crate::error::{Error, ParseError, ParseErrorKind};
+use crate::parse;
+use crate::solution::Solution;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Maps each step to the steps that must finish before it can begin.
+pub type Prerequisites = BTreeMap<char, BTreeSet<char>>;
+
+fn parse_step(s: &str, word: &str) -> Result<char, ParseError> {
+    match word.chars().collect::<Vec<char>>()[..] {
+        [c] if c.is_ascii_uppercase() => Ok(c),
+        _ => {
+            let what = format!(r#"bad step: "{}""#, word);
+            Err(ParseError::with_kind(ParseErrorKind::Unexpected, what).within(s, word))
+        }
+    }
+}
+
+/// Parses an instruction, such as
+/// `Step C must be finished before step A can begin.`
+fn parse_instruction(s: &str) -> Result<(char, char), ParseError> {
+    match s.split(' ').collect::<Vec<&str>>()[..] {
+        ["Step", before, "must", "be", "finished", "before", "step", after, "can", "begin."] => {
+            Ok((parse_step(s, before)?, parse_step(s, after)?))
+        }
+        _ => Err(ParseError::new("bad instruction").with_span(0..s.len())),
+    }
+}
+
+/// Returns the steps that are ready to begin, having no prerequisites left.
+fn ready(prerequisites: &Prerequisites) -> BTreeSet<char> {
+    let steps = prerequisites.iter();
+    steps
+        .filter(|(_, before)| before.is_empty())
+        .map(|(&step, _)| step)
+        .collect()
+}
+
+/// Marks the specified step finished, returning the steps it unblocked.
+fn finish(prerequisites: &mut Prerequisites, step: char) -> Vec<char> {
+    let mut unblocked = Vec::new();
+    for (&other, before) in prerequisites.iter_mut() {
+        if before.remove(&step) && before.is_empty() {
+            unblocked.push(other);
+        }
+    }
+    unblocked
+}
+
+/// Returns an error unless every step has been finished.  Any left over are
+/// part of a cycle.
+fn check_done(done: usize, prerequisites: &Prerequisites) -> Result<(), Error> {
+    if done == prerequisites.len() {
+        Ok(())
+    } else {
+        Err(Error::NoSolution)
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Prerequisites;
+    type Output1 = String;
+    type Output2 = usize;
+
+    // O(N log N) time, O(N) space
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        let mut prerequisites = Prerequisites::new();
+        for (before, after) in parse::lines(text, parse_instruction)? {
+            prerequisites.entry(before).or_default();
+            prerequisites.entry(after).or_default().insert(before);
+        }
+        Ok(prerequisites)
+    }
+
+    fn part1(&self, prerequisites: &Self::Input) -> Result<Self::Output1, Error> {
+        part1::solve(prerequisites.clone())
+    }
+
+    fn part2(&self, prerequisites: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(prerequisites.clone(), part2::WORKERS, part2::OVERHEAD)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn sample() {
+        let text = fs::read_to_string("tests/day7/sample1").unwrap();
+        let prerequisites = Day7.parse(&text).unwrap();
+        assert_eq!("CABDFE", part1::solve(prerequisites.clone()).unwrap());
+        assert_eq!(15, part2::solve(prerequisites, 2, 0).unwrap());
+    }
+
+    #[test]
+    fn cycle() {
+        let text = "Step A must be finished before step B can begin.\n\
+                    Step B must be finished before step A can begin.\n";
+        let prerequisites = Day7.parse(text).unwrap();
+        assert!(part1::solve(prerequisites).is_err());
+    }
+}
@@ -0,0 +1,18 @@
+use super::Prerequisites;
+use crate::error::Error;
+use crate::graph::Graph;
+
+/// Returns the order in which to complete the steps, alphabetically among
+/// those that are ready.
+// O(N log N) time, O(N) space
+pub fn solve(prerequisites: Prerequisites) -> Result<String, Error> {
+    let mut graph = Graph::new();
+    for (&step, before) in &prerequisites {
+        graph.add_node(step);
+        for &other in before {
+            graph.add_edge(other, step, ());
+        }
+    }
+    let order = graph.least_topological_order().ok_or(Error::NoSolution)?;
+    Ok(order.into_iter().collect())
+}
@@ -0,0 +1,54 @@
+use super::{check_done, finish, ready, Prerequisites};
+use crate::error::Error;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// The number of workers: you, plus four elves.
+pub const WORKERS: usize = 5;
+
+/// The number of seconds each step takes, beyond its letter's position in the
+/// alphabet.
+pub const OVERHEAD: usize = 60;
+
+fn duration(step: char, overhead: usize) -> usize {
+    overhead + (step as usize - 'A' as usize) + 1
+}
+
+/// Returns the number of seconds the specified number of workers take to
+/// complete all the steps.  Idle workers always begin the alphabetically
+/// first step that is ready.
+// O(N² log N) time, O(N) space
+pub fn solve(
+    mut prerequisites: Prerequisites,
+    workers: usize,
+    overhead: usize,
+) -> Result<usize, Error> {
+    let mut ready = ready(&prerequisites);
+    let mut busy = BinaryHeap::new(); // (Reverse(finish time), step)
+    let mut now = 0;
+    let mut done = 0;
+    loop {
+        while busy.len() < workers {
+            match ready.pop_first() {
+                Some(step) => busy.push((Reverse(now + duration(step, overhead)), step)),
+                None => break,
+            }
+        }
+        let (Reverse(time), step) = match busy.pop() {
+            Some(work) => work,
+            None => break,
+        };
+        now = time;
+        done += 1;
+        ready.extend(finish(&mut prerequisites, step));
+        // Steps finishing at the same moment free their workers together.
+        while busy.peek().is_some_and(|&(Reverse(time), _)| time == now) {
+            if let Some((_, step)) = busy.pop() {
+                done += 1;
+                ready.extend(finish(&mut prerequisites, step));
+            }
+        }
+    }
+    check_done(done, &prerequisites)?;
+    Ok(now)
+}
@@ -0,0 +1,86 @@
+pub mod part1;
+pub mod part2;
+
+use crate::error::{Error, ParseError, ParseErrorKind};
+use crate::parse;
+use crate::solution::Solution;
+
+/// A node of the license tree.
+#[derive(Debug)]
+pub struct Node {
+    pub children: Vec<Node>,
+    pub metadata: Vec<usize>,
+}
+
+impl Node {
+    /// Builds a node from the front of the specified numbers: a header giving
+    /// the number of children and metadata entries, then the children, then
+    /// the metadata.
+    fn build<I: Iterator<Item = usize>>(numbers: &mut I) -> Result<Node, ParseError> {
+        let mut next = || {
+            numbers.next().ok_or_else(|| {
+                ParseError::with_kind(ParseErrorKind::UnexpectedEnd, "truncated tree")
+            })
+        };
+        let (num_children, num_metadata) = (next()?, next()?);
+        let children = (0..num_children)
+            .map(|_| Node::build(numbers))
+            .collect::<Result<_, _>>()?;
+        let metadata = numbers.take(num_metadata).collect::<Vec<_>>();
+        if metadata.len() < num_metadata {
+            let what = "truncated metadata";
+            return Err(ParseError::with_kind(ParseErrorKind::UnexpectedEnd, what));
+        }
+        Ok(Node { children, metadata })
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Node;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    // O(N) time, O(N) space
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        let numbers = text
+            .split_whitespace()
+            .map(parse::integer)
+            .collect::<Result<Vec<usize>, _>>()?;
+        let mut numbers = numbers.into_iter();
+        let root = Node::build(&mut numbers)?;
+        if numbers.next().is_some() {
+            return Err(ParseError::new("trailing numbers after tree").into());
+        }
+        Ok(root)
+    }
+
+    fn part1(&self, root: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(root))
+    }
+
+    fn part2(&self, root: &Self::Input) -> Result<Self::Output2, Error> {
+        Ok(part2::solve(root))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SAMPLE: &str = "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2\n";
+
+    #[test]
+    fn sample() {
+        let root = Day8.parse(SAMPLE).unwrap();
+        assert_eq!(138, part1::solve(&root));
+        assert_eq!(66, part2::solve(&root));
+    }
+
+    #[test]
+    fn truncated() {
+        let err = Day8.parse("2 3 0 3 10 11 12 1 1 0 1 99 2 1 1").unwrap_err();
+        assert!(matches!(err, Error::Parse(err) if err.kind() == ParseErrorKind::UnexpectedEnd));
+    }
+}
@@ -0,0 +1,8 @@
+use super::Node;
+
+/// Returns the sum of every metadata entry in the tree.
+// O(N) time, O(D) space, where D is the depth of the tree
+pub fn solve(node: &Node) -> usize {
+    let own: usize = node.metadata.iter().sum();
+    own + node.children.iter().map(solve).sum::<usize>()
+}
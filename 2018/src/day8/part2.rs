@@ -0,0 +1,17 @@
+use super::Node;
+
+/// Returns the value of the root node.  A leaf's value is the sum of its
+/// metadata; any other node's metadata are 1-based indexes of the children
+/// whose values it sums.  Indexes that refer to no child, including 0, are
+/// skipped.
+// O(N) time, O(D) space, where D is the depth of the tree
+pub fn solve(node: &Node) -> usize {
+    if node.children.is_empty() {
+        return node.metadata.iter().sum();
+    }
+    let values: Vec<usize> = node.children.iter().map(solve).collect();
+    node.metadata
+        .iter()
+        .filter_map(|&index| index.checked_sub(1).and_then(|i| values.get(i)))
+        .sum()
+}
@@ -0,0 +1,49 @@
+/// A circle of marbles, stored as a doubly linked list indexed by marble
+/// number, with a cursor at the current marble.  Marble 0 starts out alone.
+pub struct Circle {
+    /// The marbles before and after each marble.
+    links: Vec<(usize, usize)>,
+    current: usize,
+}
+
+impl Circle {
+    /// Creates a circle holding marble 0, with room for marbles up to the
+    /// specified number.
+    pub fn new(last: usize) -> Circle {
+        Circle {
+            links: vec![(0, 0); last + 1],
+            current: 0,
+        }
+    }
+
+    /// Moves the cursor clockwise by the specified number of marbles, or
+    /// counterclockwise if negative.
+    pub fn rotate(&mut self, steps: isize) {
+        for _ in 0..steps.unsigned_abs() {
+            let (before, after) = self.links[self.current];
+            self.current = if steps < 0 { before } else { after };
+        }
+    }
+
+    /// Inserts the specified marble, which must not already be in the circle,
+    /// clockwise of the current marble, and makes it current.
+    pub fn insert(&mut self, marble: usize) {
+        let before = self.current;
+        let after = self.links[before].1;
+        self.links[marble] = (before, after);
+        self.links[before].1 = marble;
+        self.links[after].0 = marble;
+        self.current = marble;
+    }
+
+    /// Removes the current marble, returning it, and makes the marble
+    /// clockwise of it current.
+    pub fn remove(&mut self) -> usize {
+        let marble = self.current;
+        let (before, after) = self.links[marble];
+        self.links[before].1 = after;
+        self.links[after].0 = before;
+        self.current = after;
+        marble
+    }
+}
@@ -0,0 +1,95 @@
+mod circle;
+
+pub mod part1;
+pub mod part2;
+
+use crate::error::{Error, ParseError};
+use crate::parse;
+use crate::solution::Solution;
+use circle::Circle;
+
+/// The rules of a game, such as
+/// `10 players; last marble is worth 1618 points`.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Game {
+    pub players: usize,
+    pub last: usize,
+}
+
+impl Game {
+    /// Returns the winning score.
+    // O(N) time, O(N + P) space
+    fn play(&self) -> Result<usize, Error> {
+        if self.players == 0 {
+            return Err(Error::NoSolution);
+        }
+        let mut scores = vec![0; self.players];
+        let mut circle = Circle::new(self.last);
+        for marble in 1..=self.last {
+            if marble % 23 == 0 {
+                circle.rotate(-7);
+                scores[marble % self.players] += marble + circle.remove();
+            } else {
+                circle.rotate(1);
+                circle.insert(marble);
+            }
+        }
+        scores.into_iter().max().ok_or(Error::NoSolution)
+    }
+}
+
+fn parse_game(s: &str) -> Result<Game, ParseError> {
+    match s.split(' ').collect::<Vec<&str>>()[..] {
+        [players, "players;", "last", "marble", "is", "worth", last, "points"] => Ok(Game {
+            players: parse::integer(players).map_err(|err| err.within(s, players))?,
+            last: parse::integer(last).map_err(|err| err.within(s, last))?,
+        }),
+        _ => {
+            let what = "bad game: expected N players; last marble is worth M points";
+            Err(ParseError::new(what).with_span(0..s.len()))
+        }
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Game;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        match parse::lines(text, parse_game)?.into_iter().next() {
+            Some(game) => Ok(game),
+            None => Err(Error::EmptyInput),
+        }
+    }
+
+    fn part1(&self, game: &Self::Input) -> Result<Self::Output1, Error> {
+        part1::solve(game)
+    }
+
+    fn part2(&self, game: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(game)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn samples() {
+        let samples = [
+            (9, 25, 32),
+            (10, 1618, 8317),
+            (13, 7999, 146373),
+            (17, 1104, 2764),
+            (21, 6111, 54718),
+            (30, 5807, 37305),
+        ];
+        for &(players, last, score) in &samples {
+            assert_eq!(score, Game { players, last }.play().unwrap());
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use super::Game;
+use crate::error::Error;
+
+/// Returns the winning score of the specified game.
+// O(N) time, O(N + P) space
+pub fn solve(game: &Game) -> Result<usize, Error> {
+    game.play()
+}
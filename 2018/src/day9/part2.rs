@@ -0,0 +1,14 @@
+use super::Game;
+use crate::error::{Error, ParseError};
+
+/// How many times larger the last marble is in part 2.
+const SCALE: usize = 100;
+
+/// Returns the winning score if the last marble were worth 100 times more.
+// O(N) time, O(N + P) space
+pub fn solve(game: &Game) -> Result<usize, Error> {
+    let last = game.last.checked_mul(SCALE).ok_or_else(|| {
+        ParseError::new(format!("{} points: last marble is too large", game.last))
+    })?;
+    Game { last, ..*game }.play()
+}
@@ -0,0 +1,20 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub mod registry;
+
+pub use advent_core::{
+    animate, answers, automaton, bench, cycle, driver, error, graph, grid, image, parse, solution,
+    space, table,
+};
+pub use advent_core::{Error, Part, Puzzle, Solution};
@@ -0,0 +1,42 @@
+//! Every puzzle solved in this crate, keyed by day.
+
+use crate::{day1, day10, day11, day12, day2, day3, day4, day5, day6, day7, day8, day9};
+use advent_core::entry;
+
+pub use advent_core::registry::Entry;
+
+static ENTRIES: &[Entry] = &[
+    entry!(2018, 1, day1::Day1),
+    entry!(2018, 2, day2::Day2),
+    entry!(2018, 3, day3::Day3),
+    entry!(2018, 4, day4::Day4),
+    entry!(2018, 5, day5::Day5),
+    entry!(2018, 6, day6::Day6),
+    entry!(2018, 7, day7::Day7),
+    entry!(2018, 8, day8::Day8),
+    entry!(2018, 9, day9::Day9),
+    entry!(2018, 10, day10::Day10),
+    entry!(2018, 11, day11::Day11),
+    entry!(2018, 12, day12::Day12),
+];
+
+/// Returns all registered puzzles, ordered by day.
+pub fn entries() -> &'static [Entry] {
+    ENTRIES
+}
+
+/// Returns the puzzle registered for the specified year and day, if any.
+pub fn find(year: u16, day: u8) -> Option<&'static Entry> {
+    advent_core::registry::find(ENTRIES, year, day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sorted_and_unique() {
+        let keys: Vec<_> = entries().iter().map(|e| (e.year, e.day)).collect();
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -0,0 +1,43 @@
+//! Checks every registered puzzle against the answers recorded in its test
+//! directory.  Slow answers are checked only when ignored tests are run:
+//!
+//!     cargo test --release --test answers -- --ignored
+
+use advent2018::answers::{self, Answer, Check};
+use advent2018::registry;
+
+fn assert_all<F>(predicate: F)
+where
+    F: FnMut(&Answer) -> bool,
+{
+    let checks = answers::check_all(registry::entries(), predicate).unwrap();
+    let failures: Vec<&Check> = checks.iter().filter(|check| !check.passed()).collect();
+    assert!(failures.is_empty(), "\n{}", answers::table(failures));
+}
+
+#[test]
+fn fast_answers() {
+    assert_all(|answer| !answer.slow);
+}
+
+#[test]
+#[ignore]
+fn slow_answers() {
+    assert_all(|answer| answer.slow);
+}
+
+#[test]
+fn inputs_recorded() {
+    for entry in registry::entries() {
+        let manifest = answers::load_manifest(entry.test_dir()).unwrap();
+        for &part in entry.puzzle.parts() {
+            assert!(
+                manifest.iter().any(|a| a.file == "input" && a.part == part),
+                "{} day {} part {}: no recorded answer for input",
+                entry.year,
+                entry.day,
+                part
+            );
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Frames for `aoc run --animate`: each generation of the puzzles that are
+//! cellular automata, as shown by their `Display` impls.
+
+use crate::error::Error;
+use crate::solution::Part;
+use crate::{day11, day17, day24};
+use std::iter;
+
+pub type Frames = Box<dyn Iterator<Item = String>>;
+
+/// The days that can be animated.
+pub const DAYS: [u8; 3] = [11, 17, 24];
+
+/// Returns the frames showing the specified part of the specified day's
+/// puzzle unfold from the specified input, or None if the day isn't animated.
+pub fn frames(day: u8, part: Part, text: &str) -> Option<Result<Frames, Error>> {
+    Some(match day {
+        11 => seating(text, part),
+        17 => cubes(text, part),
+        24 => floor(text),
+        _ => return None,
+    })
+}
+
+/// Returns each generation of the seating chart, until it settles.
+fn seating(text: &str, part: Part) -> Result<Frames, Error> {
+    let grid: day11::Grid = text.parse()?;
+    Ok(Box::new(
+        grid.generations(day11::SeatingRules::preset(part))
+            .map(|grid| grid.to_string()),
+    ))
+}
+
+/// Returns each cycle of the boot process, in 3 dimensions for part 1 and 4
+/// for part 2.
+fn cubes(text: &str, part: Part) -> Result<Frames, Error> {
+    let slice: day17::Slice = text.parse()?;
+    Ok(match part {
+        Part::One => cycles::<3>(&slice),
+        Part::Two => cycles::<4>(&slice),
+    })
+}
+
+fn cycles<const N: usize>(slice: &day17::Slice) -> Frames {
+    let generations = day17::generations::<N>(slice, day17::CYCLES);
+    Box::new(generations.map(|cubes| cubes.to_string()))
+}
+
+/// Returns the floor on each day of the exhibit.
+fn floor(text: &str) -> Result<Frames, Error> {
+    let floor: day24::Floor = text.parse()?;
+    let days = iter::successors(Some(floor), |floor| Some(floor.clone().day(1)));
+    Ok(Box::new(
+        days.take(day24::DAYS + 1).map(|floor| floor.to_string()),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn seating_settles() {
+        let text = fs::read_to_string("tests/day11/sample1").unwrap();
+        let shown: Vec<_> = frames(11, Part::One, &text).unwrap().unwrap().collect();
+        assert_eq!(6, shown.len());
+        assert_eq!(37, shown[5].matches('#').count());
+        assert!(frames(3, Part::One, &text).is_none());
+    }
+}
@@ -1,7 +1,5 @@
-use advent2020::day11::{part1, part2};
+use advent2020::day11::Day11;
 
 fn main() {
-    let input = "tests/day11/input";
-    println!("{}", part1::solve(input).unwrap());
-    println!("{}", part2::solve(input).unwrap());
+    advent2020::driver::main(&Day11, "tests/day11/input");
 }
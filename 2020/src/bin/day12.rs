@@ -1,7 +1,5 @@
-use advent2020::day12::{part1, part2};
+use advent2020::day12::Day12;
 
 fn main() {
-    let input = "tests/day12/input";
-    println!("{}", part1::solve(input).unwrap());
-    println!("{}", part2::solve(input).unwrap());
+    advent2020::driver::main(&Day12, "tests/day12/input");
 }
@@ -1,7 +1,5 @@
-use advent2020::day13::{part1, part2};
+use advent2020::day13::Day13;
 
 fn main() {
-    let input = "tests/day13/input";
-    println!("{}", part1::solve(input).unwrap());
-    println!("{}", part2::solve(input).unwrap());
+    advent2020::driver::main(&Day13, "tests/day13/input");
 }
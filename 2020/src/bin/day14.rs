@@ -1,7 +1,5 @@
-use advent2020::day14::{part1, part2};
+use advent2020::day14::Day14;
 
 fn main() {
-    let input = "tests/day14/input";
-    println!("{}", part1::solve(input).unwrap());
-    println!("{}", part2::solve(input).unwrap());
+    advent2020::driver::main(&Day14, "tests/day14/input");
 }
@@ -1,7 +1,5 @@
-use advent2020::day15::{part1, part2};
+use advent2020::day15::Day15;
 
 fn main() {
-    let input = [10, 16, 6, 0, 1, 17];
-    println!("{}", part1::solve(&input));
-    println!("{}", part2::solve(&input));
+    advent2020::driver::main(&Day15, "tests/day15/input");
 }
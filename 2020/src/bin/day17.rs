@@ -1,7 +1,5 @@
-use advent2020::day17::{part1, part2};
+use advent2020::day17::Day17;
 
 fn main() {
-    let input_path = "tests/day17/input";
-    println!("{}", part1::solve(input_path).unwrap());
-    println!("{}", part2::solve(input_path).unwrap());
+    advent2020::driver::main(&Day17, "tests/day17/input");
 }
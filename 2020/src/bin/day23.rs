@@ -1,7 +1,5 @@
-use advent2020::day23::{part1, part2};
+use advent2020::day23::Day23;
 
 fn main() {
-    let input = 624397158;
-    println!("{}", part1::solve(input));
-    println!("{}", part2::solve(input));
+    advent2020::driver::main(&Day23, "tests/day23/input");
 }
@@ -1,41 +1,5 @@
-use std::fs::File;
-use std::io::{self, BufRead as _, BufReader};
-use std::path::Path;
-
-fn load_rows<P: AsRef<Path>>(input: P) -> io::Result<Vec<String>> {
-    BufReader::new(File::open(input)?).lines().collect()
-}
-
-fn solve(rows: &[String], right: usize, down: usize) -> i32 {
-    let mut iter = rows.iter();
-    let row = iter.next().expect("empty table");
-    let width = row.len();
-    let mut x = 0;
-    let mut count = if row.as_bytes()[0] == b'#' { 1 } else { 0 };
-    while let Some(row) = iter.nth(down - 1) {
-        x = (x + right) % width;
-        if row.as_bytes()[x] == b'#' {
-            count += 1
-        }
-    }
-    count
-}
-
-fn solve_part1(rows: &[String]) -> i32 {
-    solve(rows, 3, 1)
-}
-
-fn solve_part2(rows: &[String]) -> i32 {
-    solve(rows, 1, 1)
-        * solve(rows, 3, 1)
-        * solve(rows, 5, 1)
-        * solve(rows, 7, 1)
-        * solve(rows, 1, 2)
-}
+use advent2020::day3::Day3;
 
 fn main() {
-    let input = "tests/day3/input";
-    let rows = load_rows(input).expect("can't read input");
-    println!("{}", solve_part1(&rows));
-    println!("{}", solve_part2(&rows));
+    advent2020::driver::main(&Day3, "tests/day3/input");
 }
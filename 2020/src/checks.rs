@@ -0,0 +1,33 @@
+//! Checks for `aoc check`, which report every problem with a puzzle's input,
+//! rather than just the first, so that hand-written inputs can be fixed in
+//! one go.
+
+use crate::day7;
+use crate::error::ParseError;
+
+/// The days whose inputs can be checked.
+pub const DAYS: [u8; 1] = [7];
+
+/// Returns every problem with the specified input to the specified day's
+/// puzzle, or None if the day has no check.
+pub fn check(day: u8, text: &str) -> Option<Vec<ParseError>> {
+    Some(match day {
+        7 => day7::check(text).err().unwrap_or_default(),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn bags() {
+        let text = fs::read_to_string("tests/day7/sample1").unwrap();
+        assert_eq!(Some(0), check(7, &text).map(|problems| problems.len()));
+        let text = "dim tan bags contain 1 dim tan bag, 2 plaid red bags.";
+        assert_eq!(Some(2), check(7, text).map(|problems| problems.len()));
+        assert!(check(8, text).is_none());
+    }
+}
@@ -0,0 +1,34 @@
+pub mod part1;
+pub mod part2;
+
+use crate::error::Error;
+use crate::parse;
+use crate::solution::Solution;
+use std::collections::HashSet;
+
+/// The entries of an expense report.
+pub type Entries = HashSet<i32>;
+
+/// The sum the elves are looking for.
+const TARGET: i32 = 2020;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Entries;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    // O(N) time, O(N) space
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(parse::integers(text)?.into_iter().collect())
+    }
+
+    fn part1(&self, entries: &Self::Input) -> Result<Self::Output1, Error> {
+        part1::solve(entries)
+    }
+
+    fn part2(&self, entries: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(entries)
+    }
+}
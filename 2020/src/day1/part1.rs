@@ -0,0 +1,14 @@
+use super::{Entries, TARGET};
+use crate::error::Error;
+
+/// Returns the product of the two entries that sum to 2020.
+// O(N) time, O(1) space
+pub fn solve(entries: &Entries) -> Result<i32, Error> {
+    for entry in entries {
+        let delta = TARGET - entry;
+        if entries.contains(&delta) {
+            return Ok(delta * entry);
+        }
+    }
+    Err(Error::NoSolution)
+}
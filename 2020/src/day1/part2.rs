@@ -0,0 +1,23 @@
+use super::{Entries, TARGET};
+use crate::error::Error;
+use std::collections::HashMap;
+
+/// Returns the product of the three entries that sum to 2020.
+// O(N²) time, O(N²) space
+pub fn solve(entries: &Entries) -> Result<i32, Error> {
+    let mut pairs = HashMap::new();
+    for first in entries {
+        for second in entries {
+            if first != second {
+                pairs.insert(first + second, (first, second));
+            }
+        }
+    }
+    for third in entries {
+        let delta = TARGET - third;
+        if let Some((&first, &second)) = pairs.get(&delta) {
+            return Ok(first * second * third);
+        }
+    }
+    Err(Error::NoSolution)
+}
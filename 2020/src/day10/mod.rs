@@ -0,0 +1,96 @@
+pub mod part1;
+pub mod part2;
+
+use crate::error::{Error, ParseError, ParseErrorKind};
+use crate::graph::Graph;
+use crate::parse;
+use crate::solution::Solution;
+use std::iter;
+
+pub const MIN_DELTA: u32 = 1;
+pub const MAX_DELTA: u32 = 3;
+
+/// Parses adapter joltages, one per line.  Returns them in ascending order,
+/// followed by the joltage of the device itself.  Adapters so high that the
+/// device's joltage would overflow are rejected.
+pub fn parse_joltages(text: &str) -> Result<Vec<u32>, Error> {
+    let mut joltages: Vec<u32> = parse::lines(text, |line| {
+        let joltage: u32 = parse::integer(line)?;
+        match joltage.checked_add(MAX_DELTA) {
+            Some(_) => Ok(joltage),
+            None => {
+                let what = format!("joltage {} is too high for a device to follow", joltage);
+                let err = ParseError::with_kind(ParseErrorKind::Number, what);
+                Err(err.within(line, line.trim()))
+            }
+        }
+    })?;
+    joltages.sort_unstable();
+    if let Some(&last) = joltages.last() {
+        joltages.push(last + MAX_DELTA);
+        Ok(joltages)
+    } else {
+        Err(Error::EmptyInput)
+    }
+}
+
+/// Returns which of the specified adapters can plug into which, starting
+/// from the outlet, at 0 jolts.  Nodes are positions in the outlet followed
+/// by the adapters in ascending order, as returned along with the graph, so
+/// that adapters of the same joltage stay apart.  Each adapter has edges to
+/// those after it that can plug into it.
+pub fn graph(adapters: &[u32]) -> (Vec<u32>, Graph<usize>) {
+    let mut chain: Vec<u32> = iter::once(0).chain(adapters.iter().cloned()).collect();
+    chain.sort_unstable();
+    let mut graph = Graph::new();
+    for i in 0..chain.len() {
+        graph.add_node(i);
+    }
+    for (i, &joltage) in chain.iter().enumerate() {
+        let next = (i + 1..chain.len())
+            .take_while(|&j| chain[j] - joltage <= MAX_DELTA)
+            .filter(|&j| chain[j] - joltage >= MIN_DELTA);
+        for j in next {
+            graph.add_edge(i, j, ());
+        }
+    }
+    (chain, graph)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<u32>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        parse_joltages(text)
+    }
+
+    fn part1(&self, adapters: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(adapters))
+    }
+
+    fn part2(&self, adapters: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(adapters)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(vec![1, 4, 7], parse_joltages("4\n1\n").unwrap());
+        let text = format!("1\n {}\n", u32::MAX - 1);
+        match parse_joltages(&text) {
+            Err(Error::Parse(err)) => {
+                assert_eq!(ParseErrorKind::Number, err.kind());
+                assert_eq!((Some(2), Some(2)), (err.line(), err.column()));
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+}
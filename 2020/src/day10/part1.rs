@@ -0,0 +1,41 @@
+use super::{MAX_DELTA, MIN_DELTA};
+use std::mem;
+
+/// Multiplies the number of 1-jolt differences between consecutive adapters
+/// by the number of 3-jolt differences, once the adapters are in order.
+pub fn solve(adapters: &[u32]) -> usize {
+    let mut adapters = adapters.to_vec();
+    adapters.sort_unstable();
+    let deltas: Vec<u32> = adapters
+        .iter()
+        .scan(0, |x, &y| Some(y - mem::replace(x, y)))
+        .collect();
+    let min_count = deltas.iter().cloned().filter(|&d| d == MIN_DELTA).count();
+    let max_count = deltas.iter().cloned().filter(|&d| d == MAX_DELTA).count();
+    min_count * max_count
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::parse_joltages;
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn sample1a() {
+        let text = fs::read_to_string("tests/day10/sample1a").unwrap();
+        assert_eq!(35, solve(&parse_joltages(&text).unwrap()));
+    }
+
+    #[test]
+    fn sample1b() {
+        let text = fs::read_to_string("tests/day10/sample1b").unwrap();
+        assert_eq!(220, solve(&parse_joltages(&text).unwrap()));
+    }
+
+    #[test]
+    fn unsorted() {
+        // Differences of 1, 1, 2, and 3 jolts, once in order.
+        assert_eq!(2, solve(&[4, 1, 2, 7]));
+    }
+}
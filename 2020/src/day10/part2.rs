@@ -0,0 +1,51 @@
+use super::graph;
+use crate::error::Error;
+
+/// Counts the distinct arrangements of adapters that connect the outlet to
+/// the device, which is the adapter of highest joltage.  Adapters of the
+/// same joltage can't plug into each other, so at most one of them is used.
+/// There being too many arrangements to count is no solution.
+pub fn solve(adapters: &[u32]) -> Result<usize, Error> {
+    if adapters.is_empty() {
+        return Ok(0);
+    }
+    let (chain, graph) = graph(adapters);
+    graph
+        .count_paths(&0, &(chain.len() - 1))
+        .ok_or(Error::NoSolution)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::parse_joltages;
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn sample1a() {
+        let text = fs::read_to_string("tests/day10/sample1a").unwrap();
+        assert_eq!(8, solve(&parse_joltages(&text).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn sample1b() {
+        let text = fs::read_to_string("tests/day10/sample1b").unwrap();
+        assert_eq!(19208, solve(&parse_joltages(&text).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn duplicates() {
+        // Either 1-jolt adapter, but not both, leads on to the device.
+        assert_eq!(2, solve(&[1, 1, 4]).unwrap());
+        assert_eq!(2, solve(&[4, 1, 1]).unwrap());
+        assert_eq!(0, solve(&[]).unwrap());
+    }
+
+    #[test]
+    fn long_chain() {
+        let adapters: Vec<u32> = (1..=40).collect();
+        assert_eq!(23_837_527_729, solve(&adapters).unwrap());
+        let adapters: Vec<u32> = (1..=200).collect();
+        assert!(matches!(solve(&adapters), Err(Error::NoSolution)));
+    }
+}
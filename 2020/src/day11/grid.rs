@@ -1,302 +1,291 @@
-use super::{Position, Size, Spot};
+use super::{Neighborhood, SeatingRules, Spot};
+use crate::automaton::{Adjacent, Automaton, Edges, LineOfSight, Storage, Topology};
+use crate::error::Error;
 use crate::error::ParseError;
-use std::cmp;
-use std::error::Error;
+use crate::grid::{self, Position};
+use crate::image::{Image, Rgb};
 use std::fmt;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::fs;
+use std::iter;
 use std::path::Path;
+use std::str::FromStr;
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct Grid {
-    height: usize,
-    width: usize,
-    spots: Vec<Spot>,
+    spots: grid::Grid<Spot>,
 }
 
 impl Grid {
-    fn at(&self, pos: Position) -> Spot {
-        assert!(pos.row < self.height);
-        assert!(pos.column < self.width);
-        self.spots[pos.row * self.width + pos.column]
-    }
-
-    pub fn from_file<P>(input: P) -> Result<Grid, Box<dyn Error>>
+    pub fn from_file<P>(input: P) -> Result<Grid, Error>
     where
         P: AsRef<Path>,
     {
-        let mut lines = BufReader::new(File::open(&input)?).lines();
-        let first_line = match lines.next() {
-            Some(line) => line?,
-            None => todo!("support empty grids"),
-        };
-        if first_line.is_empty() {
-            return Err(Box::new(ParseError::in_file(input, "empty row")));
-        }
-        let width = first_line.len();
-        let mut height = 1;
-        let mut spots = Spot::parse_line(&first_line)?;
-        for line in lines {
-            let line = line?;
-            if line.len() != width {
-                return Err(Box::new(ParseError::at(input, height, "jagged rows")));
-            }
-            spots.extend(Spot::parse_line(&line)?.iter());
-            height += 1;
-        }
-        Ok(Grid {
-            height,
-            width,
-            spots,
-        })
+        Ok(fs::read_to_string(input)?.parse()?)
     }
 
-    pub fn with_size(size: Size) -> Grid {
-        if size.height < 2 || size.width < 2 {
-            todo!("support single-row and single-column grids")
-        } else {
-            Grid {
-                height: size.height,
-                width: size.width,
-                spots: vec![Spot::Floor; size.area()],
-            }
-        }
+    /// Returns the line-of-sight topology of this grid, where people look
+    /// past floor to the first seat in each direction, and beyond the
+    /// specified edges.
+    pub fn sightlines(&self, edges: Edges) -> LineOfSight {
+        LineOfSight::with_edges(&self.spots, |&spot| spot == Spot::Floor, edges)
     }
 
-    fn count_neighbors1(&self, pos: Position) -> usize {
-        // i and j are 0-based indexes into the 3x3 grid around pos.
-        let mut count = 0;
-        let Position { row, column } = pos;
-        for i in 0..3 {
-            for j in 0..3 {
-                if (i, j) != (1, 1)
-                    && row + i > 0
-                    && column + j > 0
-                    && row + i - 1 < self.height
-                    && column + j - 1 < self.width
-                    && self.at(Position {
-                        row: row + i - 1,
-                        column: column + j - 1,
-                    }) == Spot::Occupied
-                {
-                    count += 1;
-                }
-            }
+    /// Returns the seats people look at by the specified rules.
+    fn neighbors(&self, rules: &SeatingRules) -> Neighbors {
+        match rules.neighborhood {
+            Neighborhood::Adjacent => Neighbors::Adjacent(Adjacent::new(&self.spots, rules.edges)),
+            Neighborhood::Sight => Neighbors::Sight(self.sightlines(rules.edges)),
         }
-        count
     }
 
-    fn count_neighbors2_right(&self, pos: Position) -> usize {
-        let row = pos.row;
-        for column in (pos.column + 1)..self.width {
-            match self.at(Position { row, column }) {
-                Spot::Floor => (),
-                Spot::Empty => return 0,
-                Spot::Occupied => return 1,
-            }
-        }
-        0
+    /// Writes the next generation into the specified grid, by the specified
+    /// rules.
+    pub fn next(&self, rules: &SeatingRules, out: &mut Grid) {
+        self.next_parallel(rules, out, 1);
     }
 
-    fn count_neighbors2_up_right(&self, pos: Position) -> usize {
-        let distance = cmp::min(pos.row, self.width - 1 - pos.column);
-        for delta in 1..=distance {
-            let row = pos.row - delta;
-            let column = pos.column + delta;
-            match self.at(Position { row, column }) {
-                Spot::Floor => (),
-                Spot::Empty => return 0,
-                Spot::Occupied => return 1,
-            }
-        }
-        0
+    /// Like [`Grid::next`], but splits the grid into bands of rows, one for
+    /// each of the specified number of threads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if threads is 0.
+    pub fn next_parallel(&self, rules: &SeatingRules, out: &mut Grid, threads: usize) {
+        let neighbors = self.neighbors(rules);
+        self.spots
+            .step_into_parallel(&neighbors, rules, &mut out.spots, threads);
     }
 
-    fn count_neighbors2_up(&self, pos: Position) -> usize {
-        let column = pos.column;
-        for row in (0..pos.row).rev() {
-            match self.at(Position { row, column }) {
-                Spot::Floor => (),
-                Spot::Empty => return 0,
-                Spot::Occupied => return 1,
-            }
-        }
-        0
+    /// Writes the next generation into the specified grid, by the rules of
+    /// part 1: people look only at adjacent seats.
+    pub fn next1(&self, out: &mut Grid) {
+        self.next(&SeatingRules::PART1, out);
     }
 
-    fn count_neighbors2_up_left(&self, pos: Position) -> usize {
-        let distance = cmp::min(pos.row, pos.column);
-        for delta in 1..=distance {
-            let row = pos.row - delta;
-            let column = pos.column - delta;
-            match self.at(Position { row, column }) {
-                Spot::Floor => (),
-                Spot::Empty => return 0,
-                Spot::Occupied => return 1,
-            }
-        }
-        0
+    /// Writes the next generation into the specified grid, by the rules of
+    /// part 2: people look at the first seat in each direction.  Prefer
+    /// [`Grid::next2_along`] to compute more than one generation.
+    pub fn next2(&self, out: &mut Grid) {
+        self.next(&SeatingRules::PART2, out);
     }
 
-    fn count_neighbors2_left(&self, pos: Position) -> usize {
-        let row = pos.row;
-        for column in (0..pos.column).rev() {
-            match self.at(Position { row, column }) {
-                Spot::Floor => (),
-                Spot::Empty => return 0,
-                Spot::Occupied => return 1,
-            }
-        }
-        0
+    /// Like [`Grid::next2`], but with the sightlines already worked out, as
+    /// by [`Grid::sightlines`] for this grid or any other with floor in the
+    /// same places.
+    pub fn next2_along(&self, sightlines: &LineOfSight, out: &mut Grid) {
+        self.spots
+            .step_into(sightlines, &SeatingRules::PART2, &mut out.spots);
     }
 
-    fn count_neighbors2_down_left(&self, pos: Position) -> usize {
-        let distance = cmp::min(self.height - 1 - pos.row, pos.column);
-        for delta in 1..=distance {
-            let row = pos.row + delta;
-            let column = pos.column - delta;
-            match self.at(Position { row, column }) {
-                Spot::Floor => (),
-                Spot::Empty => return 0,
-                Spot::Occupied => return 1,
-            }
-        }
-        0
+    /// Returns the grid once no seat changes from one generation to the next
+    /// by the specified rules, or None if the seats instead change in an
+    /// endless cycle.
+    pub fn settle(&self, rules: &SeatingRules) -> Option<Grid> {
+        self.settle_parallel(rules, 1)
     }
 
-    fn count_neighbors2_down(&self, pos: Position) -> usize {
-        let column = pos.column;
-        for row in (pos.row + 1)..self.height {
-            match self.at(Position { row, column }) {
-                Spot::Floor => (),
-                Spot::Empty => return 0,
-                Spot::Occupied => return 1,
-            }
-        }
-        0
+    /// Like [`Grid::settle`], but computes each generation on the specified
+    /// number of threads, as by [`Grid::next_parallel`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if threads is 0.
+    pub fn settle_parallel(&self, rules: &SeatingRules, threads: usize) -> Option<Grid> {
+        let neighbors = self.neighbors(rules);
+        let mut automaton = Automaton::new(self.spots.clone(), neighbors, *rules);
+        automaton.run_to_fixpoint_parallel(threads)?;
+        Some(Grid {
+            spots: automaton.into_cells(),
+        })
     }
 
-    fn count_neighbors2_down_right(&self, pos: Position) -> usize {
-        let distance = cmp::min(self.height - 1 - pos.row, self.width - 1 - pos.column);
-        for delta in 1..=distance {
-            let row = pos.row + delta;
-            let column = pos.column + delta;
-            match self.at(Position { row, column }) {
-                Spot::Floor => (),
-                Spot::Empty => return 0,
-                Spot::Occupied => return 1,
-            }
-        }
-        0
+    /// Returns the grid once it settles by the rules of part 1.
+    pub fn settle1(&self) -> Option<Grid> {
+        self.settle(&SeatingRules::PART1)
     }
 
-    fn count_neighbors2(&self, pos: Position) -> usize {
-        self.count_neighbors2_right(pos)
-            + self.count_neighbors2_up_right(pos)
-            + self.count_neighbors2_up(pos)
-            + self.count_neighbors2_up_left(pos)
-            + self.count_neighbors2_left(pos)
-            + self.count_neighbors2_down_left(pos)
-            + self.count_neighbors2_down(pos)
-            + self.count_neighbors2_down_right(pos)
+    /// Returns the grid once it settles by the rules of part 2.
+    pub fn settle2(&self) -> Option<Grid> {
+        self.settle(&SeatingRules::PART2)
     }
 
-    pub fn next1(&self, out: &mut Grid) {
-        assert_eq!(out.height, self.height);
-        assert_eq!(out.width, self.width);
-        assert!(self.height > 1);
-        assert!(self.width > 1);
-        out.spots.clear();
-        for row in 0..self.height {
-            for column in 0..self.width {
-                let pos = Position { row, column };
-                let old = self.at(pos);
-                if old == Spot::Floor {
-                    out.spots.push(old);
-                } else {
-                    let count = self.count_neighbors1(pos);
-                    out.spots.push(self.at(pos).next1(count));
-                }
-            }
-        }
+    pub fn pop_count(&self) -> usize {
+        self.spots.iter().filter(|&&s| s == Spot::Occupied).count()
     }
 
-    pub fn next2(&self, out: &mut Grid) {
-        assert_eq!(out.height, self.height);
-        assert_eq!(out.width, self.width);
-        assert!(self.height > 1);
-        assert!(self.width > 1);
-        out.spots.clear();
-        for row in 0..self.height {
-            for column in 0..self.width {
-                let pos = Position { row, column };
-                let old = self.at(pos);
-                if old == Spot::Floor {
-                    out.spots.push(old);
-                } else {
-                    let count = self.count_neighbors2(pos);
-                    out.spots.push(self.at(pos).next2(count));
-                }
+    /// Returns this grid and each generation after it by the specified
+    /// rules, ending with the first generation in which no seat changes.
+    /// Never ends if the seats change in an endless cycle.
+    pub fn generations(self, rules: SeatingRules) -> impl Iterator<Item = Grid> {
+        let neighbors = self.neighbors(&rules);
+        let next = move |grid: &Grid, out: &mut Grid| {
+            grid.spots.step_into(&neighbors, &rules, &mut out.spots);
+        };
+        let mut current = Some(self);
+        iter::from_fn(move || {
+            let grid = current.take()?;
+            let mut after = grid.clone();
+            next(&grid, &mut after);
+            if after != grid {
+                current = Some(after);
             }
-        }
+            Some(grid)
+        })
     }
 
-    pub fn pop_count(&self) -> usize {
-        self.spots.iter().filter(|&&s| s == Spot::Occupied).count()
+    /// Returns a picture of this grid, each spot a square scale pixels on a
+    /// side: occupied seats yellow, empty ones green, and floor dark gray.
+    pub fn image(&self, scale: usize) -> Image {
+        Image::from_grid(&self.spots, scale, |spot| match spot {
+            Spot::Floor => Rgb(64, 64, 64),
+            Spot::Empty => Rgb(0, 160, 64),
+            Spot::Occupied => Rgb(255, 204, 0),
+        })
+    }
+}
+
+/// The seats each person looks at, by some [`SeatingRules`].
+enum Neighbors {
+    Adjacent(Adjacent),
+    Sight(LineOfSight),
+}
+
+impl Topology<Position> for Neighbors {
+    // Inlined so that the automaton's neighbor count is too; otherwise
+    // part 2 runs half again as slow as with LineOfSight alone.
+    #[inline]
+    fn for_each_neighbor<F: FnMut(Position)>(&self, at: Position, visit: F) {
+        match self {
+            Neighbors::Adjacent(adjacent) => adjacent.for_each_neighbor(at, visit),
+            Neighbors::Sight(sightlines) => sightlines.for_each_neighbor(at, visit),
+        }
     }
 
-    pub fn size(&self) -> Size {
-        Size {
-            height: self.height,
-            width: self.width,
+    fn outside_is_live(&self) -> bool {
+        match self {
+            Neighbors::Adjacent(adjacent) => adjacent.outside_is_live(),
+            Neighbors::Sight(sightlines) => sightlines.outside_is_live(),
         }
     }
 }
 
+impl FromStr for Grid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Grid { spots: s.parse()? })
+    }
+}
+
 impl fmt::Display for Grid {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for row in 0..self.height {
-            for column in 0..self.width {
-                let pos = Position { row, column };
-                self.at(pos).fmt(f)?
-            }
-            writeln!(f)?
-        }
-        Ok(())
+        self.spots.fmt(f)
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::error::ParseErrorKind;
+    use crate::grid::NEIGHBORS8;
+
+    /// Returns whether the first seat visible from the specified position
+    /// in each direction is occupied.
+    fn visible_occupied(grid: &Grid, pos: Position) -> Vec<bool> {
+        let sightlines = grid.sightlines(Edges::Open);
+        let seen = NEIGHBORS8.iter().map(|&d| sightlines.first_visible(pos, d));
+        seen.map(|seat| seat.is_some_and(|p| grid.spots[p] == Spot::Occupied))
+            .collect()
+    }
 
     #[test]
-    fn count_neighbors2_sample2a() {
+    fn visible_sample2a() {
         let grid = Grid::from_file("tests/day11/sample2a").unwrap();
-        let pos = Position { row: 4, column: 3 };
-        assert_eq!(1, grid.count_neighbors2_right(pos));
-        assert_eq!(1, grid.count_neighbors2_up_right(pos));
-        assert_eq!(1, grid.count_neighbors2_up(pos));
-        assert_eq!(1, grid.count_neighbors2_up_left(pos));
-        assert_eq!(1, grid.count_neighbors2_left(pos));
-        assert_eq!(1, grid.count_neighbors2_down_left(pos));
-        assert_eq!(1, grid.count_neighbors2_down(pos));
-        assert_eq!(1, grid.count_neighbors2_down_right(pos));
-        assert_eq!(8, grid.count_neighbors2(pos));
+        let pos = Position::new(4, 3);
+        assert_eq!(vec![true; 8], visible_occupied(&grid, pos));
+        let mut next = grid.clone();
+        grid.next2(&mut next);
+        assert_eq!(Spot::Empty, next.spots[pos]);
     }
 
     #[test]
-    fn count_neighbors2_sample2b() {
+    fn visible_sample2b() {
         let grid = Grid::from_file("tests/day11/sample2b").unwrap();
-        let pos = Position { row: 1, column: 1 };
-        assert_eq!(0, grid.count_neighbors2_right(pos));
-        assert_eq!(0, grid.count_neighbors2_up_right(pos));
-        assert_eq!(0, grid.count_neighbors2_up(pos));
-        assert_eq!(0, grid.count_neighbors2_up_left(pos));
-        assert_eq!(0, grid.count_neighbors2_left(pos));
-        assert_eq!(0, grid.count_neighbors2_down_left(pos));
-        assert_eq!(0, grid.count_neighbors2_down(pos));
-        assert_eq!(0, grid.count_neighbors2_down_right(pos));
-        assert_eq!(0, grid.count_neighbors2(pos));
+        let pos = Position::new(1, 1);
+        assert_eq!(vec![false; 8], visible_occupied(&grid, pos));
+        let mut next = grid.clone();
+        grid.next2(&mut next);
+        assert_eq!(Spot::Occupied, next.spots[pos]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = "".parse::<Grid>().unwrap_err();
+        assert_eq!(ParseErrorKind::Empty, err.kind());
+        let err = "L.L\nL.\n".parse::<Grid>().unwrap_err();
+        assert_eq!(Some(2), err.line());
+        let err = "L.L\nLxL\n".parse::<Grid>().unwrap_err();
+        assert_eq!(Some(2), err.column());
+    }
+
+    #[test]
+    fn single_row() {
+        let grid: Grid = "L.LL".parse().unwrap();
+        let mut next = grid.clone();
+        grid.next1(&mut next);
+        assert_eq!("#.##\n", next.to_string());
+    }
+
+    #[test]
+    fn generations() {
+        let grid = Grid::from_file("tests/day11/sample1").unwrap();
+        let last = grid
+            .clone()
+            .generations(SeatingRules::PART2)
+            .last()
+            .unwrap();
+        assert_eq!(Some(last.clone()), grid.settle2());
+        let image = last.image(2);
+        assert_eq!((20, 20), (image.width(), image.height()));
+        assert_eq!(Some(Rgb(255, 204, 0)), image.get(1, 1));
+    }
+
+    #[test]
+    fn settle() {
+        let grid = Grid::from_file("tests/day11/sample1").unwrap();
+        let count = |rules: SeatingRules| grid.settle(&rules).map(|grid| grid.pop_count());
+        assert_eq!(Some(37), count(SeatingRules::PART1));
+        assert_eq!(Some(26), count(SeatingRules::PART2));
+        for &preset in &[SeatingRules::PART1, SeatingRules::PART2] {
+            // A live border can only crowd seats near the edges.
+            let live = SeatingRules {
+                edges: Edges::Live,
+                ..preset
+            };
+            assert!(count(live).unwrap() < count(preset).unwrap());
+            let settled = grid.settle(&live).unwrap();
+            assert_eq!(Some(settled.clone()), settled.settle(&live));
+        }
+        // Neighbors who sit down together leave together, and come back.
+        let loner = SeatingRules {
+            crowd: 1,
+            ..SeatingRules::PART1
+        };
+        assert_eq!(None, count(loner));
+    }
+
+    #[test]
+    fn parallel() {
+        let grid = Grid::from_file("tests/day11/sample1").unwrap();
+        for &rules in &[SeatingRules::PART1, SeatingRules::PART2] {
+            let mut serial = grid.clone();
+            grid.next(&rules, &mut serial);
+            for threads in 1..=12 {
+                let mut parallel = grid.clone();
+                grid.next_parallel(&rules, &mut parallel, threads);
+                assert_eq!(serial, parallel);
+                assert_eq!(grid.settle(&rules), grid.settle_parallel(&rules, threads));
+            }
+        }
     }
 }
@@ -1,12 +1,33 @@
 mod grid;
-mod position;
-mod size;
+mod rules;
 mod spot;
 
 pub mod part1;
 pub mod part2;
 
-use grid::Grid;
-use position::Position;
-use size::Size;
+pub use grid::Grid;
+pub use rules::{Neighborhood, SeatingRules};
+
+use crate::error::Error;
+use crate::solution::Solution;
 use spot::Spot;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(text.parse()?)
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Result<Self::Output1, Error> {
+        part1::solve(grid)
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(grid)
+    }
+}
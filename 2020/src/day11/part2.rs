@@ -1,21 +1,9 @@
 use super::Grid;
-use std::error::Error;
-use std::mem;
-use std::path::Path;
+use crate::error::Error;
 
-pub fn solve<P>(input: P) -> Result<usize, Box<dyn Error>>
-where
-    P: AsRef<Path>,
-{
-    let mut old = Grid::from_file(input)?;
-    let mut new = Grid::with_size(old.size());
-    loop {
-        old.next2(&mut new);
-        if old == new {
-            return Ok(old.pop_count());
-        }
-        mem::swap(&mut old, &mut new);
-    }
+pub fn solve(grid: &Grid) -> Result<usize, Error> {
+    let grid = grid.settle2().ok_or(Error::NoSolution)?;
+    Ok(grid.pop_count())
 }
 
 #[cfg(test)]
@@ -25,6 +13,7 @@ mod test {
     #[test]
     fn solve_sample1() {
         let input = "tests/day11/sample1";
-        assert_eq!(26, solve(input).unwrap());
+        let grid = Grid::from_file(input).unwrap();
+        assert_eq!(26, solve(&grid).unwrap());
     }
 }
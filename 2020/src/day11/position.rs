@@ -1,5 +0,0 @@
-#[derive(Clone, Copy)]
-pub struct Position {
-    pub row: usize,
-    pub column: usize,
-}
@@ -0,0 +1,214 @@
+use super::Spot;
+use crate::automaton::{Edges, Rule};
+use crate::error::{ParseError, ParseErrorKind};
+use crate::parse;
+use crate::solution::Part;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// Which seats people look at to decide whether to sit down or leave.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Neighborhood {
+    /// The eight seats around their own, as in part 1.
+    Adjacent,
+    /// The first seat in each of the eight directions, past any floor, as in
+    /// part 2.
+    Sight,
+}
+
+impl FromStr for Neighborhood {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "adjacent" => Ok(Neighborhood::Adjacent),
+            "sight" => Ok(Neighborhood::Sight),
+            _ => {
+                let what = format!("bad neighborhood {:?}; expected adjacent or sight", s);
+                Err(ParseError::with_kind(ParseErrorKind::Invalid, what))
+            }
+        }
+    }
+}
+
+impl Display for Neighborhood {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Neighborhood::Adjacent => write!(f, "adjacent"),
+            Neighborhood::Sight => write!(f, "sight"),
+        }
+    }
+}
+
+/// How people fill and leave seats: each generation, they look at the seats
+/// in their neighborhood, take an empty seat if at most tolerance of those
+/// are occupied, and leave an occupied one if crowd or more are.
+///
+/// Rules can be written as settings, one `KEY = VALUE` per line, where `#`
+/// starts a comment:
+///
+/// ```text
+/// neighborhood = sight   # or adjacent
+/// edges = open           # or live, wrap, or reflect
+/// crowd = 5
+/// tolerance = 0
+/// ```
+///
+/// Settings left out keep their values from part 1's rules.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SeatingRules {
+    pub neighborhood: Neighborhood,
+    pub edges: Edges,
+    /// The fewest occupied neighbors that make someone leave their seat.
+    pub crowd: usize,
+    /// The most occupied neighbors with which someone takes an empty seat.
+    pub tolerance: usize,
+}
+
+impl SeatingRules {
+    pub const PART1: SeatingRules = SeatingRules {
+        neighborhood: Neighborhood::Adjacent,
+        edges: Edges::Open,
+        crowd: 4,
+        tolerance: 0,
+    };
+
+    pub const PART2: SeatingRules = SeatingRules {
+        neighborhood: Neighborhood::Sight,
+        edges: Edges::Open,
+        crowd: 5,
+        tolerance: 0,
+    };
+
+    /// Returns the rules of the specified part of the puzzle.
+    pub fn preset(part: Part) -> SeatingRules {
+        match part {
+            Part::One => SeatingRules::PART1,
+            Part::Two => SeatingRules::PART2,
+        }
+    }
+
+    /// Returns these rules with the specified settings changed.  Errors are
+    /// located at the line that caused them.
+    pub fn configure(mut self, settings: &str) -> Result<SeatingRules, ParseError> {
+        parse::lines(settings, |line| self.set(line))?;
+        Ok(self)
+    }
+
+    /// Changes the one setting on the specified line, if any.
+    fn set(&mut self, line: &str) -> Result<(), ParseError> {
+        let setting = line.split('#').next().unwrap_or_default();
+        if setting.trim().is_empty() {
+            return Ok(());
+        }
+        let (key, value) = setting.split_once('=').ok_or_else(|| {
+            let what = "bad setting: expected KEY = VALUE";
+            ParseError::with_kind(ParseErrorKind::Unexpected, what).within(line, setting)
+        })?;
+        let locate = |err: ParseError| err.within(line, value);
+        match key.trim() {
+            "neighborhood" => self.neighborhood = value.parse().map_err(locate)?,
+            "edges" => self.edges = value.parse().map_err(locate)?,
+            "crowd" => self.crowd = parse::integer(value).map_err(locate)?,
+            "tolerance" => self.tolerance = parse::integer(value).map_err(locate)?,
+            _ => {
+                let what = format!(
+                    "unknown setting {:?}; expected neighborhood, edges, crowd, or tolerance",
+                    key.trim()
+                );
+                let err = ParseError::with_kind(ParseErrorKind::Invalid, what);
+                return Err(err.within(line, key));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for SeatingRules {
+    fn default() -> Self {
+        SeatingRules::PART1
+    }
+}
+
+impl Rule<Spot> for SeatingRules {
+    fn next(&self, spot: Spot, occupied: usize) -> Spot {
+        match spot {
+            Spot::Empty if occupied <= self.tolerance => Spot::Occupied,
+            Spot::Occupied if occupied >= self.crowd => Spot::Empty,
+            _ => spot,
+        }
+    }
+}
+
+/// Parses settings on top of part 1's rules.
+impl FromStr for SeatingRules {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SeatingRules::default().configure(s)
+    }
+}
+
+/// Shows every setting, in the form they're parsed from.
+impl Display for SeatingRules {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        writeln!(f, "neighborhood = {}", self.neighborhood)?;
+        writeln!(f, "edges = {}", self.edges)?;
+        writeln!(f, "crowd = {}", self.crowd)?;
+        writeln!(f, "tolerance = {}", self.tolerance)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn next() {
+        let rules = SeatingRules::PART1;
+        assert_eq!(Spot::Occupied, rules.next(Spot::Empty, 0));
+        assert_eq!(Spot::Empty, rules.next(Spot::Empty, 1));
+        assert_eq!(Spot::Occupied, rules.next(Spot::Occupied, 3));
+        assert_eq!(Spot::Empty, rules.next(Spot::Occupied, 4));
+        assert_eq!(Spot::Floor, rules.next(Spot::Floor, 0));
+        assert_eq!(Spot::Occupied, SeatingRules::PART2.next(Spot::Occupied, 4));
+    }
+
+    #[test]
+    fn configure() {
+        let rules: SeatingRules = "\
+            # part 2, but pickier\n\
+            neighborhood = sight\n\
+            \n\
+            crowd=5   # as in part 2\n\
+            tolerance = 1\n"
+            .parse()
+            .unwrap();
+        let expected = SeatingRules {
+            tolerance: 1,
+            ..SeatingRules::PART2
+        };
+        assert_eq!(expected, rules);
+        assert_eq!(rules, rules.to_string().parse().unwrap());
+        let wrap = SeatingRules::PART2.configure("edges = wrap").unwrap();
+        assert_eq!(Edges::Wrap, wrap.edges);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = "crowd = 4\nneighbourhood = sight"
+            .parse::<SeatingRules>()
+            .unwrap_err();
+        assert_eq!((ParseErrorKind::Invalid, Some(2)), (err.kind(), err.line()));
+        assert_eq!(Some(1), err.column());
+        let err = "crowd = many".parse::<SeatingRules>().unwrap_err();
+        assert_eq!(
+            (ParseErrorKind::Number, Some(9)),
+            (err.kind(), err.column())
+        );
+        let err = "tolerance 1".parse::<SeatingRules>().unwrap_err();
+        assert_eq!(ParseErrorKind::Unexpected, err.kind());
+        let err = "edges = torus".parse::<SeatingRules>().unwrap_err();
+        assert_eq!(ParseErrorKind::Invalid, err.kind());
+    }
+}
@@ -1,4 +1,6 @@
-use crate::error::ParseError;
+use crate::automaton::Cell;
+use crate::error::{ParseError, ParseErrorKind};
+use std::convert::TryFrom;
 use std::fmt;
 
 /// The state of some position in a Grid.
@@ -10,37 +12,36 @@ pub enum Spot {
 }
 
 impl Spot {
-    fn from_char(c: char) -> Result<Spot, ParseError> {
+    pub fn from_char(c: char) -> Result<Spot, ParseError> {
         match c {
             '.' => Ok(Spot::Floor),
             'L' => Ok(Spot::Empty),
             '#' => Ok(Spot::Occupied),
-            _ => Err(ParseError::new(format!("{}: bad spot", c))),
+            _ => Err(ParseError::with_kind(
+                ParseErrorKind::Unexpected,
+                format!("{}: bad spot; expected any of .L#", c),
+            )),
         }
     }
+}
 
-    /// Returns the next value of this spot, given the specified number of
-    /// neighbors.
-    pub fn next1(&self, n: usize) -> Spot {
-        match self {
-            Spot::Empty if n == 0 => Spot::Occupied,
-            Spot::Occupied if n > 3 => Spot::Empty,
-            _ => *self,
-        }
+/// Occupied seats are live, in that they count against their neighbors, and
+/// floor never changes.
+impl Cell for Spot {
+    fn is_live(self) -> bool {
+        self == Spot::Occupied
     }
 
-    /// Returns the next value of this spot, given the specified number of
-    /// neighbors.
-    pub fn next2(&self, n: usize) -> Spot {
-        match self {
-            Spot::Empty if n == 0 => Spot::Occupied,
-            Spot::Occupied if n > 4 => Spot::Empty,
-            _ => *self,
-        }
+    fn is_inert(self) -> bool {
+        self == Spot::Floor
     }
+}
+
+impl TryFrom<char> for Spot {
+    type Error = ParseError;
 
-    pub fn parse_line(line: &str) -> Result<Vec<Spot>, ParseError> {
-        line.chars().map(Spot::from_char).collect()
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Spot::from_char(c)
     }
 }
 
@@ -1,5 +1,6 @@
-use crate::error::ParseError;
+use crate::error::{ParseError, ParseErrorKind};
 
+#[derive(Clone, Copy, Debug)]
 pub enum Instruction {
     North { distance: usize },
     South { distance: usize },
@@ -10,22 +11,65 @@ pub enum Instruction {
     Forward { distance: usize },
 }
 
+/// Returns the number of counterclockwise quarter turns in the specified
+/// angle, which must be a multiple of 90 degrees.
+pub fn quarter_turns(degrees: usize) -> Result<isize, ParseError> {
+    if degrees.is_multiple_of(90) {
+        Ok((degrees / 90 % 4) as isize)
+    } else {
+        let what = format!("{}°: expected a multiple of 90°", degrees);
+        Err(ParseError::new(what))
+    }
+}
+
 impl Instruction {
-    pub fn parse(mut line: String) -> Result<Instruction, ParseError> {
-        if line.is_empty() {
-            return Err(ParseError::new("empty instruction"));
+    pub fn parse(line: String) -> Result<Instruction, ParseError> {
+        let mut chars = line.chars();
+        let action = chars
+            .next()
+            .ok_or_else(|| ParseError::with_kind(ParseErrorKind::Empty, "empty instruction"))?;
+        let text = chars.as_str();
+        let value: usize = text
+            .parse()
+            .map_err(|err| ParseError::from(err).within(&line, text))?;
+        if matches!(action, 'L' | 'R') {
+            quarter_turns(value).map_err(|err| err.within(&line, text))?;
         }
-        let action = line.as_bytes()[0];
-        let value: usize = line.split_off(1).parse()?;
         Ok(match action {
-            b'N' => Instruction::North { distance: value },
-            b'E' => Instruction::East { distance: value },
-            b'S' => Instruction::South { distance: value },
-            b'W' => Instruction::West { distance: value },
-            b'L' => Instruction::Left { degrees: value },
-            b'R' => Instruction::Right { degrees: value },
-            b'F' => Instruction::Forward { distance: value },
-            _ => return Err(ParseError::new(format!("{}: bad action", action))),
+            'N' => Instruction::North { distance: value },
+            'E' => Instruction::East { distance: value },
+            'S' => Instruction::South { distance: value },
+            'W' => Instruction::West { distance: value },
+            'L' => Instruction::Left { degrees: value },
+            'R' => Instruction::Right { degrees: value },
+            'F' => Instruction::Forward { distance: value },
+            _ => {
+                let what = "bad action: expected any of NESWLRF";
+                let span = 0..action.len_utf8();
+                return Err(ParseError::with_kind(ParseErrorKind::Unexpected, what).with_span(span));
+            }
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_errors() {
+        let err = Instruction::parse("R45".to_owned()).unwrap_err();
+        assert_eq!(Some(1..3), err.span());
+        let err = Instruction::parse("é90".to_owned()).unwrap_err();
+        assert_eq!(ParseErrorKind::Unexpected, err.kind());
+        let err = Instruction::parse(String::new()).unwrap_err();
+        assert_eq!(ParseErrorKind::Empty, err.kind());
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(3, quarter_turns(270).unwrap());
+        assert_eq!(0, quarter_turns(720).unwrap());
+        assert!(quarter_turns(100).is_err());
+    }
+}
@@ -7,8 +7,50 @@ mod vector;
 pub mod part1;
 pub mod part2;
 
+pub use instruction::Instruction;
+
+use crate::error::Error;
+use crate::error::ParseError;
+use crate::solution::Solution;
 use direction::Cardinal as CardinalDirection;
-use instruction::Instruction;
 use point::Point;
 use ship::Ship;
+use std::fs;
+use std::path::Path;
 use vector::Vector;
+
+pub fn parse_instructions(text: &str) -> Result<Vec<Instruction>, ParseError> {
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| {
+            Instruction::parse(line.to_owned()).map_err(|err| err.on_line(index + 1, line))
+        })
+        .collect()
+}
+
+pub fn load_instructions<P>(input: P) -> Result<Vec<Instruction>, Error>
+where
+    P: AsRef<Path>,
+{
+    Ok(parse_instructions(&fs::read_to_string(input)?)?)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Instruction>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(parse_instructions(text)?)
+    }
+
+    fn part1(&self, instructions: &Self::Input) -> Result<Self::Output1, Error> {
+        part1::solve(instructions)
+    }
+
+    fn part2(&self, instructions: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(instructions)
+    }
+}
@@ -1,20 +1,18 @@
+use super::instruction::quarter_turns;
 use super::vector::{EAST, NORTH, SOUTH, WEST};
 use super::{Instruction, Ship};
-use std::error::Error;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
+use crate::error::Error;
 
-pub fn solve<P: AsRef<Path>>(input: P) -> Result<usize, Box<dyn Error>> {
+pub fn solve(instructions: &[Instruction]) -> Result<usize, Error> {
     let mut ship = Ship::new();
-    for line in BufReader::new(File::open(input)?).lines() {
-        ship = match Instruction::parse(line?)? {
+    for instruction in instructions {
+        ship = match *instruction {
             Instruction::North { distance } => ship.strafe(NORTH * distance as isize),
             Instruction::South { distance } => ship.strafe(SOUTH * distance as isize),
             Instruction::East { distance } => ship.strafe(EAST * distance as isize),
             Instruction::West { distance } => ship.strafe(WEST * distance as isize),
-            Instruction::Left { degrees } => ship.turn(degrees as isize),
-            Instruction::Right { degrees } => ship.turn(-(degrees as isize)),
+            Instruction::Left { degrees } => ship.turn(quarter_turns(degrees)?),
+            Instruction::Right { degrees } => ship.turn(-quarter_turns(degrees)?),
             Instruction::Forward { distance } => ship.forward(distance),
         };
     }
@@ -24,9 +22,11 @@ pub fn solve<P: AsRef<Path>>(input: P) -> Result<usize, Box<dyn Error>> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::day12::load_instructions;
 
     #[test]
     fn sample1() {
-        assert_eq!(25, solve("tests/day12/sample1").unwrap());
+        let instructions = load_instructions("tests/day12/sample1").unwrap();
+        assert_eq!(25, solve(&instructions).unwrap());
     }
 }
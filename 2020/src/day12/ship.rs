@@ -22,9 +22,9 @@ impl Ship {
         }
     }
 
-    pub fn turn(&self, degrees: isize) -> Ship {
+    pub fn turn(&self, quarter_turns: isize) -> Ship {
         Ship {
-            dir: self.dir.turn(degrees),
+            dir: self.dir.turn(quarter_turns),
             pos: self.pos,
         }
     }
@@ -41,7 +41,7 @@ impl Ship {
 
     /// Returns this Ship's Manhattan distance from the origin.
     pub fn distance(&self) -> usize {
-        self.pos.x.abs() as usize + self.pos.y.abs() as usize
+        self.pos.x.unsigned_abs() + self.pos.y.unsigned_abs()
     }
 
     pub fn wayward(&self, way: Vector, distance: usize) -> Ship {
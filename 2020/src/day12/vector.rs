@@ -8,13 +8,14 @@ pub struct Vector {
 }
 
 impl Vector {
-    pub fn rotate(&self, degrees: isize) -> Vector {
-        let (sin, cos) = match degrees {
+    /// Rotates this vector counterclockwise by the specified number of
+    /// quarter turns.  Negative turns are clockwise.
+    pub fn rotate(&self, quarter_turns: isize) -> Vector {
+        let (sin, cos) = match quarter_turns.rem_euclid(4) {
             0 => (0, 1),
-            90 | -270 => (1, 0),
-            180 | -180 => (0, -1),
-            270 | -90 => (-1, 0),
-            _ => panic!("{}°: bad angle", degrees),
+            1 => (1, 0),
+            2 => (0, -1),
+            _ => (-1, 0),
         };
         Vector {
             dx: self.dx * cos - self.dy * sin,
@@ -1,2 +1,74 @@
 pub mod part1;
 pub mod part2;
+
+use crate::error::Error;
+use crate::error::ParseError;
+use crate::solution::Solution;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Parses a comma-separated list of bus IDs, in which out-of-service buses
+/// are marked 'x'.
+pub fn parse_buses(line: &str) -> Vec<Option<usize>> {
+    line.split_terminator(',').map(|s| s.parse().ok()).collect()
+}
+
+/// The notes from part 1 of the puzzle: your earliest possible departure
+/// time, and the IDs of buses in service (or None for buses out of service).
+#[derive(Debug)]
+pub struct Notes {
+    pub timestamp: usize,
+    pub buses: Vec<Option<usize>>,
+}
+
+impl FromStr for Notes {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.split_terminator('\n');
+        let timestamp: usize = lines
+            .next()
+            .ok_or_else(|| ParseError::new("empty input"))?
+            .parse()?;
+        let buses = parse_buses(
+            lines
+                .next()
+                .ok_or_else(|| ParseError::new("expected two lines, got only one"))?,
+        );
+        if lines.next().is_some() {
+            Err(ParseError::new("expected only two lines"))
+        } else if buses.iter().all(Option::is_none) {
+            Err(ParseError::new("can't find any buses"))
+        } else {
+            Ok(Notes { timestamp, buses })
+        }
+    }
+}
+
+pub fn load_notes<P>(input: P) -> Result<Notes, Error>
+where
+    P: AsRef<Path>,
+{
+    Ok(fs::read_to_string(input)?.parse()?)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Notes;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(text.parse()?)
+    }
+
+    fn part1(&self, notes: &Self::Input) -> Result<Self::Output1, Error> {
+        part1::solve(notes)
+    }
+
+    fn part2(&self, notes: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(notes)
+    }
+}
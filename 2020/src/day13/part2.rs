@@ -1,25 +1,12 @@
-/// This file is a line-by-line translation of src/bin/day13_part2.py.  See
-/// that file for a high-level explanation of the algorithm implemented here.
-use crate::error::{NoSolution, ParseError};
-use std::error::Error;
-use std::fs;
-
-fn is_prime(n: usize) -> bool {
-    if n < 2 {
-        false
-    } else if n % 2 == 0 {
-        n == 2
-    } else {
-        let mut i = 3;
-        while i * i <= n {
-            if n % i == 0 {
-                return false;
-            }
-            i += 2;
-        }
-        true
-    }
-}
+/// This file began as a line-by-line translation of src/bin/day13_part2.py.
+/// See that file for a high-level explanation of the problem in terms of
+/// remainders.  This version applies the Chinese Remainder Theorem in its
+/// general form, combining buses one at a time by the extended Euclidean
+/// algorithm, so the bus IDs needn't be pairwise coprime, and inputs with no
+/// answer are reported rather than assumed away.
+use super::Notes;
+use crate::error::Error;
+use std::convert::TryFrom;
 
 #[derive(Debug)]
 struct BusDelay {
@@ -33,78 +20,86 @@ struct BusRemainder {
     remainder: usize,
 }
 
-fn parse_line(line: &str) -> Vec<BusDelay> {
-    line.split(',')
+fn delays(buses: &[Option<usize>]) -> Vec<BusDelay> {
+    buses
+        .iter()
         .enumerate()
-        .filter_map(|(d, s)| s.parse().ok().map(|id| BusDelay { id, delay: d }))
+        .filter_map(|(d, bus)| bus.map(|id| BusDelay { id, delay: d }))
         .collect()
 }
 
-fn load_input(input_path: &str) -> Result<Vec<BusDelay>, Box<dyn Error>> {
-    let text = fs::read_to_string(input_path)?;
-    let line = text
-        .split_terminator('\n')
-        .nth(1)
-        .ok_or_else(|| ParseError::in_file(input_path, "expected two lines"))?;
-    Ok(parse_line(line))
-}
-
-/// Returns an integer N such that multiplicand * N % bus_id == remainder.
-fn find_multiplier(
-    multiplicand: usize,
-    bus_id: usize,
-    remainder: usize,
-) -> Result<usize, NoSolution> {
-    for n in 0..bus_id {
-        if multiplicand * n % bus_id == remainder {
-            return Ok(n);
-        }
+/// Returns the greatest common divisor of a and b, along with x and y such
+/// that a·x + b·y is that divisor.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
     }
-    Err(NoSolution)
+    (r0, x0, y0)
 }
 
-fn find_timestamp(bus_remainders: &[BusRemainder]) -> Result<usize, NoSolution> {
-    let product: usize = bus_remainders.iter().map(|bus| bus.id).product();
-    let mut sum = 0;
+/// Returns the least timestamp having the specified remainder for each bus.
+/// Once the timestamp satisfies some buses, adding any multiple of the least
+/// common multiple of their IDs ("period") keeps them satisfied, so each new
+/// bus is satisfied by adding the multiple that solves a congruence modulo
+/// its ID.  That has a solution only if the gcd of the period and the ID
+/// divides how far the timestamp is from the bus's remainder; otherwise no
+/// timestamp works.  The timestamp stays below the period, which may grow
+/// well past the answer; only timestamps too large for a usize, or periods
+/// too large for a u128, count as no answer.
+fn find_timestamp(bus_remainders: &[BusRemainder]) -> Result<usize, Error> {
+    let mut timestamp: u128 = 0;
+    let mut period: u128 = 1;
     for bus in bus_remainders {
-        let multiplicand = product / bus.id;
-        let multiplier = find_multiplier(multiplicand, bus.id, bus.remainder)?;
-        let term = multiplicand * multiplier;
-        sum += term;
+        let id = bus.id as u128;
+        // Solve period·k ≡ remainder - timestamp (mod id) for k.  Only the
+        // period modulo the ID matters, which keeps the gcd's terms small.
+        let gap = (bus.remainder as u128 % id + id - timestamp % id) % id;
+        let (gcd, inverse, _) = extended_gcd((period % id) as i128, id as i128);
+        let gcd = gcd as u128;
+        if !gap.is_multiple_of(gcd) {
+            return Err(Error::NoSolution);
+        }
+        let step = id / gcd;
+        let inverse = inverse.rem_euclid(step as i128) as u128;
+        let k = (gap / gcd % step) * inverse % step;
+        timestamp = period
+            .checked_mul(k)
+            .and_then(|offset| timestamp.checked_add(offset))
+            .ok_or(Error::NoSolution)?;
+        period = period.checked_mul(step).ok_or(Error::NoSolution)?;
     }
-    Ok(sum % product)
+    usize::try_from(timestamp).map_err(|_| Error::NoSolution)
 }
 
-fn solve_buses(buses: Vec<BusDelay>) -> Result<usize, NoSolution> {
-    assert!(buses.iter().all(|&BusDelay { id, .. }| is_prime(id)));
+fn solve_buses(buses: Vec<BusDelay>) -> Result<usize, Error> {
+    // A bus with ID zero never departs.
+    if buses.iter().any(|bus| bus.id == 0) {
+        return Err(Error::NoSolution);
+    }
     let bus_remainders: Vec<BusRemainder> = buses
         .iter()
         .map(|bus| BusRemainder {
             id: bus.id,
-            remainder: if bus.id < bus.delay {
-                (bus.id * bus.delay - bus.delay) % bus.id
-            } else {
-                (bus.id - bus.delay) % bus.id
-            },
+            remainder: (bus.id - bus.delay % bus.id) % bus.id,
         })
         .collect();
     find_timestamp(&bus_remainders)
 }
 
-pub fn solve(input_path: &str) -> Result<usize, Box<dyn Error>> {
-    Ok(solve_buses(load_input(input_path)?)?)
+pub fn solve(notes: &Notes) -> Result<usize, Error> {
+    solve_buses(delays(&notes.buses))
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-
-    #[test]
-    fn test_is_prime() {
-        let want = vec![2, 3, 5, 7, 11, 13, 17, 19];
-        let got: Vec<_> = (0..20).filter(|&i| is_prime(i)).collect();
-        assert_eq!(want, got);
-    }
+    use crate::day13::{load_notes, parse_buses};
 
     #[test]
     fn test_find_timestamp() {
@@ -127,8 +122,8 @@ mod test {
 
     #[test]
     fn solve() {
-        let input = load_input("tests/day13/sample1").unwrap();
-        assert_eq!(1068781, solve_buses(input).unwrap());
+        let notes = load_notes("tests/day13/sample1").unwrap();
+        assert_eq!(1068781, solve_buses(delays(&notes.buses)).unwrap());
         for &(line, want) in &[
             ("17,x,13,19", 3417),
             ("67,7,59,61", 754018),
@@ -136,7 +131,44 @@ mod test {
             ("67,7,x,59,61", 1261476),
             ("1789,37,47,1889", 1202161486),
         ] {
-            assert_eq!(want, solve_buses(parse_line(line)).unwrap())
+            assert_eq!(want, solve_buses(delays(&parse_buses(line))).unwrap())
         }
     }
+
+    #[test]
+    fn composite_ids() {
+        // t = 4 satisfies 4 | t, 6 | t + 2, and 9 | t + 5.
+        assert_eq!(4, solve_buses(delays(&parse_buses("4,x,6,x,x,9"))).unwrap());
+        // No t is both even and odd.
+        let err = solve_buses(delays(&parse_buses("4,2"))).unwrap_err();
+        assert!(matches!(err, Error::NoSolution));
+        let err = solve_buses(delays(&parse_buses("6,x,x,10"))).unwrap_err();
+        assert!(matches!(err, Error::NoSolution));
+        let err = solve_buses(delays(&parse_buses("0"))).unwrap_err();
+        assert!(matches!(err, Error::NoSolution));
+    }
+
+    #[test]
+    fn large_ids() {
+        let buses = parse_buses("1000000007,1000000009");
+        assert_eq!(500000008500000035, solve_buses(delays(&buses)).unwrap());
+        // The IDs' product is past usize::MAX, but t = 1000 satisfies all.
+        let (a, b) = ((1 << 32) + 15, (1 << 32) + 17);
+        let buses = vec![
+            BusDelay {
+                id: a,
+                delay: a - 1000,
+            },
+            BusDelay {
+                id: b,
+                delay: b - 1000,
+            },
+            BusDelay { id: 7, delay: 1 },
+        ];
+        assert_eq!(1000, solve_buses(buses).unwrap());
+        // The least timestamp for these is past usize::MAX.
+        let (a, b) = ((1 << 63) + 1, (1 << 63) + 3);
+        let buses = vec![BusDelay { id: a, delay: 0 }, BusDelay { id: b, delay: 1 }];
+        assert!(matches!(solve_buses(buses), Err(Error::NoSolution)));
+    }
 }
@@ -1,4 +1,4 @@
-use crate::error::ParseError;
+use crate::error::{ParseError, ParseErrorKind};
 use std::str::FromStr;
 
 pub const LEN: usize = 36;
@@ -26,7 +26,7 @@ impl FromStr for Address {
         let address: usize = s.parse()?;
         if MAX < address {
             let what = format!("{}: address is too large", address);
-            Err(ParseError::new(what))
+            Err(ParseError::with_kind(ParseErrorKind::Number, what))
         } else {
             Ok(Address(address))
         }
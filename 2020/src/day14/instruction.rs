@@ -1,32 +1,42 @@
 use super::address::Address;
 use super::mask::Mask;
 use super::value::Value;
-use crate::error::ParseError;
+use crate::error::{ParseError, ParseErrorKind};
 
 const PREFIX: &str = "mem[";
 
 fn parse_address(line: &str) -> Result<Address, ParseError> {
     if !line.starts_with(PREFIX) {
-        let what = format!("{}: bad instruction: expected '{}'", line, PREFIX);
-        return Err(ParseError::new(what));
+        let what = format!("bad instruction: expected 'mask' or '{}'", PREFIX);
+        let end = line.find(' ').unwrap_or(line.len());
+        return Err(ParseError::with_kind(ParseErrorKind::Unexpected, what).with_span(0..end));
     }
     let end = line.find(']').ok_or_else(|| {
-        let what = format!("{}: bad instruction: missing ']'", line);
-        ParseError::new(what)
+        ParseError::with_kind(
+            ParseErrorKind::UnexpectedEnd,
+            "bad instruction: missing ']'",
+        )
+        .with_span(line.len()..line.len())
     })?;
-    line[PREFIX.len()..end].parse()
+    let text = &line[PREFIX.len()..end];
+    text.parse()
+        .map_err(|err: ParseError| err.within(line, text))
 }
 
 fn parse_value(line: &str) -> Result<Value, ParseError> {
     let mut parts = line.splitn(3, ' ').skip(1);
     let text = match (parts.next(), parts.next()) {
         (Some("="), Some(text)) => Ok(text),
-        _ => Err(ParseError::new(format!("{}: expected value", line))),
+        _ => Err(
+            ParseError::with_kind(ParseErrorKind::UnexpectedEnd, "expected '= VALUE'")
+                .with_span(line.len()..line.len()),
+        ),
     }?;
     text.parse()
+        .map_err(|err: ParseError| err.within(line, text))
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum Instruction {
     Assign(Address, Value),
     Mask(Mask),
@@ -45,3 +55,17 @@ impl Instruction {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn error_spans() {
+        let err = Instruction::parse("mem[8] = 1x1").unwrap_err();
+        assert_eq!(Some(9..12), err.span());
+        let err = Instruction::parse("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX2X").unwrap_err();
+        assert_eq!(ParseErrorKind::Unexpected, err.kind());
+        assert_eq!(Some(41..42), err.span());
+    }
+}
@@ -1,7 +1,7 @@
 use super::address::Address;
 use super::masked::Masked;
 use super::value::Value;
-use crate::error::ParseError;
+use crate::error::{ParseError, ParseErrorKind};
 
 const LEN: usize = 36;
 
@@ -9,11 +9,14 @@ fn slice_text(line: &str) -> Result<&str, ParseError> {
     let mut parts = line.splitn(3, ' ');
     match (parts.next(), parts.next(), parts.next()) {
         (Some("mask"), Some("="), Some(text)) => Ok(text),
-        _ => Err(ParseError::new(format!("{}: expected mask", line))),
+        _ => Err(
+            ParseError::with_kind(ParseErrorKind::UnexpectedEnd, "expected 'mask = BITS'")
+                .with_span(line.len()..line.len()),
+        ),
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Mask {
     clear: usize,
     set: usize,
@@ -28,8 +31,9 @@ impl Mask {
                 b'0' => mask.clear |= 1 << (LEN - i - 1),
                 b'1' => mask.set |= 1 << (LEN - i - 1),
                 _ => {
-                    let what = format!("{}: bad mask bit", b);
-                    return Err(ParseError::new(what));
+                    let what = "bad mask bit: expected X, 0, or 1";
+                    return Err(ParseError::with_kind(ParseErrorKind::Unexpected, what)
+                        .with_span(i..(i + 1)));
                 }
             };
         }
@@ -54,13 +58,15 @@ impl Mask {
     }
 
     pub fn parse_line<S: AsRef<str>>(line: S) -> Result<Mask, ParseError> {
-        let text = slice_text(line.as_ref())?;
-        if text.len() == LEN {
+        let line = line.as_ref();
+        let text = slice_text(line)?;
+        let result = if text.len() == LEN {
             Mask::parse(text)
         } else {
-            let what = format!("{}: bad mask: expected {} bits", text, LEN);
+            let what = format!("bad mask: expected {} bits", LEN);
             Err(ParseError::new(what))
-        }
+        };
+        result.map_err(|err| err.within(line, text))
     }
 }
 
@@ -8,3 +8,61 @@ mod value;
 
 pub mod part1;
 pub mod part2;
+
+use crate::error::Error;
+use crate::error::ParseError;
+use crate::solution::Solution;
+use instruction::Instruction;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The initialization program for the ferry's docking computer.
+#[derive(Debug)]
+pub struct Program {
+    instructions: Vec<Instruction>,
+}
+
+impl Program {
+    pub fn from_file<P>(input: P) -> Result<Program, Error>
+    where
+        P: AsRef<Path>,
+    {
+        Ok(fs::read_to_string(input)?.parse()?)
+    }
+}
+
+impl FromStr for Program {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let instructions = s
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                Instruction::parse(line).map_err(|err| err.on_line(index + 1, line))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Program { instructions })
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Program;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(text.parse()?)
+    }
+
+    fn part1(&self, program: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(program))
+    }
+
+    fn part2(&self, program: &Self::Input) -> Result<Self::Output2, Error> {
+        Ok(part2::solve(program))
+    }
+}
@@ -1,19 +1,12 @@
-use super::instruction::Instruction;
 use super::machine::Machine;
-use std::error::Error;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
+use super::Program;
 
-pub fn solve<P>(input: P) -> Result<usize, Box<dyn Error>>
-where
-    P: AsRef<Path>,
-{
+pub fn solve(program: &Program) -> usize {
     let mut machine = Machine::new();
-    for line in BufReader::new(File::open(input)?).lines() {
-        machine.execute1(Instruction::parse(line?)?);
+    for instruction in program.instructions.iter().cloned() {
+        machine.execute1(instruction);
     }
-    Ok(machine.sum())
+    machine.sum()
 }
 
 #[cfg(test)]
@@ -22,7 +15,7 @@ mod test {
 
     #[test]
     fn solve_sample1() {
-        let input = "tests/day14/sample1";
-        assert_eq!(165, solve(input).unwrap());
+        let program = Program::from_file("tests/day14/sample1").unwrap();
+        assert_eq!(165, solve(&program));
     }
 }
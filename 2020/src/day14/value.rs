@@ -1,4 +1,4 @@
-use crate::error::ParseError;
+use crate::error::{ParseError, ParseErrorKind};
 use std::str::FromStr;
 
 const MAX: usize = (1 << 36) - 1;
@@ -25,7 +25,7 @@ impl FromStr for Value {
         let value: usize = s.parse()?;
         if MAX < value {
             let what = format!("{}: value is too large", value);
-            Err(ParseError::new(what))
+            Err(ParseError::with_kind(ParseErrorKind::Number, what))
         } else {
             Ok(Value(value))
         }
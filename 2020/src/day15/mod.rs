@@ -2,3 +2,36 @@ mod game;
 
 pub mod part1;
 pub mod part2;
+
+use crate::error::Error;
+use crate::error::ParseError;
+use crate::solution::Solution;
+
+/// Parses a comma-separated list of starting numbers.
+pub fn parse_numbers(text: &str) -> Result<Vec<usize>, ParseError> {
+    Ok(text
+        .trim()
+        .split(',')
+        .map(|s| s.trim().parse())
+        .collect::<Result<_, _>>()?)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(parse_numbers(text)?)
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(numbers))
+    }
+
+    fn part2(&self, numbers: &Self::Input) -> Result<Self::Output2, Error> {
+        Ok(part2::solve(numbers))
+    }
+}
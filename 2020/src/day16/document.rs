@@ -0,0 +1,107 @@
+use crate::error::{ParseError, ParseErrorKind};
+use crate::parse::{self, Paragraph};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+#[derive(Debug, Eq, Hash, PartialEq)]
+pub struct Rule {
+    pub field: String,
+    pub ranges: (RangeInclusive<u64>, RangeInclusive<u64>),
+}
+
+impl Rule {
+    pub fn is_valid(&self, value: u64) -> bool {
+        self.ranges.0.contains(&value) || self.ranges.1.contains(&value)
+    }
+}
+
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sep = ": ";
+        let pos = s.find(": ").ok_or_else(|| {
+            let what = format!(r#"bad rule: expected separator "{}""#, sep);
+            ParseError::with_kind(ParseErrorKind::UnexpectedEnd, what).with_span(s.len()..s.len())
+        })?;
+        let (field, tail) = s.split_at(pos + sep.len());
+        let parts = tail.split(' ').collect::<Vec<_>>();
+        let range = |part: &str| parse::range(part).map_err(|err| err.within(s, part));
+        if let [first, "or", second] = parts.as_slice() {
+            Ok(Rule {
+                field: field[0..(field.len() - sep.len())].to_owned(),
+                ranges: (range(first)?, range(second)?),
+            })
+        } else {
+            let what = "bad rule: expected RANGE or RANGE";
+            Err(ParseError::with_kind(ParseErrorKind::Unexpected, what).within(s, tail))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Ticket {
+    pub values: Vec<u64>,
+}
+
+impl FromStr for Ticket {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut values = Vec::new();
+        for part in s.split(',') {
+            values.push(parse::integer(part).map_err(|err| err.within(s, part))?);
+        }
+        Ok(Ticket { values })
+    }
+}
+
+#[derive(Debug)]
+pub struct Document {
+    pub rules: Vec<Rule>,
+    pub ticket: Ticket,       // your ticket
+    pub tickets: Vec<Ticket>, // nearby tickets
+}
+
+/// Parses the lines of the specified paragraph that follow its header, if
+/// it has the specified header.
+fn parse_section<T>(paragraph: &Paragraph, header: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    let (heading, body) = paragraph.split_heading();
+    if heading != header {
+        let what = format!("expected {:?}", header);
+        let err = ParseError::with_kind(ParseErrorKind::Unexpected, what);
+        return Err(paragraph.locate(0, err.with_span(0..heading.len())));
+    }
+    body.parse_lines(str::parse)
+}
+
+impl FromStr for Document {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (rules, mine, nearby) = match parse::paragraphs(s)[..] {
+            [ref rules, ref mine, ref nearby] => (
+                rules.parse_lines(str::parse)?,
+                parse_section(mine, "your ticket:")?,
+                parse_section(nearby, "nearby tickets:")?,
+            ),
+            _ => {
+                let what = "expected rules, your ticket, and nearby tickets";
+                return Err(ParseError::new(what));
+            }
+        };
+        if mine.len() != 1 {
+            let what = "expected exactly one ticket of your own";
+            return Err(ParseError::new(what));
+        }
+        let ticket = mine.into_iter().next().unwrap();
+        Ok(Document {
+            rules,
+            ticket,
+            tickets: nearby,
+        })
+    }
+}
@@ -0,0 +1,29 @@
+mod document;
+
+pub mod part1;
+pub mod part2;
+
+pub use document::{Document, Rule, Ticket};
+
+use crate::error::Error;
+use crate::solution::Solution;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Document;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(text.parse()?)
+    }
+
+    fn part1(&self, doc: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(doc))
+    }
+
+    fn part2(&self, doc: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(doc)
+    }
+}
@@ -0,0 +1,22 @@
+use super::Document;
+
+/// Sums the values on nearby tickets that aren't valid for any field.
+pub fn solve(doc: &Document) -> u64 {
+    doc.tickets
+        .iter()
+        .flat_map(|ticket| ticket.values.iter())
+        .filter(|&value| !doc.rules.iter().any(|rule| rule.is_valid(*value)))
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn sample1() {
+        let text = fs::read_to_string("tests/day16/sample1").unwrap();
+        assert_eq!(71, solve(&text.parse().unwrap()));
+    }
+}
@@ -0,0 +1,98 @@
+use super::{Document, Rule, Ticket};
+use crate::error::Error;
+use std::collections::{HashMap, HashSet};
+use std::iter::FromIterator;
+
+fn collect_valid_tickets(doc: &Document) -> impl Iterator<Item = &Ticket> {
+    doc.tickets.iter().filter(move |ticket| {
+        !ticket
+            .values
+            .iter()
+            .any(|value| doc.rules.iter().all(|rule| !rule.is_valid(*value)))
+    })
+}
+
+fn enumerate_values(ticket: &Ticket) -> impl Iterator<Item = (usize, &u64)> {
+    ticket.values.iter().enumerate()
+}
+
+/// Maps columns (by index) to sets of rules that rejected any values in them.
+fn exclude_rules_by_column(doc: &Document) -> Vec<HashSet<&Rule>> {
+    let mut excluded_rules = vec![HashSet::new(); doc.ticket.values.len()];
+    let tickets = collect_valid_tickets(doc);
+    for (column, &value) in tickets.flat_map(enumerate_values) {
+        let rules = doc.rules.iter().filter(|rule| !rule.is_valid(value));
+        excluded_rules[column].extend(rules);
+    }
+    excluded_rules
+}
+
+fn complement<'doc>(
+    sets: &[HashSet<&'doc Rule>],
+    universe: &'doc [Rule],
+) -> HashMap<usize, HashSet<&'doc Rule>> {
+    let universe = HashSet::from_iter(universe.iter());
+    sets.iter().map(|set| &universe - set).enumerate().collect()
+}
+
+/// Maps each rule to its column index.
+fn map_columns(doc: &Document) -> Result<HashMap<&Rule, usize>, Error> {
+    // Map columns to sets of rules that cannot apply to them ("exclusions").
+    // Complement the sets to find applicable rules by column ("candidates").
+    // While candidates remain (i.e., any column is mapped to multiple rules):
+    //      Find the first column having exactly one candidate.
+    //          If none, return Error::NoSolution.
+    //      Graduate that entry to a match:
+    //          Map the rule to the column ("columns").
+    //          Remove the entry from the candidates map.
+    //      Remove the matched rule from all remaining candidate sets.
+    // Return the final mapping from rules to columns.
+    let exclusions = exclude_rules_by_column(doc);
+    let mut candidates = complement(&exclusions, &doc.rules);
+    let mut columns = HashMap::new();
+    while !candidates.is_empty() {
+        let (&column, rule) = candidates
+            .iter_mut()
+            .find_map(|(column, rules)| {
+                if rules.len() == 1 {
+                    rules.drain().next().map(|rule| (column, rule))
+                } else {
+                    None
+                }
+            })
+            .ok_or(Error::NoSolution)?;
+        candidates.remove(&column);
+        columns.insert(rule, column);
+        for rules in candidates.values_mut() {
+            rules.remove(&rule);
+        }
+    }
+    Ok(columns)
+}
+
+/// Multiplies together the values of the fields on your ticket whose names
+/// begin with "departure".
+pub fn solve(doc: &Document) -> Result<u64, Error> {
+    Ok(map_columns(doc)?
+        .iter()
+        .filter_map(|(rule, &column)| {
+            if rule.field.starts_with("departure") {
+                doc.ticket.values.get(column)
+            } else {
+                None
+            }
+        })
+        .product())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn sample2() {
+        let text = fs::read_to_string("tests/day16/sample2").unwrap();
+        assert_eq!(1, solve(&text.parse().unwrap()).unwrap());
+    }
+}
@@ -1,4 +1,5 @@
-use crate::error::ParseError;
+use crate::error::{ParseError, ParseErrorKind};
+use std::convert::TryFrom;
 use std::fmt::{self, Display, Formatter};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -12,7 +13,10 @@ impl Cube {
         match c {
             '#' => Ok(Cube::Active),
             '.' => Ok(Cube::Inactive),
-            _ => Err(ParseError::new(format!("bad cube: {}", c))),
+            _ => Err(ParseError::with_kind(
+                ParseErrorKind::Unexpected,
+                format!("bad cube {:?}: expected '#' or '.'", c),
+            )),
         }
     }
 
@@ -31,6 +35,24 @@ impl Cube {
     }
 }
 
+impl From<bool> for Cube {
+    fn from(active: bool) -> Self {
+        if active {
+            Cube::Active
+        } else {
+            Cube::Inactive
+        }
+    }
+}
+
+impl TryFrom<char> for Cube {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Cube::parse(c)
+    }
+}
+
 impl Display for Cube {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
@@ -1,8 +1,144 @@
 mod cube;
-mod grid3d;
-mod grid4d;
-mod point3d;
-mod point4d;
 
 pub mod part1;
 pub mod part2;
+
+pub use cube::Cube;
+
+use crate::automaton::{Automaton, Lattice};
+use crate::error::Error;
+use crate::grid::Grid;
+use crate::image::{Image, Rgb};
+use crate::solution::Solution;
+use crate::space::{Point, SparseGrid};
+use std::ops::RangeInclusive;
+
+/// The initial state of a pocket dimension: a 2D slice of cubes, all others
+/// being inactive.
+pub type Slice = Grid<Cube>;
+
+/// The number of cycles in the boot process.
+pub const CYCLES: usize = 6;
+
+/// The numbers of dimensions in which pocket dimensions can be simulated.
+pub const DIMENSIONS: RangeInclusive<usize> = 2..=6;
+
+/// Returns whether a cube is active after a cycle, given whether it is active
+/// now and how many of its neighbors are.
+pub fn rule(active: bool, neighbors: usize) -> bool {
+    Cube::from(active).next(neighbors).is_active()
+}
+
+/// Returns the number of active cubes after the specified number of cycles
+/// in an N-dimensional pocket dimension initialized from the specified slice.
+// O(C·A·3^N) time, where A is the greatest number of active cubes in any cycle
+pub fn simulate<const N: usize>(slice: &Slice, cycles: usize) -> usize {
+    let cubes = SparseGrid::<N>::from_plane(slice, |cube| cube.is_active());
+    Automaton::new(cubes, Lattice, rule).run(cycles).len()
+}
+
+/// Returns the active cubes of an N-dimensional pocket dimension initialized
+/// from the specified slice, before the first cycle and after each of the
+/// specified number of cycles.
+pub fn generations<const N: usize>(
+    slice: &Slice,
+    cycles: usize,
+) -> impl Iterator<Item = SparseGrid<N>> {
+    let cubes = SparseGrid::<N>::from_plane(slice, |cube| cube.is_active());
+    let mut automaton = Automaton::new(cubes, Lattice, rule);
+    (0..=cycles).map(move |cycle| {
+        if cycle > 0 {
+            automaton.step();
+        }
+        automaton.cells().clone()
+    })
+}
+
+/// Returns pictures of the [`generations`] of a pocket dimension, showing
+/// every 2D slice that cubes can reach within the specified number of
+/// cycles, as laid out by [`SparseGrid::mosaic`].  Each cube is a square
+/// scale pixels on a side: active cubes yellow and inactive ones dark gray.
+pub fn images<const N: usize>(
+    slice: &Slice,
+    cycles: usize,
+    scale: usize,
+) -> impl Iterator<Item = Image> {
+    // Cubes spread at most one step along each axis per cycle.
+    let reach = Point([cycles as isize; N]);
+    let corner = Point::on_plane(slice.width() as isize - 1, slice.height() as isize - 1);
+    let (min, max) = (Point::ORIGIN - reach, corner + reach);
+    generations::<N>(slice, cycles).map(move |cubes| {
+        Image::from_grid(&cubes.mosaic(min, max), scale, |cube| match cube {
+            Some(true) => Rgb(255, 204, 0),
+            Some(false) => Rgb(64, 64, 64),
+            None => Rgb::BLACK,
+        })
+    })
+}
+
+/// Like [`simulate`], but with the number of dimensions chosen at run time.
+/// Returns None if the number is not in [`DIMENSIONS`].
+pub fn simulate_in(dimensions: usize, slice: &Slice, cycles: usize) -> Option<usize> {
+    match dimensions {
+        2 => Some(simulate::<2>(slice, cycles)),
+        3 => Some(simulate::<3>(slice, cycles)),
+        4 => Some(simulate::<4>(slice, cycles)),
+        5 => Some(simulate::<5>(slice, cycles)),
+        6 => Some(simulate::<6>(slice, cycles)),
+        _ => None,
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Slice;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(text.parse()?)
+    }
+
+    fn part1(&self, slice: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(slice))
+    }
+
+    fn part2(&self, slice: &Self::Input) -> Result<Self::Output2, Error> {
+        Ok(part2::solve(slice))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample1() -> Slice {
+        ".#.\n\
+         ..#\n\
+         ###"
+        .parse()
+        .unwrap()
+    }
+
+    #[test]
+    fn simulate_in_dimensions() {
+        let slice = sample1();
+        assert_eq!(Some(5), simulate_in(2, &slice, 0));
+        assert_eq!(Some(112), simulate_in(3, &slice, CYCLES));
+        assert_eq!(None, simulate_in(1, &slice, CYCLES));
+        assert_eq!(None, simulate_in(7, &slice, CYCLES));
+    }
+
+    #[test]
+    fn images_keep_size() {
+        let slice = sample1();
+        let last = generations::<3>(&slice, CYCLES).last().unwrap();
+        assert_eq!(112, last.len());
+        // 13 slices of 15x15 cubes, with gutters between them
+        let sizes: Vec<_> = images::<3>(&slice, CYCLES, 2)
+            .map(|image| (image.width(), image.height()))
+            .collect();
+        assert_eq!(vec![(2 * (13 * 16 - 1), 30); CYCLES + 1], sizes);
+    }
+}
@@ -1,8 +1,6 @@
-use super::grid4d::Grid4d;
-use std::error::Error;
-use std::fs;
+use super::{Slice, CYCLES};
 
-pub fn solve(input_path: &str) -> Result<usize, Box<dyn Error>> {
-    let grid: Grid4d = fs::read_to_string(input_path)?.parse()?;
-    Ok(grid.advance(6).population())
+/// Returns the number of active cubes after booting in 4 dimensions.
+pub fn solve(slice: &Slice) -> usize {
+    super::simulate::<4>(slice, CYCLES)
 }
@@ -3,3 +3,26 @@ mod token;
 
 pub mod part1;
 pub mod part2;
+
+use crate::error::Error;
+use crate::solution::Solution;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = String; // Each part tokenizes by its own rules.
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(text.to_owned())
+    }
+
+    fn part1(&self, text: &Self::Input) -> Result<Self::Output1, Error> {
+        part1::solve(text)
+    }
+
+    fn part2(&self, text: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(text)
+    }
+}
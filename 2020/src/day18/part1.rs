@@ -1,6 +1,6 @@
 use super::partial::Partial;
 use super::token::Token;
-use crate::error::ParseError;
+use crate::error::{Error, ParseError};
 
 fn eval_imp(tokens: &mut Vec<Token>) -> Result<u64, ParseError> {
     let mut op: Option<Partial> = None;
@@ -41,10 +41,12 @@ fn eval(mut tokens: Vec<Token>) -> Result<u64, ParseError> {
     eval_imp(&mut tokens)
 }
 
-pub fn solve(text: &str) -> Result<u64, ParseError> {
+pub fn solve(text: &str) -> Result<u64, Error> {
     let mut sum = 0;
-    for line in text.lines() {
-        sum += eval(Token::parse_all(line)?)?;
+    for (index, line) in text.lines().enumerate() {
+        sum += Token::parse_all(line)
+            .and_then(eval)
+            .map_err(|err| err.on_line(index + 1, line))?;
     }
     Ok(sum)
 }
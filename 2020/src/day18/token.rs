@@ -1,5 +1,4 @@
-use crate::error::ParseError;
-use std::str::FromStr;
+use crate::error::{ParseError, ParseErrorKind};
 
 #[derive(Debug)]
 pub enum Token {
@@ -11,37 +10,49 @@ pub enum Token {
 }
 
 impl Token {
+    /// Splits the specified expression into tokens.  Errors point at the
+    /// offending bytes of the expression.
     pub fn parse_all(expr: &str) -> Result<Vec<Token>, ParseError> {
         let mut tokens = Vec::new();
-        let mut words: Vec<&str> = expr.split_whitespace().collect();
-        words.reverse();
-        while let Some(word) = words.pop() {
-            if word.len() > 1 && !word.chars().next().unwrap().is_numeric() {
-                let (first, second) = word.split_at(1);
-                words.push(second);
-                words.push(first);
-            } else if word.len() > 1 && !word.chars().nth(word.len() - 1).unwrap().is_numeric() {
-                let (first, second) = word.split_at(word.len() - 1);
-                words.push(second);
-                words.push(first);
-            } else {
-                tokens.push(word.parse()?);
-            }
+        let mut chars = expr.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            tokens.push(match c {
+                '+' => Token::Plus,
+                '*' => Token::Times,
+                '(' => Token::Open,
+                ')' => Token::Close,
+                _ if c.is_whitespace() => continue,
+                _ if c.is_ascii_digit() => {
+                    let mut end = i + 1;
+                    while let Some((j, _)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                        end = j + 1;
+                    }
+                    let digits = &expr[i..end];
+                    Token::Value(
+                        digits
+                            .parse()
+                            .map_err(|err| ParseError::from(err).within(expr, digits))?,
+                    )
+                }
+                _ => {
+                    let what = format!("unexpected character '{}'", c);
+                    return Err(ParseError::with_kind(ParseErrorKind::Unexpected, what)
+                        .with_span(i..(i + c.len_utf8())));
+                }
+            });
         }
         Ok(tokens)
     }
 }
 
-impl FromStr for Token {
-    type Err = ParseError;
+#[cfg(test)]
+mod test {
+    use super::*;
 
-    fn from_str(s: &str) -> Result<Token, Self::Err> {
-        Ok(match s {
-            "+" => Token::Plus,
-            "*" => Token::Times,
-            "(" => Token::Open,
-            ")" => Token::Close,
-            _ => Token::Value(s.parse()?),
-        })
+    #[test]
+    fn bad_character() {
+        let err = Token::parse_all("1 + (2 * x)").unwrap_err();
+        assert_eq!(ParseErrorKind::Unexpected, err.kind());
+        assert_eq!(Some(9..10), err.span());
     }
 }
@@ -2,3 +2,26 @@ mod rule;
 
 pub mod part1;
 pub mod part2;
+
+use crate::error::Error;
+use crate::solution::Solution;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = String; // Each part builds its own rule map.
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(text.to_owned())
+    }
+
+    fn part1(&self, text: &Self::Input) -> Result<Self::Output1, Error> {
+        part1::solve(text)
+    }
+
+    fn part2(&self, text: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(text)
+    }
+}
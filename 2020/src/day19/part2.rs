@@ -1,12 +1,16 @@
-use super::rule::{Rule, RuleMap};
-use crate::error::ParseError;
+use super::rule::{check_rules, Rule, RuleMap};
+use crate::error::{Error, ParseError};
 
-pub fn solve(text: &str) -> Result<usize, ParseError> {
+pub fn solve(text: &str) -> Result<usize, Error> {
     let mut lines = text.lines();
     let mut rules = lines
         .by_ref()
-        .take_while(|line| !line.is_empty())
-        .map(|line| line.parse())
+        .enumerate()
+        .take_while(|(_, line)| !line.is_empty())
+        .map(|(index, line)| {
+            line.parse()
+                .map_err(|err: ParseError| err.on_line(index + 1, line))
+        })
         .collect::<Result<Vec<Rule>, _>>()?;
     rules.push("8: 42 | 42 8".parse()?);
     rules.push("11: 42 31 | 42 11 31".parse()?);
@@ -14,9 +18,8 @@ pub fn solve(text: &str) -> Result<usize, ParseError> {
         .into_iter()
         .map(|rule| (rule.id, rule.pattern))
         .collect();
-    let pattern = rules
-        .get(&0)
-        .ok_or_else(|| ParseError::new("can't find rule 0"))?;
+    check_rules(&rules, 0)?;
+    let pattern = &rules[&0];
     Ok(lines.filter(|line| pattern.matches(line, &rules)).count())
 }
 
@@ -5,7 +5,7 @@
 //! respectively, then `pattern.count_bytes(line, rules)` returns a set of the
 //! values 2, 5, and 9.
 
-use crate::error::ParseError;
+use crate::error::{ParseError, ParseErrorKind};
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
@@ -21,12 +21,11 @@ enum Atom {
 impl Atom {
     fn count_bytes(&self, line: &str, rules: &RuleMap) -> HashSet<usize> {
         match self {
-            Atom::RuleId(id) => {
-                let pattern = rules
-                    .get(id)
-                    .unwrap_or_else(|| panic!("can't find rule id {}", id));
-                pattern.count_bytes(line, rules)
-            }
+            // A missing rule matches nothing; see `check_rules`.
+            Atom::RuleId(id) => match rules.get(id) {
+                Some(pattern) => pattern.count_bytes(line, rules),
+                None => HashSet::new(),
+            },
             Atom::Literal(prefix) if line.starts_with(prefix) => {
                 [prefix.len()].iter().cloned().collect()
             }
@@ -42,7 +41,11 @@ impl FromStr for Atom {
         if s.len() > 2 && s.starts_with('"') && s.ends_with('"') {
             Ok(Atom::Literal(s[1..(s.len() - 1)].to_owned()))
         } else {
-            Ok(Atom::RuleId(s.parse()?))
+            let what = "expected rule ID or quoted literal";
+            let id = s
+                .parse()
+                .map_err(|_| ParseError::with_kind(ParseErrorKind::Unexpected, what))?;
+            Ok(Atom::RuleId(id))
         }
     }
 }
@@ -78,35 +81,82 @@ impl FromStr for Branch {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let atoms = s
             .split_whitespace()
-            .map(|token| token.parse())
+            .map(|token| {
+                token
+                    .parse()
+                    .map_err(|err: ParseError| err.within(s, token))
+            })
             .collect::<Result<_, _>>()?;
         Ok(Branch(atoms))
     }
 }
 
+/// What a pattern is known to match on a given line.
+#[derive(Debug)]
+enum Memo {
+    /// The pattern's matches are still being worked out, and so far include
+    /// these.  Reaching it again on the same line, before they're done,
+    /// means the rule is left-recursive; then the matches grow, one detour
+    /// at a time, until the detours add nothing more.  `read` records
+    /// whether any detour was taken.
+    Growing {
+        counts: HashSet<usize>,
+        read: bool,
+    },
+    Done(HashSet<usize>),
+}
+
 #[derive(Debug)]
 pub struct Pattern {
-    memo: RefCell<HashMap<String, HashSet<usize>>>,
+    memo: RefCell<HashMap<String, Memo>>,
     branches: Vec<Branch>, // alternative sequences of atoms
 }
 
 impl Pattern {
     fn count_bytes(&self, line: &str, rules: &RuleMap) -> HashSet<usize> {
-        if self.memo.borrow().contains_key(line) {
-            self.memo.borrow()[line].clone()
-        } else {
-            let counts = {
-                let mut counts = HashSet::new();
-                for branch in self.branches.iter() {
-                    counts.extend(branch.count_bytes(line, &rules));
-                }
-                counts
+        match self.memo.borrow_mut().get_mut(line) {
+            Some(Memo::Growing { counts, read }) => {
+                *read = true;
+                return counts.clone();
+            }
+            Some(Memo::Done(counts)) => return counts.clone(),
+            None => (),
+        }
+        let mut seed = HashSet::new();
+        loop {
+            let growing = Memo::Growing {
+                counts: seed.clone(),
+                read: false,
             };
-            self.memo
-                .borrow_mut()
-                .insert(line.to_owned(), counts.clone());
-            counts
+            self.memo.borrow_mut().insert(line.to_owned(), growing);
+            let mut counts = HashSet::new();
+            for branch in self.branches.iter() {
+                counts.extend(branch.count_bytes(line, rules));
+            }
+            let read = matches!(
+                self.memo.borrow().get(line),
+                Some(Memo::Growing { read: true, .. })
+            );
+            // Matches only grow as the seed does, so the same number means
+            // the same matches.
+            if !read || counts.len() == seed.len() {
+                seed = counts;
+                break;
+            }
+            seed = counts;
+            // Anything else worked out on this line since may have relied on
+            // the smaller seed.
+            for pattern in rules.values() {
+                let mut memo = pattern.memo.borrow_mut();
+                if let Some(Memo::Done(_)) = memo.get(line) {
+                    memo.remove(line);
+                }
+            }
         }
+        self.memo
+            .borrow_mut()
+            .insert(line.to_owned(), Memo::Done(seed.clone()));
+        seed
     }
 
     pub fn matches(&self, line: &str, rules: &RuleMap) -> bool {
@@ -123,7 +173,11 @@ impl FromStr for Pattern {
         let branches = s
             .trim_start()
             .split(" | ")
-            .map(|branch| branch.parse())
+            .map(|branch| {
+                branch
+                    .parse()
+                    .map_err(|err: ParseError| err.within(s, branch))
+            })
             .collect::<Result<_, _>>()?;
         Ok(Pattern {
             memo: RefCell::new(HashMap::new()),
@@ -137,18 +191,104 @@ pub struct Rule {
     pub pattern: Pattern,
 }
 
+/// Returns an error if the specified root rule, or any rule reachable from
+/// it, refers to an ID that has no rule.
+pub fn check_rules(rules: &RuleMap, root: usize) -> Result<(), ParseError> {
+    if !rules.contains_key(&root) {
+        return Err(ParseError::new(format!("can't find rule {}", root)));
+    }
+    let mut seen = HashSet::new();
+    let mut todo = vec![root];
+    while let Some(id) = todo.pop() {
+        if !seen.insert(id) {
+            continue;
+        }
+        let atoms = rules[&id]
+            .branches
+            .iter()
+            .flat_map(|branch| branch.0.iter());
+        for atom in atoms {
+            if let Atom::RuleId(child) = atom {
+                if !rules.contains_key(child) {
+                    let what = format!("rule {}: can't find rule {}", id, child);
+                    return Err(ParseError::new(what));
+                }
+                todo.push(*child);
+            }
+        }
+    }
+    Ok(())
+}
+
 impl FromStr for Rule {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let id_pattern = s.split(':').collect::<Vec<_>>();
         let (id, pattern) = match id_pattern.as_slice() {
-            [id, pattern] => (id.parse()?, pattern.parse()?),
+            [id, pattern] => (
+                id.parse()
+                    .map_err(|err| ParseError::from(err).within(s, id))?,
+                pattern
+                    .parse()
+                    .map_err(|err: ParseError| err.within(s, pattern))?,
+            ),
             _ => {
-                let what = format!("pattern needs colon: {}", s);
-                return Err(ParseError::new(what));
+                let what = "pattern needs exactly one colon";
+                return Err(
+                    ParseError::with_kind(ParseErrorKind::Unexpected, what).with_span(0..s.len())
+                );
             }
         };
         Ok(Rule { id, pattern })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn error_spans() {
+        let err = "2: 1 3 | 3 q".parse::<Rule>().err().unwrap();
+        assert_eq!(ParseErrorKind::Unexpected, err.kind());
+        assert_eq!(Some(11..12), err.span());
+        let err = "2 1 3".parse::<Rule>().err().unwrap();
+        assert_eq!(Some(0..5), err.span());
+    }
+
+    fn rule_map(lines: &[&str]) -> RuleMap {
+        lines
+            .iter()
+            .map(|line| line.parse::<Rule>().ok().unwrap())
+            .map(|rule| (rule.id, rule.pattern))
+            .collect()
+    }
+
+    #[test]
+    fn missing_rule() {
+        let rules = rule_map(&["0: 1 2", "1: \"a\"", "3: 4"]);
+        assert!(check_rules(&rules, 0).is_err());
+        assert!(check_rules(&rules, 1).is_ok());
+        assert!(check_rules(&rules, 5).is_err());
+        assert!(!rules[&0].matches("aa", &rules));
+    }
+
+    #[test]
+    fn left_recursion() {
+        let rules = rule_map(&["0: 0 1 | 1", "1: \"a\""]);
+        assert!(check_rules(&rules, 0).is_ok());
+        assert!(rules[&0].matches("a", &rules));
+        assert!(rules[&0].matches("aa", &rules));
+        assert!(rules[&0].matches("aaa", &rules));
+        assert!(!rules[&0].matches("b", &rules));
+        // Left recursion through another rule, with matches of rule 2 on
+        // the same line worked out while rule 0's were still growing.
+        let rules = rule_map(&["0: 2 3 | 1", "1: \"a\"", "2: 0 | 3", "3: \"b\""]);
+        for line in &["a", "ab", "abbb", "bb", "bbbb"] {
+            assert!(rules[&0].matches(line, &rules), "{}", line);
+        }
+        assert!(!rules[&0].matches("b", &rules));
+        assert!(!rules[&0].matches("ba", &rules));
+    }
+}
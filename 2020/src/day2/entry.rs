@@ -0,0 +1,57 @@
+use crate::error::ParseError;
+use crate::parse;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+// Policy
+
+#[derive(Debug)]
+pub struct Policy {
+    pub range: RangeInclusive<u32>,
+    pub letter: char,
+}
+
+fn parse_letter(s: &str) -> Result<char, ParseError> {
+    match s.chars().collect::<Vec<char>>()[..] {
+        [c] => Ok(c),
+        _ => Err(ParseError::new(format!(r#"bad letter: "{}""#, s))),
+    }
+}
+
+impl FromStr for Policy {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.splitn(2, ' ').collect::<Vec<&str>>()[..] {
+            [range, letter] => Ok(Policy {
+                range: parse::range(range).map_err(|err| err.within(s, range))?,
+                letter: parse_letter(letter).map_err(|err| err.within(s, letter))?,
+            }),
+            _ => Err(ParseError::new(format!("bad policy: {}", s))),
+        }
+    }
+}
+
+// Entry
+
+#[derive(Debug)]
+pub struct Entry {
+    pub policy: Policy,
+    pub password: String,
+}
+
+impl FromStr for Entry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.rsplitn(2, ": ").collect::<Vec<&str>>()[..] {
+            [password, policy] => Ok(Entry {
+                policy: policy
+                    .parse()
+                    .map_err(|err: ParseError| err.within(s, policy))?,
+                password: password.to_owned(),
+            }),
+            _ => Err(ParseError::new(format!("bad entry: {}", s))),
+        }
+    }
+}
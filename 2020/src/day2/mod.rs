@@ -0,0 +1,31 @@
+mod entry;
+
+pub mod part1;
+pub mod part2;
+
+pub use entry::{Entry, Policy};
+
+use crate::error::Error;
+use crate::parse;
+use crate::solution::Solution;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Entry>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    // O(N) time, O(N) space
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(parse::lines(text, str::parse)?)
+    }
+
+    fn part1(&self, entries: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(entries))
+    }
+
+    fn part2(&self, entries: &Self::Input) -> Result<Self::Output2, Error> {
+        Ok(part2::solve(entries))
+    }
+}
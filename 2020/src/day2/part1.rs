@@ -0,0 +1,15 @@
+use super::{Entry, Policy};
+
+/// Counts the passwords having a number of their policy's letter within
+/// their policy's range.
+// O(M * N) time, O(1) space where M is the average string length
+pub fn solve(entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .filter(|Entry { policy, password }| {
+            let Policy { range, letter } = policy;
+            let count = password.chars().filter(|c| c == letter).count() as u32;
+            range.contains(&count)
+        })
+        .count()
+}
@@ -0,0 +1,18 @@
+use super::{Entry, Policy};
+
+/// Counts the passwords having their policy's letter at exactly one of the
+/// (1-based) positions given by their policy's range.
+// O(M * N) time, O(1) space where M is the average string length
+pub fn solve(entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .filter(|Entry { policy, password }| {
+            let Policy { range, letter } = policy;
+            let matches_at = |n: u32| {
+                let index = n.checked_sub(1);
+                index.and_then(|i| password.chars().nth(i as usize)) == Some(*letter)
+            };
+            matches_at(*range.start()) ^ matches_at(*range.end())
+        })
+        .count()
+}
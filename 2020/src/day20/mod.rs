@@ -1,6 +1,30 @@
 mod neighbor;
 mod tile;
-mod rotate;
 
 pub mod part1;
 pub mod part2;
+
+pub use tile::Tile;
+
+use crate::error::Error;
+use crate::solution::Solution;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<Tile>;
+    type Output1 = u64;
+    type Output2 = usize;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(Tile::parse_all(text)?)
+    }
+
+    fn part1(&self, tiles: &Self::Input) -> Result<Self::Output1, Error> {
+        part1::solve(tiles)
+    }
+
+    fn part2(&self, tiles: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(tiles)
+    }
+}
@@ -1,20 +1,31 @@
 use super::tile::Projection;
-use std::collections::HashMap;
+use crate::graph::Graph;
 
+/// Which projections fit beside which.  Edges of `rights` lead from each
+/// projection to those whose left edge matches its right edge, and edges of
+/// `downs` to those whose top edge matches its bottom edge.
 #[derive(Debug)]
-pub struct NeighborSet<'a> {
-    pub rights: Vec<&'a Projection>,
-    pub downs: Vec<&'a Projection>,
+pub struct Neighbors<'a> {
+    pub rights: Graph<&'a Projection>,
+    pub downs: Graph<&'a Projection>,
 }
 
-impl NeighborSet<'_> {
-    pub fn graph(projections: &[Projection]) -> HashMap<&Projection, NeighborSet> {
-        let mut neighbors = HashMap::new();
+impl<'a> Neighbors<'a> {
+    pub fn new(projections: &'a [Projection]) -> Neighbors<'a> {
+        let mut rights = Graph::new();
+        let mut downs = Graph::new();
         for p in projections {
-            let rights = projections.iter().filter(|q| p.right == q.left).collect();
-            let downs = projections.iter().filter(|q| p.bottom == q.top).collect();
-            neighbors.insert(p, NeighborSet { rights, downs });
+            rights.add_node(p);
+            downs.add_node(p);
+            for q in projections {
+                if p.right == q.left {
+                    rights.add_edge(p, q, ());
+                }
+                if p.bottom == q.top {
+                    downs.add_edge(p, q, ());
+                }
+            }
         }
-        neighbors
+        Neighbors { rights, downs }
     }
 }
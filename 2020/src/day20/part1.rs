@@ -1,11 +1,10 @@
-use super::neighbor::NeighborSet;
-use super::tile::{Tile, Projection};
-use crate::error::NoSolution;
-use std::collections::{HashMap, HashSet};
-use std::error::Error;
+use super::neighbor::Neighbors;
+use super::tile::{Projection, Tile};
+use crate::error::Error;
+use std::collections::HashSet;
 
 struct Solver<'a> {
-    neighbors: HashMap<&'a Projection, NeighborSet<'a>>,
+    neighbors: Neighbors<'a>,
     side: usize, // the edge length of the square image
     image: Vec<&'a Projection>,
     used: HashSet<u64>, // tile IDs,
@@ -14,7 +13,7 @@ struct Solver<'a> {
 impl<'a> Solver<'a> {
     fn new(tiles: &'a [Tile], projections: &'a [Projection]) -> Solver<'a> {
         Solver {
-            neighbors: NeighborSet::graph(projections),
+            neighbors: Neighbors::new(projections),
             side: (tiles.len() as f64).sqrt() as usize, // image is square
             image: Vec::new(),
             used: HashSet::new(),
@@ -37,16 +36,15 @@ impl<'a> Solver<'a> {
             .map(|p| {
                 let v = if j == 0 {
                     let above = self.image[(i - 1) * self.side];
-                    &self.neighbors[above].downs
+                    self.neighbors.downs.successors(&above)
                 } else {
-                    &self.neighbors[p].rights
+                    self.neighbors.rights.successors(p)
                 };
-                v.iter()
-                    .cloned()
+                v.map(|(&q, _)| q)
                     .filter(|q| !self.used.contains(&q.tile_id))
                     .collect()
             })
-            .unwrap_or_else(|| self.neighbors.keys().cloned().collect())
+            .unwrap_or_else(|| self.neighbors.rights.nodes().copied().collect())
     }
 
     fn recur(&mut self) -> Option<u64> {
@@ -55,7 +53,7 @@ impl<'a> Solver<'a> {
         }
         let candidates = self.candidates();
         for p in candidates {
-            self.image.push(&p);
+            self.image.push(p);
             self.used.insert(p.tile_id);
             let result = self.recur();
             if result.is_some() {
@@ -67,15 +65,14 @@ impl<'a> Solver<'a> {
         None
     }
 
-    fn solve(mut self) -> Result<u64, NoSolution> {
-        self.recur().ok_or(NoSolution)
+    fn solve(mut self) -> Result<u64, Error> {
+        self.recur().ok_or(Error::NoSolution)
     }
 }
 
-pub fn solve(text: &str) -> Result<u64, Box<dyn Error>> {
-    let tiles = Tile::parse_all(text)?;
-    let projections = Projection::collect(&tiles);
-    Ok(Solver::new(&tiles, &projections).solve()?)
+pub fn solve(tiles: &[Tile]) -> Result<u64, Error> {
+    let projections = Projection::collect(tiles);
+    Solver::new(tiles, &projections).solve()
 }
 
 #[cfg(test)]
@@ -87,6 +84,7 @@ mod test {
     fn solve_sample1() {
         let input_path = "tests/day20/sample1";
         let text = fs::read_to_string(input_path).unwrap();
-        assert_eq!(20899048083289, solve(&text).unwrap());
+        let tiles = Tile::parse_all(&text).unwrap();
+        assert_eq!(20899048083289, solve(&tiles).unwrap());
     }
 }
@@ -1,82 +1,60 @@
-use super::neighbor::NeighborSet;
-use super::rotate::clockwise;
+use super::neighbor::Neighbors;
 use super::tile::{Projection, Tile};
-use crate::error::NoSolution;
-use std::collections::{HashMap, HashSet};
-use std::error::Error;
+use crate::error::Error;
+use crate::grid::{Grid, Position};
+use crate::image::{Image, Rgb};
+use std::collections::HashSet;
 
 const MONSTER_NOISE: usize = 15; // number of '#' per monster
 
-fn is_monster_at(image: &[Vec<u8>], i: usize, j: usize) -> bool {
-    //                    #
-    //  #    ##    ##    ###
-    //   #  #  #  #  #  #
-    [
-        (0, 18),
-        (1, 0),
-        (1, 5),
-        (1, 6),
-        (1, 11),
-        (1, 12),
-        (1, 17),
-        (1, 18),
-        (1, 19),
-        (2, 1),
-        (2, 4),
-        (2, 7),
-        (2, 10),
-        (2, 13),
-        (2, 16),
-    ]
-    .iter()
-    .all(|(di, dj)| {
-        let (y, x) = (i + di, j + dj);
-        y < image.len() && x < image[0].len() && image[y][x] == b'#'
-    })
+/// The (row, column) offsets of the '#' of a sea monster from its top left:
+///
+/// ```text
+///                   #
+/// #    ##    ##    ###
+///  #  #  #  #  #  #
+/// ```
+const MONSTER: [(usize, usize); MONSTER_NOISE] = [
+    (0, 18),
+    (1, 0),
+    (1, 5),
+    (1, 6),
+    (1, 11),
+    (1, 12),
+    (1, 17),
+    (1, 18),
+    (1, 19),
+    (2, 1),
+    (2, 4),
+    (2, 7),
+    (2, 10),
+    (2, 13),
+    (2, 16),
+];
+
+fn is_monster_at(image: &Grid<bool>, i: usize, j: usize) -> bool {
+    MONSTER
+        .iter()
+        .all(|(di, dj)| image.get(Position::new(i + di, j + dj)) == Some(&true))
 }
 
-fn count_monsters(image: &[Vec<u8>]) -> usize {
-    let mut count = 0;
-    for i in 0..image.len() {
-        for j in 0..image[0].len() {
-            if is_monster_at(&image, i, j) {
-                count += 1;
-            }
-        }
-    }
-    count
+fn count_monsters(image: &Grid<bool>) -> usize {
+    let positions = image.positions();
+    positions
+        .filter(|pos| is_monster_at(image, pos.row, pos.column))
+        .count()
 }
 
-fn count_monsters_transformed(image: &[Vec<u8>]) -> Result<usize, NoSolution> {
-    let count = count_monsters(image);
-    if count != 0 {
-        return Ok(count);
-    }
-    let mut image = image.to_vec();
-    for _ in 1..4 {
-        image = clockwise(&image);
-        let count = count_monsters(&image);
-        if count != 0 {
-            return Ok(count);
-        }
-    }
-    image.reverse();
-    let count = count_monsters(&image);
-    if count != 0 {
-        return Ok(count);
-    }
-    for _ in 1..4 {
-        image = clockwise(&image);
-        let count = count_monsters(&image);
-        if count != 0 {
-            return Ok(count);
-        }
-    }
-    Err(NoSolution)
+fn count_monsters_transformed(image: &Grid<bool>) -> Result<usize, Error> {
+    image
+        .orientations()
+        .map(|image| count_monsters(&image))
+        .find(|&count| count != 0)
+        .ok_or(Error::NoSolution)
 }
 
 struct Solver<'a> {
-    neighbors: HashMap<&'a Projection, NeighborSet<'a>>,
+    neighbors: Neighbors<'a>,
     side: usize, // the edge length of the square image
     image: Vec<&'a Projection>,
     used: HashSet<u64>, // tile IDs,
@@ -85,7 +63,7 @@ struct Solver<'a> {
 impl<'a> Solver<'a> {
     fn new(tiles: &'a [Tile], projections: &'a [Projection]) -> Solver<'a> {
         Solver {
-            neighbors: NeighborSet::graph(projections),
+            neighbors: Neighbors::new(projections),
             side: (tiles.len() as f64).sqrt() as usize, // image is square
             image: Vec::new(),
             used: HashSet::new(),
@@ -100,25 +78,24 @@ impl<'a> Solver<'a> {
             .map(|p| {
                 let v = if j == 0 {
                     let above = self.image[(i - 1) * self.side];
-                    &self.neighbors[above].downs
+                    self.neighbors.downs.successors(&above)
                 } else {
-                    &self.neighbors[p].rights
+                    self.neighbors.rights.successors(p)
                 };
-                v.iter()
-                    .cloned()
+                v.map(|(&q, _)| q)
                     .filter(|q| !self.used.contains(&q.tile_id))
                     .collect()
             })
-            .unwrap_or_else(|| self.neighbors.keys().cloned().collect())
+            .unwrap_or_else(|| self.neighbors.rights.nodes().copied().collect())
     }
 
-    fn recur(&mut self) -> Option<Vec<Vec<u8>>> {
+    fn recur(&mut self) -> Option<Grid<bool>> {
         if self.image.len() == self.side * self.side {
             return Some(self.render());
         }
         let candidates = self.candidates();
         for p in candidates {
-            self.image.push(&p);
+            self.image.push(p);
             self.used.insert(p.tile_id);
             let result = self.recur();
             if result.is_some() {
@@ -130,41 +107,61 @@ impl<'a> Solver<'a> {
         None
     }
 
-    fn render(&self) -> Vec<Vec<u8>> {
-        const INTERIOR_SIDE: usize = 8;
-        let side = self.side;
-        let mut rendered: Vec<Vec<u8>> = vec![Vec::new(); side * INTERIOR_SIDE];
-        for i in 0..side {
-            for j in 0..side {
-                let p = self.image[i * side + j];
-                for k in 0..INTERIOR_SIDE {
-                    rendered[i * INTERIOR_SIDE + k].extend(p.interior[k].iter());
-                }
-            }
-        }
-        rendered
+    fn render(&self) -> Grid<bool> {
+        let interior = &self.image[0].interior;
+        let (height, width) = (interior.height(), interior.width());
+        Grid::from_fn(self.side * height, self.side * width, |pos| {
+            let p = self.image[pos.row / height * self.side + pos.column / width];
+            p.interior[Position::new(pos.row % height, pos.column % width)]
+        })
     }
 
-    #[allow(clippy::naive_bytecount)]
-    fn solve(mut self) -> Result<usize, NoSolution> {
-        let image = self.recur().ok_or(NoSolution)?;
+    fn solve(mut self) -> Result<usize, Error> {
+        let image = self.recur().ok_or(Error::NoSolution)?;
         let count = count_monsters_transformed(&image)?;
         if count == 0 {
-            Err(NoSolution)
+            Err(Error::NoSolution)
         } else {
-            let noise: usize = image
-                .iter()
-                .map(|row| row.iter().filter(|&&b| b == b'#').count())
-                .sum();
+            let noise = image.iter().filter(|&&pixel| pixel).count();
             Ok(noise - count * MONSTER_NOISE)
         }
     }
 }
 
-pub fn solve(text: &str) -> Result<usize, Box<dyn Error>> {
-    let tiles = Tile::parse_all(text)?;
-    let projections = Projection::collect(&tiles);
-    Ok(Solver::new(&tiles, &projections).solve()?)
+pub fn solve(tiles: &[Tile]) -> Result<usize, Error> {
+    let projections = Projection::collect(tiles);
+    Solver::new(tiles, &projections).solve()
+}
+
+/// Returns a picture of the assembled image, turned to show sea monsters,
+/// each pixel a square scale pixels on a side: monsters orange, other
+/// rough water light blue, and calm water dark blue.
+pub fn picture(tiles: &[Tile], scale: usize) -> Result<Image, Error> {
+    let projections = Projection::collect(tiles);
+    let image = Solver::new(tiles, &projections)
+        .recur()
+        .ok_or(Error::NoSolution)?;
+    let image = image
+        .orientations()
+        .find(|image| count_monsters(image) != 0)
+        .ok_or(Error::NoSolution)?;
+    let mut monsters = image.map(|_| false);
+    let tops = image
+        .positions()
+        .filter(|pos| is_monster_at(&image, pos.row, pos.column));
+    for top in tops {
+        for &(di, dj) in &MONSTER {
+            monsters[Position::new(top.row + di, top.column + dj)] = true;
+        }
+    }
+    let colors = Grid::from_fn(image.height(), image.width(), |pos| {
+        match (monsters[pos], image[pos]) {
+            (true, _) => Rgb(255, 128, 0),
+            (false, true) => Rgb(128, 192, 255),
+            (false, false) => Rgb(16, 48, 112),
+        }
+    });
+    Ok(Image::from_grid(&colors, scale, |&color| color))
 }
 
 #[cfg(test)]
@@ -176,12 +173,23 @@ mod test {
     fn solve_sample1() {
         let input_path = "tests/day20/sample1";
         let text = fs::read_to_string(input_path).unwrap();
-        assert_eq!(273, solve(&text).unwrap());
+        let tiles = Tile::parse_all(&text).unwrap();
+        assert_eq!(273, solve(&tiles).unwrap());
+    }
+
+    #[test]
+    fn picture_sample1() {
+        let text = fs::read_to_string("tests/day20/sample1").unwrap();
+        let tiles = Tile::parse_all(&text).unwrap();
+        let image = picture(&tiles, 1).unwrap();
+        assert_eq!((24, 24), (image.width(), image.height()));
+        let orange = image.pixels().iter().filter(|&&p| p == Rgb(255, 128, 0));
+        assert_eq!(2 * MONSTER_NOISE, orange.count());
     }
 
     #[test]
     fn search() {
-        let image: Vec<Vec<u8>> = "
+        let rows = "
             .####...#####..#...###..
             #####..#..#.#.####..#.#.
             .#.#...#.###...#.##.##..
@@ -208,8 +216,9 @@ mod test {
             #..###....##.#...##.##.#"
             .trim()
             .lines()
-            .map(|line| line.trim().bytes().collect())
+            .map(|line| line.trim().bytes().map(|b| b == b'#').collect())
             .collect();
+        let image = Grid::from_rows(rows).unwrap();
         assert!(is_monster_at(&image, 2, 2));
         assert_eq!(2, count_monsters(&image));
     }
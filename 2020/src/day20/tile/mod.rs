@@ -1,63 +1,52 @@
 mod projection;
 
-use super::rotate;
-use crate::error::ParseError;
-use std::str::FromStr;
+use crate::error::{ParseError, ParseErrorKind};
+use crate::grid::Grid;
+use crate::parse::{self, Paragraph};
 
 pub use projection::Projection;
 
-fn collect_column(lines: &[&str], n: usize) -> String {
-    lines.iter().flat_map(|line| line.chars().nth(n)).collect()
+/// Parses a pixel: true for `#`, and false for `.`.
+fn parse_pixel(c: char) -> Result<bool, ParseError> {
+    match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => {
+            let what = format!("bad pixel {:?}: expected '#' or '.'", c);
+            Err(ParseError::with_kind(ParseErrorKind::Unexpected, what))
+        }
+    }
 }
 
 #[derive(Clone)]
 pub struct Tile {
     id: u64,
-    top: String,
-    right: String,
-    bottom: String,
-    left: String,
-    interior: Vec<Vec<u8>>,
+    pixels: Grid<bool>,
 }
 
 impl Tile {
     pub fn parse_all(text: &str) -> Result<Vec<Tile>, ParseError> {
-        let mut tiles = Vec::new();
-        for paragraph in text.trim().split("\n\n") {
-            tiles.push(paragraph.parse()?);
-        }
-        Ok(tiles)
+        parse::paragraphs(text).iter().map(Tile::parse).collect()
     }
-}
-
-impl FromStr for Tile {
-    type Err = ParseError;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let lines: Vec<&str> = s.lines().collect();
-        if lines.len() < 3
-            || lines[1].len() < 2
-            || lines[2..].iter().any(|line| line.len() != lines[1].len())
-        {
-            return Err(ParseError::new("bad tile"));
+    /// Parses a tile from a paragraph such as `Tile 42:` followed by a
+    /// grid of pixels.
+    pub fn parse(paragraph: &Paragraph) -> Result<Tile, ParseError> {
+        let (heading, body) = paragraph.split_heading();
+        let id = heading
+            .strip_prefix("Tile ")
+            .and_then(|rest| rest.strip_suffix(':'))
+            .ok_or_else(|| {
+                let what = "expected tile ID, as in \"Tile 42:\"";
+                let err = ParseError::with_kind(ParseErrorKind::Unexpected, what);
+                paragraph.locate(0, err.with_span(0..heading.len()))
+            })?;
+        let id = parse::integer(id).map_err(|err| paragraph.locate(0, err.within(heading, id)))?;
+        let pixels = Grid::from_rows(body.grid(parse_pixel)?)?;
+        if pixels.height() < 2 || pixels.width() < 2 {
+            let what = "tile must be at least 2 pixels square";
+            return Err(paragraph.locate(0, ParseError::new(what)));
         }
-        let id = lines[0]
-            .trim_end_matches(':')
-            .split_whitespace()
-            .last()
-            .and_then(|id| id.parse().ok())
-            .ok_or_else(|| ParseError::new("expected tile ID"))?;
-        let interior = lines[2..(lines.len() - 1)]
-            .iter()
-            .map(|line| Vec::from(&line[1..(line.len() - 1)]))
-            .collect();
-        Ok(Tile {
-            id,
-            top: lines[1].to_owned(),
-            right: collect_column(&lines[1..], lines[0].len() - 1),
-            bottom: lines[lines.len() - 1].to_owned(),
-            left: collect_column(&lines[1..], 0),
-            interior,
-        })
+        Ok(Tile { id, pixels })
     }
 }
@@ -0,0 +1,63 @@
+use crate::error::ParseError;
+use crate::parse;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+pub type Ingredients = HashSet<String>;
+
+/// What a list of foods says about which ingredients might contain which
+/// allergens.
+#[derive(Clone, Debug)]
+pub struct Analysis {
+    /// Maps each allergen to the ingredients that might contain it.
+    pub candidates: HashMap<String, Ingredients>,
+    /// Maps each ingredient to the number of foods it appears in.
+    pub counts: HashMap<String, usize>,
+    /// Ingredients that definitely contain no allergens.
+    pub safe: Ingredients,
+}
+
+fn parse_line(line: &str) -> Result<(Ingredients, Vec<String>), ParseError> {
+    let parts: Vec<_> = line.trim_end_matches(')').split(" (contains ").collect();
+    match parts.as_slice() {
+        [ingredients, allergens] => Ok((
+            ingredients.split_whitespace().map(str::to_owned).collect(),
+            allergens
+                .split_whitespace()
+                .map(|a| a.trim_end_matches(',').to_owned())
+                .collect(),
+        )),
+        _ => Err(ParseError::new(
+            "expected: INGREDIENTS (contains ALLERGENS)",
+        )),
+    }
+}
+
+impl FromStr for Analysis {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut candidates: HashMap<String, Ingredients> = HashMap::new();
+        let mut counts = HashMap::new();
+        for (ingredients, allergens) in parse::lines(text, parse_line)? {
+            for allergen in allergens {
+                if let Some(set) = candidates.get_mut(&allergen) {
+                    *set = &*set & &ingredients;
+                } else {
+                    candidates.insert(allergen, ingredients.clone());
+                }
+            }
+            for ingredient in ingredients {
+                *counts.entry(ingredient).or_insert(0) += 1;
+            }
+        }
+        let all_ingredients: Ingredients = counts.keys().cloned().collect();
+        let dangerous: Ingredients = candidates.values().flatten().cloned().collect();
+        let safe = &all_ingredients - &dangerous;
+        Ok(Analysis {
+            candidates,
+            counts,
+            safe,
+        })
+    }
+}
@@ -0,0 +1,29 @@
+mod analysis;
+
+pub mod part1;
+pub mod part2;
+
+pub use analysis::{Analysis, Ingredients};
+
+use crate::error::Error;
+use crate::solution::Solution;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Analysis;
+    type Output1 = usize;
+    type Output2 = String;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(text.parse()?)
+    }
+
+    fn part1(&self, analysis: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(analysis))
+    }
+
+    fn part2(&self, analysis: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(analysis)
+    }
+}
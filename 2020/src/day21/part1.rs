@@ -0,0 +1,19 @@
+use super::Analysis;
+
+/// Counts the appearances of ingredients that can't contain any allergen.
+pub fn solve(analysis: &Analysis) -> usize {
+    let Analysis { counts, safe, .. } = analysis;
+    safe.iter().map(|ingredient| counts[ingredient]).sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn sample1() {
+        let text = fs::read_to_string("tests/day21/sample1").unwrap();
+        assert_eq!(5, solve(&text.parse().unwrap()));
+    }
+}
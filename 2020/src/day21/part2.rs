@@ -0,0 +1,51 @@
+use super::Analysis;
+use crate::error::Error;
+use std::collections::HashMap;
+
+/// Returns the dangerous ingredients, sorted by the allergens they contain,
+/// as a comma-separated list.
+pub fn solve(analysis: &Analysis) -> Result<String, Error> {
+    let Analysis {
+        candidates, safe, ..
+    } = analysis;
+    let mut candidates = candidates.clone();
+    for set in candidates.values_mut() {
+        *set = &*set - safe;
+    }
+    let mut pairs = Vec::new(); // (allergen, ingredient)...
+    while !candidates.is_empty() {
+        let known: HashMap<_, _> = candidates // allergen => ingredient
+            .iter()
+            .filter(|(_, set)| set.len() == 1)
+            .map(|(allergen, set)| (allergen.clone(), set.iter().next().unwrap().clone()))
+            .collect();
+        if known.is_empty() {
+            return Err(Error::NoSolution); // the remaining allergens are ambiguous
+        }
+        candidates.retain(|_, set| set.len() > 1);
+        for set in candidates.values_mut() {
+            for ingredient in known.values() {
+                set.remove(ingredient);
+            }
+        }
+        pairs.extend(known);
+    }
+    pairs.sort_unstable();
+    let ingredients: Vec<_> = pairs.into_iter().map(|pair| pair.1).collect();
+    Ok(ingredients.join(","))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn sample1() {
+        let text = fs::read_to_string("tests/day21/sample1").unwrap();
+        assert_eq!(
+            "mxmxvkd,sqjhc,fvjkl",
+            solve(&text.parse().unwrap()).unwrap()
+        );
+    }
+}
@@ -1,25 +1,23 @@
 use crate::error::ParseError;
+use crate::parse;
 use std::collections::VecDeque;
 
 pub type Card = u8;
 pub type Deck = VecDeque<Card>; // ordered top to bottom
 
-pub fn parse_both(text: String) -> Result<(Deck, Deck), ParseError> {
-    let mut decks = (Deck::new(), Deck::new());
-    let mut lines = text.lines().skip(1);
-    while let Some(line) = lines.next() {
-        if line.is_empty() {
-            break;
-        }
-        decks.0.push_back(line.parse()?);
+/// Parses two decks, each headed by a line naming its player.
+pub fn parse_both(text: &str) -> Result<(Deck, Deck), ParseError> {
+    let mut decks = parse::paragraphs(text)
+        .iter()
+        .map(|paragraph| paragraph.split_heading().1.parse_lines(parse::integer))
+        .collect::<Result<Vec<Vec<Card>>, _>>()?;
+    if decks.len() != 2 {
+        let what = format!("expected 2 decks, found {}", decks.len());
+        return Err(ParseError::new(what));
     }
-    for line in lines.skip(1) {
-        if line.is_empty() {
-            break;
-        }
-        decks.1.push_back(line.parse()?);
-    }
-    Ok(decks)
+    let second = decks.pop().unwrap();
+    let first = decks.pop().unwrap();
+    Ok((first.into(), second.into()))
 }
 
 pub fn score(deck: Deck) -> u64 {
@@ -4,3 +4,26 @@ pub mod part1;
 pub mod part2;
 
 pub use deck::{Card, Deck};
+
+use crate::error::Error;
+use crate::solution::Solution;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = (Deck, Deck);
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(deck::parse_both(text)?)
+    }
+
+    fn part1(&self, decks: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(decks.clone()))
+    }
+
+    fn part2(&self, decks: &Self::Input) -> Result<Self::Output2, Error> {
+        Ok(part2::solve(decks.clone()))
+    }
+}
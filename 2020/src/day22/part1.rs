@@ -36,7 +36,7 @@ mod test {
     fn solve_sample1() {
         let input_path = "tests/day22/sample1";
         let text = fs::read_to_string(input_path).unwrap();
-        let decks = deck::parse_both(text).unwrap();
+        let decks = deck::parse_both(&text).unwrap();
         assert_eq!(306, solve(decks));
     }
 }
@@ -1,5 +1,5 @@
 use super::{deck, Card, Deck};
-use std::collections::HashSet;
+use crate::cycle::{self, Detector};
 
 enum Winner {
     Player1(Deck),
@@ -29,35 +29,52 @@ fn recurse(decks: &Round, cards: (Card, Card)) -> Option<Winner> {
     }
 }
 
-fn play(mut decks: Round) -> Winner {
-    let mut seen: HashSet<Round> = HashSet::new();
-    while !seen.contains(&decks) {
-        seen.insert(decks.clone());
-        if let Some(cards) = draw(&mut decks) {
-            match recurse(&decks, cards) {
-                Some(Winner::Player1(_)) => {
+/// Plays a round, returning the decks for the next one, or the winner if
+/// either player has run out of cards.
+fn round(mut decks: Round) -> Result<Round, Winner> {
+    if let Some(cards) = draw(&mut decks) {
+        match recurse(&decks, cards) {
+            Some(Winner::Player1(_)) => {
+                decks.0.extend(&[cards.0, cards.1]);
+            }
+            Some(Winner::Player2(_)) => {
+                decks.1.extend(&[cards.1, cards.0]);
+            }
+            None => {
+                if cards.0 > cards.1 {
                     decks.0.extend(&[cards.0, cards.1]);
-                }
-                Some(Winner::Player2(_)) => {
+                } else {
+                    assert!(cards.1 > cards.0);
                     decks.1.extend(&[cards.1, cards.0]);
                 }
-                None => {
-                    if cards.0 > cards.1 {
-                        decks.0.extend(&[cards.0, cards.1]);
-                    } else {
-                        assert!(cards.1 > cards.0);
-                        decks.1.extend(&[cards.1, cards.0]);
-                    }
-                }
             }
-        } else if decks.1.is_empty() {
+        }
+        Ok(decks)
+    } else if decks.1.is_empty() {
+        Err(Winner::Player1(decks.0))
+    } else {
+        assert!(decks.0.is_empty());
+        Err(Winner::Player2(decks.1))
+    }
+}
+
+/// Plays a game.  Rounds are deterministic, so a game that repeats a round
+/// repeats itself forever; rather than remember every round, watch for the
+/// cycle, then find the first repeated round to end the game at.
+fn play(start: Round) -> Winner {
+    let mut detector = Detector::new();
+    let mut decks = start.clone();
+    loop {
+        if let Some(period) = detector.check(&decks) {
+            let next = |decks| round(decks).unwrap_or_else(|_| unreachable!("cycle ended"));
+            let (_, decks) = cycle::first_repeated(start, period, next);
             return Winner::Player1(decks.0);
-        } else {
-            assert!(decks.0.is_empty());
-            return Winner::Player2(decks.1);
         }
+        decks = match round(decks) {
+            Ok(decks) => decks,
+            Err(winner) => return winner,
+        };
     }
-    Winner::Player1(decks.0)
 }
 
 pub fn solve(decks: (Deck, Deck)) -> u64 {
@@ -78,7 +95,7 @@ mod test {
     fn solve_sample1() {
         let input_path = "tests/day22/sample1";
         let text = fs::read_to_string(input_path).unwrap();
-        let decks = deck::parse_both(text).unwrap();
+        let decks = deck::parse_both(&text).unwrap();
         assert_eq!(291, solve(decks));
     }
 }
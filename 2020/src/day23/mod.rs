@@ -1,2 +1,26 @@
 pub mod part1;
 pub mod part2;
+
+use crate::error::Error;
+use crate::error::ParseError;
+use crate::solution::Solution;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = u64;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(text.trim().parse().map_err(ParseError::from)?)
+    }
+
+    fn part1(&self, digits: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(*digits))
+    }
+
+    fn part2(&self, digits: &Self::Input) -> Result<Self::Output2, Error> {
+        Ok(part2::solve(*digits))
+    }
+}
@@ -1,3 +1,5 @@
+use crate::cycle;
+
 const BASE: usize = 10;
 const BASE_U64: u64 = BASE as u64;
 
@@ -5,10 +7,10 @@ type Cup = u8;
 type CupArray = [Cup; BASE - 1];
 
 fn has_all_digits(cups: CupArray) -> bool {
-    (1..BASE).all(|digit| cups.iter().any(|&cup| cup == digit as Cup))
+    (1..BASE).all(|digit| cups.contains(&(digit as Cup)))
 }
 
-#[derive(Clone)]
+#[derive(Clone, Eq, PartialEq)]
 struct Circle {
     cups: CupArray,
 }
@@ -53,8 +55,10 @@ impl Circle {
         Circle { cups }
     }
 
+    /// Returns the circle after n moves.  There are only so many orders
+    /// the cups can be in, so the moves must eventually repeat.
     fn nth(self, n: usize) -> Circle {
-        (0..n).fold(self, |circle, _| circle.next())
+        cycle::nth(self, n, Circle::next)
     }
 }
 
@@ -41,14 +41,12 @@ pub struct Circle {
 
 impl Circle {
     fn from_digits(mut digits: u64, len: usize) -> Circle {
-        let mut prefix = Vec::new(); // in reverse order
-        prefix.reserve(9);
+        let mut prefix = Vec::with_capacity(9); // in reverse order
         while digits > 0 {
             prefix.push((digits % 10) as Cup - 1);
             digits /= 10;
         }
-        let mut adjacent = Vec::new();
-        adjacent.reserve(len);
+        let mut adjacent = Vec::with_capacity(len);
         adjacent.resize(prefix.len(), 0);
         for (i, &cup) in prefix.iter().enumerate() {
             adjacent[cup as usize] = prefix[(i + prefix.len() - 1) % prefix.len()];
@@ -8,16 +8,10 @@ pub enum HexDirection {
 }
 
 impl HexDirection {
-    pub fn all() -> HexDirections {
-        HexDirections {
-            next: Some(HexDirection::East),
-        }
-    }
-
     /// HexDirections can't implement std::str::FromStr because it depends on
     /// the lifetime of the supplied string reference.  FromStr really wants
     /// its result to be independent of the lifetime of the original string.
-    pub fn parse_line(line: &str) -> ParseLine {
+    pub fn parse_line(line: &str) -> ParseLine<'_> {
         ParseLine { line }
     }
 }
@@ -33,26 +27,6 @@ pub enum SquareDirection {
     SouthEast,
 }
 
-pub struct HexDirections {
-    next: Option<HexDirection>,
-}
-
-impl Iterator for HexDirections {
-    type Item = HexDirection;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        match self.next {
-            Some(HexDirection::East) => self.next.replace(HexDirection::NorthEast),
-            Some(HexDirection::NorthEast) => self.next.replace(HexDirection::NorthWest),
-            Some(HexDirection::NorthWest) => self.next.replace(HexDirection::West),
-            Some(HexDirection::West) => self.next.replace(HexDirection::SouthWest),
-            Some(HexDirection::SouthWest) => self.next.replace(HexDirection::SouthEast),
-            Some(HexDirection::SouthEast) => self.next.take(),
-            None => None,
-        }
-    }
-}
-
 pub struct ParseLine<'a> {
     line: &'a str,
 }
@@ -1,11 +1,15 @@
 use super::direction::HexDirection;
 use super::latlon::LatLon;
+use crate::automaton::{Automaton, Hex, Life};
+use crate::grid::{Grid, Position};
+use crate::image::{Image, Rgb};
 use std::collections::HashSet;
-
 use std::convert::Infallible;
-use std::mem;
+use std::fmt::{self, Display, Formatter};
+use std::iter;
 use std::str::FromStr;
 
+#[derive(Clone)]
 pub struct Floor {
     black: HashSet<LatLon>,
 }
@@ -15,35 +19,113 @@ impl Floor {
         self.black.len()
     }
 
-    pub fn next_is_black(&self, tile: LatLon) -> bool {
-        let count = tile
-            .neighbors()
-            .filter(|adjacent| self.black.contains(adjacent))
-            .count();
-        count == 2 || (count == 1 && self.black.contains(&tile))
+    /// Returns the floor as it will be after the specified number of days.
+    /// Black tiles spread at most one tile per day, so the floor is copied
+    /// into a dense grid with that much room around the black tiles, where
+    /// each day is a single pass over every tile, until the floor starts
+    /// repeating itself, if ever.  Latitudes are rows, and odd ones lie half
+    /// a tile east of even ones, as in [`Hex`].
+    pub fn day(self, days: usize) -> Floor {
+        let lats = self.black.iter().map(|tile| tile.0);
+        let lons = self.black.iter().map(|tile| tile.1);
+        let (bottom, top, left, right) = match (
+            lats.clone().min(),
+            lats.max(),
+            lons.clone().min(),
+            lons.max(),
+        ) {
+            (Some(bottom), Some(top), Some(left), Some(right)) => (bottom, top, left, right),
+            _ => return self,
+        };
+        let margin = days as isize + 1;
+        // Keep grid rows the same parity as latitudes, so that hex neighbors
+        // of grid cells are those of the tiles they hold.
+        let bottom = (bottom - margin).div_euclid(2) * 2;
+        let left = left - margin;
+        let height = (top + margin - bottom + 1) as usize;
+        let width = (right + margin - left + 1) as usize;
+        let to_latlon =
+            |pos: Position| LatLon(bottom + pos.row as isize, left + pos.column as isize);
+        let grid = Grid::from_fn(height, width, |pos| self.black.contains(&to_latlon(pos)));
+        let mut automaton = Automaton::new(grid, Hex, rule());
+        let grid = automaton.jump(days);
+        let black = grid.enumerate().filter(|(_, &black)| black);
+        Floor {
+            black: black.map(|(pos, _)| to_latlon(pos)).collect(),
+        }
     }
+}
 
-    fn next_into(&self, next: &mut HashSet<LatLon>) {
-        next.clear();
-        for &tile in &self.black {
-            if self.next_is_black(tile) {
-                next.insert(tile);
-            }
-            for neighbor in tile.neighbors() {
-                if self.next_is_black(neighbor) {
-                    next.insert(neighbor);
+impl Floor {
+    /// Returns pictures of the floor today and after each of the specified
+    /// number of days, all showing the area black tiles can reach by then, so
+    /// that every picture is the same size.  Each tile is a rectangle scale
+    /// pixels high and twice that wide, north at the top, with odd latitudes
+    /// offset half a tile east: black tiles are drawn black, white ones
+    /// white, and the edges of the area gray.
+    pub fn images(self, days: usize, scale: usize) -> impl Iterator<Item = Image> {
+        let lats = self.black.iter().map(|tile| tile.0);
+        let lons = self.black.iter().map(|tile| tile.1);
+        let reach = days as isize; // black tiles spread one tile a day
+        let south = lats.clone().min().unwrap_or(0) - reach;
+        let north = lats.max().unwrap_or(0) + reach;
+        let west = lons.clone().min().unwrap_or(0) - reach;
+        let east = lons.max().unwrap_or(0) + reach;
+        let height = (north - south + 1) as usize;
+        let width = (east - west + 1) as usize;
+        let floors = iter::successors(Some(self), |floor| Some(floor.clone().day(1)));
+        floors.take(days + 1).map(move |floor| {
+            let mut image = Image::new((2 * width + 1) * scale, height * scale, Rgb(128, 128, 128));
+            for lat in south..=north {
+                let y = (north - lat) as usize * scale;
+                let indent = if lat.rem_euclid(2) == 1 { scale } else { 0 };
+                for lon in west..=east {
+                    let x = indent + 2 * (lon - west) as usize * scale;
+                    let color = if floor.black.contains(&LatLon(lat, lon)) {
+                        Rgb(32, 32, 32)
+                    } else {
+                        Rgb(240, 240, 240)
+                    };
+                    image.fill(x, y, 2 * scale, scale, color);
                 }
             }
-        }
+            image
+        })
     }
+}
+
+/// Returns the rule for flipping tiles: a white tile with exactly two black
+/// neighbors turns black, and a black tile stays black only if it has one
+/// or two black neighbors.
+fn rule() -> Life {
+    Life::new(&[2], &[1, 2])
+}
 
-    pub fn day(mut self, day: usize) -> Floor {
-        let next = &mut HashSet::new();
-        for _ in 0..day {
-            self.next_into(next);
-            mem::swap(&mut self.black, next);
+/// Shows black tiles as '#' and white ones as '.', north at the top.  Tiles
+/// are spaced apart, so that odd latitudes can be indented half a tile.
+impl Display for Floor {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let lats = self.black.iter().map(|tile| tile.0);
+        let lons = self.black.iter().map(|tile| tile.1);
+        let (bottom, top) = match (lats.clone().min(), lats.max()) {
+            (Some(bottom), Some(top)) => (bottom, top),
+            _ => return Ok(()),
+        };
+        let left = lons.clone().min().unwrap_or(0);
+        let right = lons.max().unwrap_or(0);
+        for lat in (bottom..=top).rev() {
+            let tiles = (left..=right).map(|lon| {
+                let black = self.black.contains(&LatLon(lat, lon));
+                if black {
+                    "#"
+                } else {
+                    "."
+                }
+            });
+            let indent = if lat.rem_euclid(2) == 1 { " " } else { "" };
+            writeln!(f, "{}{}", indent, tiles.collect::<Vec<_>>().join(" "))?;
         }
-        self
+        Ok(())
     }
 }
 
@@ -82,6 +164,28 @@ mod test {
         assert_eq!(10, sample1().count_black());
     }
 
+    #[test]
+    fn display() {
+        let floor: Floor = "nwwswee\ne\nne".parse().unwrap();
+        assert_eq!(" # .\n# #\n", floor.to_string());
+    }
+
+    #[test]
+    fn images() {
+        let floor: Floor = "nwwswee\ne\nne".parse().unwrap();
+        let images: Vec<_> = floor.images(1, 1).collect();
+        assert_eq!(2, images.len());
+        // Latitudes -1 to 2 and longitudes -1 to 2, as in the display test
+        // plus a tile all around.
+        assert_eq!((9, 4), (images[0].width(), images[0].height()));
+        let black = |image: &Image, x, y| image.get(x, y) == Some(Rgb(32, 32, 32));
+        assert!(black(&images[0], 3, 1) && black(&images[0], 4, 1));
+        assert!(!black(&images[0], 5, 1));
+        assert!(black(&images[0], 2, 2) && black(&images[0], 5, 2));
+        assert!(!black(&images[0], 6, 2));
+        assert_eq!(Some(Rgb(128, 128, 128)), images[0].get(0, 1));
+    }
+
     #[test]
     fn part2_sample1_day1() {
         assert_eq!(15, sample1().day(1).count_black());
@@ -1,30 +1,6 @@
-use super::direction::{HexDirection, HexDirections, SquareDirection};
+use super::direction::{HexDirection, SquareDirection};
 use std::ops::{Add, AddAssign};
 
-pub struct Neighbors {
-    center: LatLon,
-    directions: HexDirections,
-}
-
-impl Neighbors {
-    fn new(center: LatLon) -> Neighbors {
-        Neighbors {
-            center,
-            directions: HexDirection::all(),
-        }
-    }
-}
-
-impl Iterator for Neighbors {
-    type Item = LatLon;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.directions
-            .next()
-            .map(|direction| self.center + direction)
-    }
-}
-
 /// Latitude and longitude.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct LatLon(pub isize, pub isize);
@@ -52,10 +28,6 @@ impl LatLon {
     {
         hexes.fold(self, |latlon, hex| latlon + hex)
     }
-
-    pub fn neighbors(self) -> Neighbors {
-        Neighbors::new(self)
-    }
 }
 
 impl Add<HexDirection> for LatLon {
@@ -102,16 +74,17 @@ mod test {
 
     #[test]
     fn neighbors_even() {
-        let neighbors: Vec<_> = LatLon(0, 0).neighbors().collect();
+        let origin = LatLon(0, 0);
+        let hexes = HexDirection::parse_line("enenwwswse");
+        let neighbors: Vec<_> = hexes.map(|hex| origin + hex).collect();
         let want = [
             LatLon(0, 1),
             LatLon(1, 0),
-            LatLon(1, -1), 
-            LatLon(0, -1), 
-            LatLon(-1, -1), 
+            LatLon(1, -1),
+            LatLon(0, -1),
+            LatLon(-1, -1),
             LatLon(-1, 0),
         ];
         assert_eq!(want.to_vec(), neighbors);
     }
-
 }
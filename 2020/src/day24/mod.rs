@@ -15,3 +15,29 @@ mod floor;
 mod latlon;
 
 pub use floor::Floor;
+
+use crate::error::Error;
+use crate::solution::Solution;
+
+/// The number of days the floor is on exhibit in part 2.
+pub const DAYS: usize = 100;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Floor;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(text.parse()?)
+    }
+
+    fn part1(&self, floor: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(floor.count_black())
+    }
+
+    fn part2(&self, floor: &Self::Input) -> Result<Self::Output2, Error> {
+        Ok(floor.clone().day(DAYS).count_black())
+    }
+}
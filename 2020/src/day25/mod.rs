@@ -0,0 +1,115 @@
+pub mod part1;
+
+use crate::error::{Error, ParseError};
+use crate::solution::{Part, Solution};
+
+const DIVISOR: u64 = 20201227;
+
+/// The subject number from which both public keys are derived.
+pub const SUBJECT: u64 = 7;
+
+/// Transforms the specified subject number using the specified loop size.
+pub fn transform(size: u64, subject: u64) -> u64 {
+    let mut value = 1;
+    for _ in 0..size {
+        value *= subject;
+        value %= DIVISOR;
+    }
+    value
+}
+
+/// Returns the loop size that transforms the specified subject number into
+/// the specified key.
+pub fn find(key: u64, subject: u64) -> Result<u64, Error> {
+    let mut value = 1;
+    for size in 1..DIVISOR {
+        value *= subject;
+        value %= DIVISOR;
+        if key == value {
+            return Ok(size);
+        }
+    }
+    Err(Error::NoSolution)
+}
+
+/// Parses the card's and door's public keys.
+pub fn parse_keys(text: &str) -> Result<(u64, u64), ParseError> {
+    let mut keys = text.split_whitespace();
+    let card = keys
+        .next()
+        .ok_or_else(|| ParseError::new("expected card's public key"))?
+        .parse()?;
+    let door = keys
+        .next()
+        .ok_or_else(|| ParseError::new("expected door's public key"))?
+        .parse()?;
+    if keys.next().is_none() {
+        Ok((card, door))
+    } else {
+        Err(ParseError::new("expected only two keys"))
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = (u64, u64);
+    type Output1 = u64;
+    type Output2 = u64;
+
+    /// Day 25 has no second puzzle.
+    const PARTS: &'static [Part] = &[Part::One];
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(parse_keys(text)?)
+    }
+
+    fn part1(&self, &(card_key, door_key): &Self::Input) -> Result<Self::Output1, Error> {
+        part1::solve(card_key, door_key)
+    }
+
+    fn part2(&self, _: &Self::Input) -> Result<Self::Output2, Error> {
+        Err(Error::internal("day 25 has no part 2"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CARD_KEY: u64 = 5764801;
+    const DOOR_KEY: u64 = 17807724;
+    const CARD_SIZE: u64 = 8;
+    const DOOR_SIZE: u64 = 11;
+    const ENC_KEY: u64 = 14897079;
+
+    #[test]
+    fn transform_card() {
+        assert_eq!(CARD_KEY, transform(CARD_SIZE, SUBJECT));
+    }
+
+    #[test]
+    fn transform_door() {
+        assert_eq!(DOOR_KEY, transform(DOOR_SIZE, SUBJECT));
+    }
+
+    #[test]
+    fn find_card() {
+        assert_eq!(CARD_SIZE, find(CARD_KEY, SUBJECT).unwrap());
+    }
+
+    #[test]
+    fn find_door() {
+        assert_eq!(DOOR_SIZE, find(DOOR_KEY, SUBJECT).unwrap());
+    }
+
+    #[test]
+    fn enc_card() {
+        assert_eq!(ENC_KEY, transform(CARD_SIZE, DOOR_KEY));
+    }
+
+    #[test]
+    fn enc_door() {
+        assert_eq!(ENC_KEY, transform(DOOR_SIZE, CARD_KEY));
+    }
+}
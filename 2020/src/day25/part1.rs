@@ -0,0 +1,7 @@
+use super::{find, transform, SUBJECT};
+use crate::error::Error;
+
+/// Returns the encryption key the card and door share.
+pub fn solve(card_key: u64, door_key: u64) -> Result<u64, Error> {
+    Ok(transform(find(card_key, SUBJECT)?, door_key))
+}
@@ -0,0 +1,50 @@
+pub mod part1;
+pub mod part2;
+
+use crate::error::{Error, ParseError, ParseErrorKind};
+use crate::grid::Grid;
+use crate::solution::Solution;
+
+/// The map: a grid of squares, true where there is a tree.  The pattern
+/// repeats to the right indefinitely.
+pub type Map = Grid<bool>;
+
+fn parse_square(c: char) -> Result<bool, ParseError> {
+    match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => {
+            let what = format!("bad square {:?}: expected '#' or '.'", c);
+            Err(ParseError::with_kind(ParseErrorKind::Unexpected, what))
+        }
+    }
+}
+
+/// Counts the trees encountered going from the top left corner to the
+/// bottom of the map along the specified slope.
+pub fn count_trees(map: &Map, right: usize, down: usize) -> usize {
+    let rows = (0..map.height()).step_by(down);
+    rows.zip((0..).step_by(right))
+        .filter(|&(y, x)| *map.get_wrapping(y as isize, x as isize))
+        .count()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Map;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(Grid::parse(text, parse_square)?)
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(map))
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<Self::Output2, Error> {
+        Ok(part2::solve(map))
+    }
+}
@@ -0,0 +1,5 @@
+use super::{count_trees, Map};
+
+pub fn solve(map: &Map) -> usize {
+    count_trees(map, 3, 1)
+}
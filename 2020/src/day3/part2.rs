@@ -0,0 +1,9 @@
+use super::{count_trees, Map};
+
+/// Multiplies the numbers of trees encountered on each of several slopes.
+pub fn solve(map: &Map) -> usize {
+    [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+        .iter()
+        .map(|&(right, down)| count_trees(map, right, down))
+        .product()
+}
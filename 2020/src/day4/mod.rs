@@ -0,0 +1,50 @@
+pub mod part1;
+pub mod part2;
+
+use crate::error::{Error, ParseError, ParseErrorKind};
+use crate::parse::{self, Paragraph};
+use crate::solution::Solution;
+use std::collections::HashMap;
+
+/// Passport fields, by key.
+pub type Passport = HashMap<String, String>;
+
+/// Parses a passport from space- or newline-separated `KEY:VALUE` fields.
+pub fn parse_passport(paragraph: &Paragraph) -> Result<Passport, ParseError> {
+    let mut passport = Passport::new();
+    for (index, line) in paragraph.lines.iter().enumerate() {
+        for pair in line.split_whitespace() {
+            let (key, value) = pair.split_once(':').ok_or_else(|| {
+                let what = "bad field: expected KEY:VALUE";
+                let err = ParseError::with_kind(ParseErrorKind::Unexpected, what);
+                paragraph.locate(index, err.within(line, pair))
+            })?;
+            passport.insert(key.to_owned(), value.to_owned());
+        }
+    }
+    Ok(passport)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Passport>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        let paragraphs = parse::paragraphs(text);
+        Ok(paragraphs
+            .iter()
+            .map(parse_passport)
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(&self, passports: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(passports))
+    }
+
+    fn part2(&self, passports: &Self::Input) -> Result<Self::Output2, Error> {
+        Ok(part2::solve(passports))
+    }
+}
@@ -0,0 +1,20 @@
+use super::Passport;
+
+const KEYS: [&str; 7] = [
+    "byr", // Birth Year
+    "iyr", // Issue Year
+    "eyr", // Expiration Year
+    "hgt", // Height
+    "hcl", // Hair Color
+    "ecl", // Eye Color
+    "pid", // Passport ID
+];
+
+fn is_valid(passport: &Passport) -> bool {
+    KEYS.iter().all(|&key| passport.contains_key(key))
+}
+
+/// Counts the passports having every required field.
+pub fn solve(passports: &[Passport]) -> usize {
+    passports.iter().filter(|p| is_valid(p)).count()
+}
@@ -0,0 +1,91 @@
+use super::Passport;
+use std::ops::RangeInclusive;
+
+fn is_four_digits_in_range(s: &str, r: RangeInclusive<u32>) -> bool {
+    s.len() == 4 && s.parse().ok().filter(|n| r.contains(n)).is_some()
+}
+
+fn byr_is_valid(s: &str) -> bool {
+    is_four_digits_in_range(s, 1920..=2002)
+}
+
+fn iyr_is_valid(s: &str) -> bool {
+    is_four_digits_in_range(s, 2010..=2020)
+}
+
+fn eyr_is_valid(s: &str) -> bool {
+    is_four_digits_in_range(s, 2020..=2030)
+}
+
+fn hgt_is_valid(s: &str) -> bool {
+    let in_range = |num: &str, range: RangeInclusive<u32>| {
+        num.parse().ok().filter(|n| range.contains(n)).is_some()
+    };
+    if let Some(num) = s.strip_suffix("cm") {
+        in_range(num, 150..=193)
+    } else if let Some(num) = s.strip_suffix("in") {
+        in_range(num, 59..=76)
+    } else {
+        false
+    }
+}
+
+fn hcl_is_valid(s: &str) -> bool {
+    s.len() == 7 && s.starts_with('#') && s.chars().skip(1).all(|c| "0123456789abcdef".contains(c))
+}
+
+fn ecl_is_valid(s: &str) -> bool {
+    ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"][..].contains(&s)
+}
+
+fn pid_is_valid(s: &str) -> bool {
+    s.len() == 9 && s.chars().all(|c| "0123456789".contains(c))
+}
+
+fn has_valid<F>(passport: &Passport, key: &str, criterion: F) -> bool
+where
+    F: FnOnce(&str) -> bool,
+{
+    passport.get(key).filter(|value| criterion(value)).is_some()
+}
+
+fn is_valid(passport: &Passport) -> bool {
+    has_valid(passport, "byr", byr_is_valid)
+        && has_valid(passport, "iyr", iyr_is_valid)
+        && has_valid(passport, "eyr", eyr_is_valid)
+        && has_valid(passport, "hgt", hgt_is_valid)
+        && has_valid(passport, "hcl", hcl_is_valid)
+        && has_valid(passport, "ecl", ecl_is_valid)
+        && has_valid(passport, "pid", pid_is_valid)
+}
+
+/// Counts the passports having every required field, each with a valid value.
+pub fn solve(passports: &[Passport]) -> usize {
+    passports.iter().filter(|p| is_valid(p)).count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn criteria() {
+        assert!(byr_is_valid("2002"));
+        assert!(!byr_is_valid("2003"));
+
+        assert!(hgt_is_valid("60in"));
+        assert!(hgt_is_valid("190cm"));
+        assert!(!hgt_is_valid("190in"));
+        assert!(!hgt_is_valid("190"));
+
+        assert!(hcl_is_valid("#123abc"));
+        assert!(!hcl_is_valid("#123abz"));
+        assert!(!hcl_is_valid("123abc"));
+
+        assert!(ecl_is_valid("brn"));
+        assert!(!ecl_is_valid("wat"));
+
+        assert!(pid_is_valid("000000001"));
+        assert!(!pid_is_valid("0123456789"));
+    }
+}
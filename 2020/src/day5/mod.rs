@@ -0,0 +1,71 @@
+pub mod part1;
+pub mod part2;
+
+use crate::error::{Error, ParseError, ParseErrorKind};
+use crate::parse;
+use crate::solution::Solution;
+
+/// Seat IDs have ten bits: seven for the row, and three for the column.
+pub const MAX_ID: usize = (1 << 10) - 1;
+
+fn parse_char(c: char) -> Result<usize, ParseError> {
+    match c {
+        'F' | 'L' => Ok(0),
+        'B' | 'R' => Ok(1),
+        _ => {
+            let what = format!("{}: expected any of FBLR", c);
+            Err(ParseError::with_kind(ParseErrorKind::Unexpected, what))
+        }
+    }
+}
+
+/// Parses a binary space partitioned seat, such as `FBFBBFFRLR`, returning
+/// its ID.
+pub fn parse_seat(seat: &str) -> Result<usize, ParseError> {
+    let mut value = 0;
+    for (i, c) in seat.char_indices() {
+        let bit = parse_char(c).map_err(|err| err.with_span(i..(i + c.len_utf8())))?;
+        value = (value << 1) | bit;
+    }
+    Ok(value)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        let seats = parse::lines(text, parse_seat)?;
+        if seats.is_empty() {
+            Err(Error::EmptyInput)
+        } else {
+            Ok(seats)
+        }
+    }
+
+    fn part1(&self, seats: &Self::Input) -> Result<Self::Output1, Error> {
+        part1::solve(seats)
+    }
+
+    fn part2(&self, seats: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(seats)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn seat_ids() {
+        assert_eq!(357, parse_seat("FBFBBFFRLR").unwrap());
+        assert_eq!(820, parse_seat("BBFFBBFRLL").unwrap());
+        assert_eq!(
+            Some(2),
+            parse_seat("FBXB").unwrap_err().span().map(|s| s.start)
+        );
+    }
+}
@@ -0,0 +1,6 @@
+use crate::error::Error;
+
+/// Returns the highest seat ID.
+pub fn solve(seats: &[usize]) -> Result<usize, Error> {
+    seats.iter().cloned().max().ok_or(Error::EmptyInput)
+}
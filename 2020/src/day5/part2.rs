@@ -0,0 +1,11 @@
+use super::MAX_ID;
+use crate::error::Error;
+use std::collections::HashSet;
+
+/// Returns the ID of the one empty seat whose neighbors are both taken.
+pub fn solve(seats: &[usize]) -> Result<usize, Error> {
+    let taken: HashSet<usize> = seats.iter().cloned().collect();
+    (1..MAX_ID)
+        .find(|id| !taken.contains(id) && taken.contains(&(id - 1)) && taken.contains(&(id + 1)))
+        .ok_or(Error::NoSolution)
+}
@@ -0,0 +1,43 @@
+pub mod part1;
+pub mod part2;
+
+use crate::error::Error;
+use crate::parse;
+use crate::solution::Solution;
+use std::collections::HashSet;
+
+/// The questions to which one person answered "yes".
+pub type Person = HashSet<char>;
+
+/// A group of people, whose answers are separated from other groups' by
+/// blank lines.
+pub type Group = Vec<Person>;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Group>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(parse::paragraphs(text)
+            .iter()
+            .map(|paragraph| {
+                paragraph
+                    .lines
+                    .iter()
+                    .map(|line| line.chars().collect())
+                    .collect()
+            })
+            .collect())
+    }
+
+    fn part1(&self, groups: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(groups))
+    }
+
+    fn part2(&self, groups: &Self::Input) -> Result<Self::Output2, Error> {
+        Ok(part2::solve(groups))
+    }
+}
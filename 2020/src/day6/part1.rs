@@ -0,0 +1,11 @@
+use super::Group;
+use std::collections::HashSet;
+
+/// Sums, over all groups, the number of questions to which anyone in the
+/// group answered "yes".
+pub fn solve(groups: &[Group]) -> usize {
+    groups
+        .iter()
+        .map(|group| group.iter().flatten().collect::<HashSet<_>>().len())
+        .sum()
+}
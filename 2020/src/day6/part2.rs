@@ -0,0 +1,16 @@
+use super::Group;
+
+/// Sums, over all groups, the number of questions to which everyone in the
+/// group answered "yes".
+pub fn solve(groups: &[Group]) -> usize {
+    groups
+        .iter()
+        .map(|group| match group.split_first() {
+            Some((first, rest)) => first
+                .iter()
+                .filter(|question| rest.iter().all(|person| person.contains(question)))
+                .count(),
+            None => 0,
+        })
+        .sum()
+}
@@ -1,2 +1,35 @@
 pub mod part1;
 pub mod part2;
+mod rules;
+
+pub use rules::{check, name, Rules};
+
+use crate::error::Error;
+use crate::solution::Solution;
+
+pub type Color = (String, String);
+
+/// The color of the bag we're carrying.
+fn shiny_gold() -> Color {
+    ("shiny".to_owned(), "gold".to_owned())
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Rules;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(text.parse()?)
+    }
+
+    fn part1(&self, rules: &Self::Input) -> Result<Self::Output1, Error> {
+        Ok(part1::solve(rules, &shiny_gold()))
+    }
+
+    fn part2(&self, rules: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(rules, &shiny_gold())
+    }
+}
@@ -1,54 +1,34 @@
-use std::collections::HashMap;
-use std::error::Error;
-use std::fs::File;
-use std::io::{BufRead as _, BufReader};
-use std::path::Path;
+use super::{Color, Rules};
+use crate::error::Error;
 
-type Color = (String, String);
-type RuleMap = HashMap<Color, HashMap<Color, usize>>;
-
-fn load_rules<P: AsRef<Path>>(input: P) -> Result<RuleMap, Box<dyn Error>> {
-    let mut rules = RuleMap::new();
-    for line in BufReader::new(File::open(input)?).lines() {
-        let line = line?;
-        let mut tokens = line.split_whitespace();
-        let key = (
-            tokens.next().ok_or("expected shade")?.to_owned(),
-            tokens.next().ok_or("expected color")?.to_owned(),
-        );
-        let mut tokens = tokens.skip(2); // "bags contain"
-        let mut contents = HashMap::new();
-        while let (Some(count), Some(shade), Some(color), Some(_bags)) =
-            (tokens.next(), tokens.next(), tokens.next(), tokens.next())
-        {
-            // The loop test fails if the bag contains "no" "other" "bags."
-            let color = (shade.to_owned(), color.to_owned());
-            contents.insert(color, count.parse()?);
-        }
-        rules.insert(key, contents);
-    }
-    Ok(rules)
-}
-
-fn tally(rules: &RuleMap, color: &Color) -> usize {
-    rules[color]
-        .iter()
-        .map(|(c, n)| n * (1 + tally(rules, c)))
-        .sum()
-}
-
-pub fn solve(input: &str, color: &Color) -> Result<usize, Box<dyn Error>> {
-    Ok(tally(&load_rules(input)?, color))
+/// Returns the number of bags required inside a bag of the specified color.
+pub fn solve(rules: &Rules, color: &Color) -> Result<usize, Error> {
+    rules.total_inside(color).ok_or(Error::NoSolution)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::day7::shiny_gold;
+    use std::fs;
 
     #[test]
     fn sample1() {
-        let rules = load_rules("tests/day7/sample1").unwrap();
-        let color = ("shiny".to_owned(), "gold".to_owned());
-        assert_eq!(32, tally(&rules, &color));
+        let text = fs::read_to_string("tests/day7/sample1").unwrap();
+        let rules = text.parse().unwrap();
+        assert_eq!(32, solve(&rules, &shiny_gold()).unwrap());
+    }
+
+    #[test]
+    fn circular() {
+        let rules: Rules = "shiny gold bags contain 1 dark red bag.\n\
+                            dark red bags contain 2 shiny gold bags, 1 faded blue bag."
+            .parse()
+            .unwrap();
+        assert!(matches!(
+            solve(&rules, &shiny_gold()),
+            Err(Error::NoSolution)
+        ));
+        assert_eq!(1, crate::day7::part1::solve(&rules, &shiny_gold()));
     }
 }
@@ -0,0 +1,327 @@
+use super::Color;
+use crate::error::{ParseError, ParseErrorKind};
+use crate::graph::Graph;
+use crate::parse;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+/// The luggage rules: which bags must directly contain how many of which
+/// others.  Edges of the graph lead from each bag to the bags directly
+/// inside it, weighted by how many are required.
+#[derive(Clone, Debug)]
+pub struct Rules {
+    graph: Graph<Color, usize>,
+}
+
+/// Returns the name of the specified color, as it appears in the rules.
+pub fn name(color: &Color) -> String {
+    format!("{} {}", color.0, color.1)
+}
+
+/// A bag's color, and the colors and numbers of the bags directly inside.
+type Rule = (Color, Vec<(Color, usize)>);
+
+/// Parses a rule, such as
+/// `light red bags contain 1 bright white bag, 2 muted yellow bags.`
+fn parse_rule(line: &str) -> Result<Rule, ParseError> {
+    let mut tokens = line.split_whitespace();
+    let mut expect = |what| {
+        tokens.next().ok_or_else(|| {
+            ParseError::with_kind(ParseErrorKind::UnexpectedEnd, what)
+                .with_span(line.len()..line.len())
+        })
+    };
+    let color = (
+        expect("expected shade")?.to_owned(),
+        expect("expected color")?.to_owned(),
+    );
+    expect("expected \"bags\"")?;
+    expect("expected \"contain\"")?;
+    let mut contents = Vec::new();
+    loop {
+        let count = expect("expected count")?;
+        if count == "no" {
+            break; // "no other bags."
+        }
+        let count = parse::integer(count).map_err(|err| err.within(line, count))?;
+        let inner = (
+            expect("expected shade")?.to_owned(),
+            expect("expected color")?.to_owned(),
+        );
+        contents.push((inner, count));
+        if expect("expected \"bags\"")?.ends_with('.') {
+            break;
+        }
+    }
+    Ok((color, contents))
+}
+
+impl Rules {
+    fn new(rules: Vec<Rule>) -> Rules {
+        let mut graph = Graph::new();
+        for (color, contents) in rules {
+            graph.add_node(color.clone());
+            for (inner, count) in contents {
+                graph.add_edge(color.clone(), inner, count);
+            }
+        }
+        Rules { graph }
+    }
+
+    /// Returns the graph of which bags directly contain which.
+    pub fn graph(&self) -> &Graph<Color, usize> {
+        &self.graph
+    }
+
+    /// Returns the number of colors of bag that can eventually contain a bag
+    /// of the specified color.
+    pub fn containers(&self, color: &Color) -> usize {
+        let outward = self.graph.reversed();
+        outward.bfs(color).len().saturating_sub(1)
+    }
+
+    /// Returns the number of inner bags of the specified color that a bag of
+    /// the outer color must ultimately contain, counting bags inside bags;
+    /// or None if the rules are circular, so that it must contain endless
+    /// bags, or so many that they can't be counted.
+    pub fn count_inside(&self, outer: &Color, inner: &Color) -> Option<usize> {
+        self.tally(outer, |color| color == inner)
+    }
+
+    /// Returns the total number of bags that a bag of the specified color
+    /// must contain, or None if the rules are circular or the total
+    /// overflows.
+    pub fn total_inside(&self, color: &Color) -> Option<usize> {
+        self.tally(color, |_| true)
+    }
+
+    /// Returns the number of bags that a bag of the specified color must
+    /// contain, counting only those for whose colors counted returns true;
+    /// or None if the rules are circular or the number overflows.
+    fn tally<F>(&self, outer: &Color, counted: F) -> Option<usize>
+    where
+        F: Fn(&Color) -> bool,
+    {
+        let mut totals: HashMap<&Color, usize> = HashMap::new();
+        for color in self.graph.postorder(outer)? {
+            let mut total: usize = 0;
+            for (inner, &count) in self.graph.successors(color) {
+                let each = totals[inner].checked_add(usize::from(counted(inner)))?;
+                total = total.checked_add(count.checked_mul(each)?)?;
+            }
+            totals.insert(color, total);
+        }
+        Some(totals.get(outer).copied().unwrap_or(0))
+    }
+
+    /// Returns the colors of the bags that no other bag contains, in the
+    /// order of their rules.
+    pub fn outermost(&self) -> Vec<&Color> {
+        let nodes = self.graph.nodes();
+        nodes
+            .filter(|color| self.graph.predecessors(color).next().is_none())
+            .collect()
+    }
+
+    /// Returns the longest chain of bags nested one inside the next, from
+    /// outermost to innermost, or None if the rules are circular.  Of chains
+    /// equally long, the one whose bags are mentioned first is returned.
+    pub fn deepest_chain(&self) -> Option<Vec<&Color>> {
+        // For each bag, the length of the longest chain starting with it,
+        // and the next bag in that chain.
+        let mut depths: HashMap<&Color, (usize, Option<&Color>)> = HashMap::new();
+        for color in self.graph.topological_order()?.into_iter().rev() {
+            let inner = self.graph.successors(color).map(|(inner, _)| inner);
+            let deepest = inner.fold(None, |best: Option<&Color>, inner| match best {
+                Some(best) if depths[best].0 >= depths[inner].0 => Some(best),
+                _ => Some(inner),
+            });
+            let depth = deepest.map_or(1, |inner| depths[inner].0 + 1);
+            depths.insert(color, (depth, deepest));
+        }
+        let outer = self
+            .graph
+            .nodes()
+            .fold(None, |best: Option<&Color>, color| match best {
+                Some(best) if depths[best].0 >= depths[color].0 => Some(best),
+                _ => Some(color),
+            });
+        let chain = std::iter::successors(outer, |color| depths[color].1);
+        Some(chain.collect())
+    }
+}
+
+impl FromStr for Rules {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Rules::new(parse::lines(s, parse_rule)?))
+    }
+}
+
+/// Parses rules, and checks that they describe bags that can be packed:
+/// that every bag mentioned has a rule of its own, and that no bag must
+/// contain itself.  Returns every problem found, each located at the rule to
+/// blame; circular rules show the chain of bags that leads back around.
+pub fn check(text: &str) -> Result<Rules, Vec<ParseError>> {
+    let parsed = parse::lines(text, parse_rule).map_err(|err| vec![err])?;
+    let lines: Vec<&str> = text.lines().collect();
+    // The index of each bag's rule.
+    let mut heads = HashMap::new();
+    for (index, (color, _)) in parsed.iter().enumerate() {
+        heads.entry(color.clone()).or_insert(index);
+    }
+    // Locates an error at the mention of the specified bag among the
+    // contents of the rule on the line at the specified index.
+    let locate = |err: ParseError, index: usize, inner: &Color| {
+        let line = lines[index];
+        let contents = line.find(" contain ").map_or(line, |at| &line[at..]);
+        let name = name(inner);
+        let err = match contents.find(&name) {
+            Some(at) => err.within(line, &contents[at..at + name.len()]),
+            None => err,
+        };
+        err.on_line(index + 1, line)
+    };
+    let mut problems = Vec::new();
+    let mut reported = HashSet::new();
+    for (index, (_, contents)) in parsed.iter().enumerate() {
+        for (inner, _) in contents {
+            if !heads.contains_key(inner) && reported.insert(inner.clone()) {
+                let what = format!("no rule for {} bags", name(inner));
+                problems.push(locate(ParseError::new(what), index, inner));
+            }
+        }
+    }
+    let rules = Rules::new(parsed);
+    for component in rules.graph.strongly_connected_components() {
+        if let Some(cycle) = rules.graph.cycle_through(component[0]) {
+            let chain: Vec<_> = cycle.iter().map(|color| name(color)).collect();
+            let what = format!("circular rules: {}", chain.join(" -> "));
+            problems.push(locate(ParseError::new(what), heads[cycle[0]], cycle[1]));
+        }
+    }
+    if problems.is_empty() {
+        Ok(rules)
+    } else {
+        problems.sort_by_key(|err| err.line());
+        Err(problems)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    fn color(name: &str) -> Color {
+        let (shade, color) = name.split_once(' ').unwrap();
+        (shade.to_owned(), color.to_owned())
+    }
+
+    fn sample1() -> Rules {
+        fs::read_to_string("tests/day7/sample1")
+            .unwrap()
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let rules = sample1();
+        assert_eq!((9, 13), (rules.graph().len(), rules.graph().edge_count()));
+        let weight = rules
+            .graph()
+            .weight(&color("muted yellow"), &color("faded blue"));
+        assert_eq!(Some(&9), weight);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = "light red bags contain".parse::<Rules>().unwrap_err();
+        assert_eq!(ParseErrorKind::UnexpectedEnd, err.kind());
+        let text = "faded blue bags contain no other bags.\n\
+                    light red bags contain one bright white bag.";
+        let err = text.parse::<Rules>().unwrap_err();
+        assert_eq!((ParseErrorKind::Number, Some(2)), (err.kind(), err.line()));
+        assert_eq!(Some(24), err.column());
+    }
+
+    #[test]
+    fn queries() {
+        let rules = sample1();
+        let gold = color("shiny gold");
+        assert_eq!(4, rules.containers(&gold));
+        assert_eq!(Some(32), rules.total_inside(&gold));
+        assert_eq!(
+            Some(3 + 2 * 5),
+            rules.count_inside(&gold, &color("faded blue"))
+        );
+        let light_red = color("light red");
+        assert_eq!(Some(1 + 2 * 2), rules.count_inside(&light_red, &gold));
+        let outermost: Vec<_> = rules.outermost().into_iter().map(name).collect();
+        assert_eq!(vec!["light red", "dark orange"], outermost);
+        let chain: Vec<_> = rules
+            .deepest_chain()
+            .unwrap()
+            .into_iter()
+            .map(name)
+            .collect();
+        let expected = [
+            "light red",
+            "bright white",
+            "shiny gold",
+            "dark olive",
+            "faded blue",
+        ];
+        assert_eq!(expected.to_vec(), chain);
+    }
+
+    #[test]
+    fn check() {
+        let text = fs::read_to_string("tests/day7/sample1").unwrap();
+        assert!(super::check(&text).is_ok());
+        let text = "shiny gold bags contain 2 dark red bags, 1 faded pink bag.\n\
+                    dark red bags contain 1 dark blue bag.\n\
+                    dark blue bags contain 3 shiny gold bags, 1 faded pink bag.\n\
+                    dim tan bags contain 1 dim tan bag.";
+        let problems = super::check(text).unwrap_err();
+        let problems: Vec<_> = problems.iter().map(|err| err.to_string()).collect();
+        let expected = [
+            "line 1, column 44: no rule for faded pink bags",
+            "line 1, column 27: circular rules: shiny gold -> dark red -> dark blue -> shiny gold",
+            "line 4, column 24: circular rules: dim tan -> dim tan",
+        ];
+        assert_eq!(expected.to_vec(), problems);
+        let err = &super::check("dim tan bags contain 1").unwrap_err()[0];
+        assert_eq!(ParseErrorKind::UnexpectedEnd, err.kind());
+    }
+
+    #[test]
+    fn circular() {
+        let rules: Rules = "dark red bags contain 2 dark blue bags.\n\
+                            dark blue bags contain 1 dark red bag, 1 dark green bag.\n\
+                            dark green bags contain no other bags."
+            .parse()
+            .unwrap();
+        assert_eq!(None, rules.total_inside(&color("dark red")));
+        assert_eq!(Some(0), rules.total_inside(&color("dark green")));
+        assert_eq!(None, rules.deepest_chain());
+        assert_eq!(2, rules.containers(&color("dark green")));
+        assert!(rules.outermost().is_empty());
+    }
+
+    #[test]
+    fn overflow() {
+        let text = "dark red bags contain 4294967296 dark blue bags.\n\
+                    dark blue bags contain 4294967296 dark green bags.\n\
+                    dark green bags contain no other bags.";
+        let rules: Rules = text.parse().unwrap();
+        assert_eq!(None, rules.total_inside(&color("dark red")));
+        assert_eq!(
+            None,
+            rules.count_inside(&color("dark red"), &color("dark green"))
+        );
+        assert_eq!(Some(1 << 32), rules.total_inside(&color("dark blue")));
+    }
+}
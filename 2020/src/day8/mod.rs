@@ -0,0 +1,26 @@
+pub mod part1;
+pub mod part2;
+
+use crate::error::Error;
+use crate::solution::Solution;
+use crate::vm::{OpcodeTable, Program, Word};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Program;
+    type Output1 = Word;
+    type Output2 = Word;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(OpcodeTable::standard().parse_program(text)?)
+    }
+
+    fn part1(&self, program: &Self::Input) -> Result<Self::Output1, Error> {
+        part1::solve(program)
+    }
+
+    fn part2(&self, program: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(program)
+    }
+}
@@ -0,0 +1,27 @@
+use crate::error::Error;
+use crate::vm::{Instruction, Machine, Outcome, Word};
+
+/// Returns the accumulator value just before any instruction would run a
+/// second time.
+pub fn solve(program: &[Instruction]) -> Result<Word, Error> {
+    let mut machine = Machine::new(program.to_vec());
+    if let Outcome::Loop = machine.run() {
+        Ok(machine.acc())
+    } else {
+        Err(Error::NoSolution)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse;
+    use std::fs;
+
+    #[test]
+    fn sample1() {
+        let text = fs::read_to_string("tests/day8/sample1").unwrap();
+        let program: Vec<Instruction> = parse::lines(&text, str::parse).unwrap();
+        assert_eq!(5, solve(&program).unwrap());
+    }
+}
@@ -0,0 +1,153 @@
+use crate::error::Error;
+use crate::vm::{Instruction, Opcode, Word};
+use std::collections::VecDeque;
+
+/// A change to one instruction that makes the program halt.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Repair {
+    /// The index of the instruction changed.
+    pub index: usize,
+    /// What the instruction becomes.
+    pub patched: Instruction,
+    /// The accumulator value once the repaired program halts.
+    pub acc: Word,
+}
+
+/// Returns the instruction with `jmp` swapped for `nop` or vice versa, or
+/// None if it's neither.
+fn flipped(instruction: Instruction) -> Option<Instruction> {
+    let opcode = match instruction.opcode {
+        Opcode::JMP => Opcode::NOP,
+        Opcode::NOP => Opcode::JMP,
+        _ => return None,
+    };
+    Some(Instruction::new(opcode, instruction.arg))
+}
+
+/// Returns the index of the instruction that runs after the one at the
+/// specified index, which may be the program's length, at its end; or None
+/// if the instruction would jump out of the program.
+fn next(program: &[Instruction], index: usize, instruction: Instruction) -> Option<usize> {
+    let offset = match instruction.opcode {
+        Opcode::JMP => instruction.arg,
+        _ => 1,
+    };
+    let target = (index as Word).checked_add(offset)?;
+    if (0..=program.len() as Word).contains(&target) {
+        Some(target as usize)
+    } else {
+        None
+    }
+}
+
+/// Returns how much the specified instruction adds to the accumulator.
+fn gain(instruction: Instruction) -> Word {
+    match instruction.opcode {
+        Opcode::ACC => instruction.arg,
+        _ => 0,
+    }
+}
+
+/// Returns every way to repair the program by swapping one `jmp` for a
+/// `nop` or vice versa, in order of the instruction changed.  A program that
+/// already halts needs no repair, so has none.
+// O(n) time, by working backward from the end to the set of instructions
+// that reach it, and what each adds to the accumulator on the way: flipping
+// an instruction on the path the broken program takes repairs it just when
+// the flip leads into that set, and the result is what the path added before
+// the flip plus what the rest adds.
+pub fn repairs(program: &[Instruction]) -> Vec<Repair> {
+    let end = program.len();
+    let mut incoming = vec![Vec::new(); end + 1];
+    for (index, &instruction) in program.iter().enumerate() {
+        if let Some(next) = next(program, index, instruction) {
+            incoming[next].push(index);
+        }
+    }
+    // For each instruction that leads to the end, what the accumulator gains
+    // from there on.
+    let mut to_end: Vec<Option<Word>> = vec![None; end + 1];
+    to_end[end] = Some(0);
+    let mut queue = VecDeque::from(vec![(end, 0)]);
+    while let Some((index, rest)) = queue.pop_front() {
+        for &prev in &incoming[index] {
+            if to_end[prev].is_none() {
+                let total = gain(program[prev]).wrapping_add(rest);
+                to_end[prev] = Some(total);
+                queue.push_back((prev, total));
+            }
+        }
+    }
+    // The path the program takes until it loops or jumps out, with the
+    // accumulator's value as it reaches each instruction.
+    let mut path = Vec::new();
+    let mut visited = vec![false; end + 1];
+    let (mut index, mut acc): (usize, Word) = (0, 0);
+    while !visited[index] {
+        if index == end {
+            return Vec::new();
+        }
+        visited[index] = true;
+        path.push((index, acc));
+        acc = acc.wrapping_add(gain(program[index]));
+        match next(program, index, program[index]) {
+            Some(next) => index = next,
+            None => break,
+        }
+    }
+    path.sort_unstable();
+    let fixes = path.into_iter().filter_map(|(index, acc)| {
+        let patched = flipped(program[index])?;
+        let rest = to_end[next(program, index, patched)?]?;
+        Some(Repair {
+            index,
+            patched,
+            acc: acc.wrapping_add(rest),
+        })
+    });
+    fixes.collect()
+}
+
+/// Repairs the program by swapping one `jmp` for a `nop` or vice versa, so
+/// that it halts, and returns the final accumulator value.
+pub fn solve(program: &[Instruction]) -> Result<Word, Error> {
+    let repair = repairs(program).into_iter().next();
+    repair.map(|repair| repair.acc).ok_or(Error::NoSolution)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::vm::OpcodeTable;
+    use std::fs;
+
+    fn parse(text: &str) -> Vec<Instruction> {
+        OpcodeTable::standard().parse_program(text).unwrap()
+    }
+
+    #[test]
+    fn sample1() {
+        let text = fs::read_to_string("tests/day8/sample1").unwrap();
+        let program = parse(&text);
+        let expected = Repair {
+            index: 7,
+            patched: "nop -4".parse().unwrap(),
+            acc: 8,
+        };
+        assert_eq!(vec![expected], super::repairs(&program));
+        assert_eq!(8, solve(&program).unwrap());
+    }
+
+    #[test]
+    fn repairs() {
+        let found = |text| -> Vec<_> {
+            let repairs = super::repairs(&parse(text));
+            repairs.iter().map(|r| (r.index, r.acc)).collect()
+        };
+        assert_eq!(vec![(0, 0), (1, 1)], found("nop +3\njmp +0\nacc +1"));
+        assert_eq!(vec![(0, 0)], found("jmp -1"));
+        assert!(found("acc +1\nnop +0").is_empty());
+        let program = parse("jmp +0\njmp -1");
+        assert!(matches!(solve(&program), Err(Error::NoSolution)));
+    }
+}
@@ -0,0 +1,29 @@
+pub mod part1;
+pub mod part2;
+
+use crate::error::Error;
+use crate::parse;
+use crate::solution::Solution;
+
+/// How many numbers the XMAS cipher remembers.  The samples use 5 instead.
+pub const PREAMBLE: usize = 25;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+        Ok(parse::integers(text)?)
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> Result<Self::Output1, Error> {
+        part1::solve(numbers, PREAMBLE)
+    }
+
+    fn part2(&self, numbers: &Self::Input) -> Result<Self::Output2, Error> {
+        part2::solve(numbers, part1::solve(numbers, PREAMBLE)?)
+    }
+}
@@ -0,0 +1,43 @@
+use crate::error::Error;
+use std::collections::VecDeque;
+
+fn is_sum_of_any_pair(number: usize, past: &VecDeque<usize>) -> bool {
+    for m in past {
+        for n in past {
+            if m != n && m + n == number {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Returns the first number after the preamble that isn't the sum of two of
+/// the numbers in memory, which holds as many of the most recent numbers as
+/// the preamble.
+pub fn solve(numbers: &[usize], memory: usize) -> Result<usize, Error> {
+    let (preamble, rest) = numbers.split_at(memory.min(numbers.len()));
+    let mut past: VecDeque<usize> = preamble.iter().copied().collect();
+    for &number in rest {
+        if !is_sum_of_any_pair(number, &past) {
+            return Ok(number);
+        }
+        past.pop_front();
+        past.push_back(number);
+    }
+    Err(Error::NoSolution)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse;
+    use std::fs;
+
+    #[test]
+    fn sample1() {
+        let text = fs::read_to_string("tests/day9/sample1").unwrap();
+        let numbers = parse::integers(&text).unwrap();
+        assert_eq!(127, solve(&numbers, 5).unwrap());
+    }
+}
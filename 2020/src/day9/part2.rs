@@ -0,0 +1,44 @@
+use crate::error::Error;
+
+/// Returns the sum of the smallest and largest of the specified numbers.
+fn sum_min_max(numbers: &[usize]) -> Option<usize> {
+    Some(numbers.iter().min()? + numbers.iter().max()?)
+}
+
+/// Finds a contiguous run of at least two of the specified numbers that adds
+/// up to the specified series, and returns the sum of its smallest and
+/// largest numbers.
+pub fn solve(numbers: &[usize], series: usize) -> Result<usize, Error> {
+    let sums: Vec<usize> = numbers // sum of numbers up to each index, inclusive
+        .iter()
+        .scan(0, |sum, number| {
+            *sum += number;
+            Some(*sum)
+        })
+        .collect();
+    for end in 1..sums.len() {
+        if sums[end] == series {
+            return sum_min_max(&numbers[..=end]).ok_or(Error::NoSolution);
+        }
+        for begin in 0..(end - 1) {
+            if sums[end] - sums[begin] == series {
+                return sum_min_max(&numbers[(begin + 1)..=end]).ok_or(Error::NoSolution);
+            }
+        }
+    }
+    Err(Error::NoSolution)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse;
+    use std::fs;
+
+    #[test]
+    fn sample2() {
+        let text = fs::read_to_string("tests/day9/sample2").unwrap();
+        let numbers = parse::integers(&text).unwrap();
+        assert_eq!(62, solve(&numbers, 127).unwrap());
+    }
+}
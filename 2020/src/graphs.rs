@@ -0,0 +1,61 @@
+//! Graphs for `aoc graph`, in Graphviz's DOT language: the puzzles whose
+//! inputs describe what leads to what.
+
+use crate::day10;
+use crate::day7::{self, Rules};
+use crate::error::Error;
+
+/// The days whose graphs can be drawn.
+pub const DAYS: [u8; 2] = [7, 10];
+
+/// Returns the graph described by the specified input to the specified
+/// day's puzzle, in DOT, or None if the day has no graph.
+pub fn dot(day: u8, text: &str) -> Option<Result<String, Error>> {
+    Some(match day {
+        7 => bags(text),
+        10 => adapters(text),
+        _ => return None,
+    })
+}
+
+/// Returns which bags must directly contain which, labeled by how many.
+fn bags(text: &str) -> Result<String, Error> {
+    let rules: Rules = text.parse()?;
+    let graph = rules.graph();
+    Ok(graph.to_dot(day7::name, |count| Some(count.to_string())))
+}
+
+/// Returns which adapters can plug into which, from the outlet, at 0 jolts,
+/// to the device.
+fn adapters(text: &str) -> Result<String, Error> {
+    let adapters = day10::parse_joltages(text)?;
+    let (joltages, graph) = day10::graph(&adapters);
+    Ok(graph.to_dot(|&i| joltages[i].to_string(), |_| None))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn bags() {
+        let text = fs::read_to_string("tests/day7/sample1").unwrap();
+        let dot = dot(7, &text).unwrap().unwrap();
+        assert!(dot.contains("    n0 [label=\"light red\"];\n"));
+        assert!(dot.contains("    n0 -> n1 [label=\"1\"];\n"));
+        let count = |what| dot.lines().filter(|line| line.contains(what)).count();
+        assert_eq!((9 + 13, 13), (count("label"), count("->")));
+    }
+
+    #[test]
+    fn adapters() {
+        let text = fs::read_to_string("tests/day10/sample1a").unwrap();
+        let dot = dot(10, &text).unwrap().unwrap();
+        assert!(dot.starts_with("digraph {\n    n0 [label=\"0\"];\n"));
+        // The outlet, 11 adapters, and the device, with 16 ways to connect.
+        let count = |what| dot.lines().filter(|line| line.contains(what)).count();
+        assert_eq!((13, 16), (count("label"), count("->")));
+        assert!(super::dot(11, &text).is_none());
+    }
+}
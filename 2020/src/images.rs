@@ -0,0 +1,79 @@
+//! Pictures for `aoc render`: each generation of the puzzles that are
+//! cellular automata, and day 20's assembled image, with the sea monsters
+//! picked out.
+
+use crate::day20::{self, Tile};
+use crate::error::Error;
+use crate::image::Image;
+use crate::solution::Part;
+use crate::{day11, day17, day24};
+use std::iter;
+
+pub type Frames = Box<dyn Iterator<Item = Image>>;
+
+/// The days that can be pictured.
+pub const DAYS: [u8; 4] = [11, 17, 20, 24];
+
+/// Returns the pictures of the specified part of the specified day's puzzle
+/// unfolding from the specified input, each cell being scale pixels across,
+/// or None if the day isn't pictured.  Every picture of a puzzle is the same
+/// size.
+pub fn frames(day: u8, part: Part, text: &str, scale: usize) -> Option<Result<Frames, Error>> {
+    Some(match day {
+        11 => seating(text, part, scale),
+        17 => cubes(text, part, scale),
+        20 => sea(text, scale),
+        24 => floor(text, scale),
+        _ => return None,
+    })
+}
+
+/// Returns each generation of the seating chart, until it settles.
+fn seating(text: &str, part: Part, scale: usize) -> Result<Frames, Error> {
+    let grid: day11::Grid = text.parse()?;
+    Ok(Box::new(
+        grid.generations(day11::SeatingRules::preset(part))
+            .map(move |grid| grid.image(scale)),
+    ))
+}
+
+/// Returns each cycle of the boot process, in 3 dimensions for part 1 and 4
+/// for part 2.
+fn cubes(text: &str, part: Part, scale: usize) -> Result<Frames, Error> {
+    let slice: day17::Slice = text.parse()?;
+    let cycles = day17::CYCLES;
+    Ok(match part {
+        Part::One => Box::new(day17::images::<3>(&slice, cycles, scale)),
+        Part::Two => Box::new(day17::images::<4>(&slice, cycles, scale)),
+    })
+}
+
+/// Returns the assembled image, for either part.
+fn sea(text: &str, scale: usize) -> Result<Frames, Error> {
+    let tiles = Tile::parse_all(text)?;
+    let image = day20::part2::picture(&tiles, scale)?;
+    Ok(Box::new(iter::once(image)))
+}
+
+/// Returns the floor on each day of the exhibit.
+fn floor(text: &str, scale: usize) -> Result<Frames, Error> {
+    let floor: day24::Floor = text.parse()?;
+    Ok(Box::new(floor.images(day24::DAYS, scale)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn same_size() {
+        let text = fs::read_to_string("tests/day24/sample1").unwrap();
+        let shown: Vec<_> = frames(24, Part::Two, &text, 1).unwrap().unwrap().collect();
+        assert_eq!(day24::DAYS + 1, shown.len());
+        let first = &shown[0];
+        let size = |image: &Image| (image.width(), image.height());
+        assert!(shown.iter().all(|image| size(image) == size(first)));
+        assert!(frames(8, Part::One, &text, 1).is_none());
+    }
+}
@@ -1,16 +1,41 @@
+pub mod day1;
+pub mod day10;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
 pub mod day7;
+pub mod day8;
+pub mod day9;
 
 pub mod day11;
 pub mod day12;
 pub mod day13;
 pub mod day14;
 pub mod day15;
+pub mod day16;
 pub mod day17;
 pub mod day18;
 pub mod day19;
 pub mod day20;
+pub mod day21;
 pub mod day22;
 pub mod day23;
 pub mod day24;
+pub mod day25;
 
-pub mod error;
+pub mod animations;
+pub mod checks;
+pub mod graphs;
+pub mod images;
+pub mod registry;
+pub mod vm;
+
+// The shared modules remain reachable through this crate, so each day can
+// keep using them as `crate::error`, `crate::parse`, and so on.
+pub use advent_core::{
+    animate, answers, automaton, bench, cycle, driver, error, graph, grid, image, parse, solution,
+    space, table,
+};
+pub use advent_core::{Error, Part, Puzzle, Solution};
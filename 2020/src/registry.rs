@@ -0,0 +1,66 @@
+//! Every puzzle solved in this crate, keyed by day.
+
+use crate::{
+    day1, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day2, day20, day21,
+    day22, day23, day24, day25, day3, day4, day5, day6, day7, day8, day9,
+};
+use advent_core::entry;
+
+pub use advent_core::registry::Entry;
+
+static ENTRIES: &[Entry] = &[
+    entry!(2020, 1, day1::Day1),
+    entry!(2020, 2, day2::Day2),
+    entry!(2020, 3, day3::Day3),
+    entry!(2020, 4, day4::Day4),
+    entry!(2020, 5, day5::Day5),
+    entry!(2020, 6, day6::Day6),
+    entry!(2020, 7, day7::Day7),
+    entry!(2020, 8, day8::Day8),
+    entry!(2020, 9, day9::Day9),
+    entry!(2020, 10, day10::Day10),
+    entry!(2020, 11, day11::Day11),
+    entry!(2020, 12, day12::Day12),
+    entry!(2020, 13, day13::Day13),
+    entry!(2020, 14, day14::Day14),
+    entry!(2020, 15, day15::Day15),
+    entry!(2020, 16, day16::Day16),
+    entry!(2020, 17, day17::Day17),
+    entry!(2020, 18, day18::Day18),
+    entry!(2020, 19, day19::Day19),
+    entry!(2020, 20, day20::Day20),
+    entry!(2020, 21, day21::Day21),
+    entry!(2020, 22, day22::Day22),
+    entry!(2020, 23, day23::Day23),
+    entry!(2020, 24, day24::Day24),
+    entry!(2020, 25, day25::Day25),
+];
+
+/// Returns all registered puzzles, ordered by day.
+pub fn entries() -> &'static [Entry] {
+    ENTRIES
+}
+
+/// Returns the puzzle registered for the specified year and day, if any.
+pub fn find(year: u16, day: u8) -> Option<&'static Entry> {
+    advent_core::registry::find(ENTRIES, year, day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sorted_and_unique() {
+        let keys: Vec<_> = entries().iter().map(|e| (e.year, e.day)).collect();
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn find_day7() {
+        let entry = find(2020, 7).unwrap();
+        assert!(entry.input_path().ends_with("tests/day7/input"));
+        assert!(entry.input_path().is_file());
+        assert!(find(2020, 26).is_none());
+    }
+}
@@ -0,0 +1,304 @@
+//! The handheld game console's virtual machine, from day 8.  A [`Machine`]
+//! runs a [`Program`] whose opcodes come from an [`OpcodeTable`]: day 8's
+//! `acc`, `jmp`, and `nop` as standard, plus any defined alongside them.  It
+//! can run a step at a time, run until a condition holds, stop at
+//! breakpoints, and give up after a limit on steps.  Every way a run can end,
+//! such as halting, looping, or jumping out of the program, is an
+//! [`Outcome`].
+
+mod opcodes;
+
+pub use opcodes::{Flow, Instruction, Opcode, OpcodeTable, Operation, Program};
+
+use std::collections::BTreeSet;
+
+/// The value of a register or an argument.
+pub type Word = i64;
+
+/// The number of registers.
+pub const REGISTERS: usize = 4;
+
+pub type Registers = [Word; REGISTERS];
+
+/// The accumulator: the one register day 8's opcodes use.
+pub const ACC: usize = 0;
+
+/// Why a machine stopped running.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    /// The program ended: the machine reached the end of the program, just
+    /// past its last instruction, or an operation halted it.
+    Halt,
+    /// The machine was about to run an instruction for the second time, so
+    /// that left alone, it would run forever.
+    Loop,
+    /// The current instruction would jump out of the program, to the
+    /// specified index.
+    OutOfBounds(Word),
+    /// The current instruction's opcode isn't in the machine's table.
+    UnknownOpcode(Opcode),
+    /// The current instruction has a breakpoint.
+    Breakpoint,
+    /// The condition given to [`Machine::run_until`] holds.
+    Condition,
+    /// The machine has run as many steps as its limit allows.
+    Limit,
+}
+
+/// A console running a program.
+#[derive(Clone, Debug)]
+pub struct Machine {
+    opcodes: OpcodeTable,
+    program: Program,
+    registers: Registers,
+    pc: usize, // program counter
+    halted: bool,
+    steps: u64,
+    visited: Vec<bool>, // by instruction index
+    breakpoints: BTreeSet<usize>,
+    limit: Option<u64>,
+}
+
+impl Machine {
+    /// Returns a machine ready to run a program of standard opcodes.
+    pub fn new(program: Program) -> Machine {
+        Machine::with_opcodes(OpcodeTable::standard(), program)
+    }
+
+    /// Returns a machine ready to run a program of opcodes from the
+    /// specified table.
+    pub fn with_opcodes(opcodes: OpcodeTable, program: Program) -> Machine {
+        let visited = vec![false; program.len()];
+        Machine {
+            opcodes,
+            program,
+            registers: Registers::default(),
+            pc: 0,
+            halted: false,
+            steps: 0,
+            visited,
+            breakpoints: BTreeSet::new(),
+            limit: None,
+        }
+    }
+
+    /// Returns the machine to where it started, with every register zero.
+    /// Breakpoints and the limit stay as they are.
+    pub fn reset(&mut self) {
+        self.registers = Registers::default();
+        self.pc = 0;
+        self.halted = false;
+        self.steps = 0;
+        self.visited.iter_mut().for_each(|visited| *visited = false);
+    }
+
+    pub fn program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    pub fn opcodes(&self) -> &OpcodeTable {
+        &self.opcodes
+    }
+
+    pub fn registers(&self) -> &Registers {
+        &self.registers
+    }
+
+    pub fn registers_mut(&mut self) -> &mut Registers {
+        &mut self.registers
+    }
+
+    /// Returns the value of the accumulator.
+    pub fn acc(&self) -> Word {
+        self.registers[ACC]
+    }
+
+    /// Returns the index of the instruction to run next.
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    /// Returns the number of instructions run since the machine started.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Returns whether the program has ended.
+    pub fn halted(&self) -> bool {
+        self.halted || self.pc == self.program.len()
+    }
+
+    /// Sets a breakpoint at the instruction with the specified index, and
+    /// returns whether there wasn't one already.
+    pub fn add_breakpoint(&mut self, pc: usize) -> bool {
+        self.breakpoints.insert(pc)
+    }
+
+    /// Clears the breakpoint at the instruction with the specified index,
+    /// and returns whether there was one.
+    pub fn remove_breakpoint(&mut self, pc: usize) -> bool {
+        self.breakpoints.remove(&pc)
+    }
+
+    /// Sets the most steps the machine may run in all, counting those it has
+    /// already, or None for no limit.
+    pub fn set_limit(&mut self, limit: Option<u64>) {
+        self.limit = limit;
+    }
+
+    /// Runs the current instruction.  Returns None if the machine can go on,
+    /// or the outcome that stops it: Halt if the program has ended, or
+    /// OutOfBounds if the instruction would jump out of the program or
+    /// UnknownOpcode if its opcode isn't in the table, in which cases the
+    /// machine is left as it was.  Breakpoints, loops, and
+    /// the limit are left to the caller.
+    pub fn step(&mut self) -> Option<Outcome> {
+        if self.halted() {
+            return Some(Outcome::Halt);
+        }
+        let instruction = self.program[self.pc];
+        let operation = match self.opcodes.operation(instruction.opcode) {
+            Some(operation) => operation,
+            None => return Some(Outcome::UnknownOpcode(instruction.opcode)),
+        };
+        let mut registers = self.registers;
+        let next = match operation(&mut registers, instruction.arg) {
+            Flow::Next => self.pc + 1,
+            Flow::Jump(offset) => {
+                let target = (self.pc as Word).saturating_add(offset);
+                if target < 0 || target > self.program.len() as Word {
+                    return Some(Outcome::OutOfBounds(target));
+                }
+                target as usize
+            }
+            Flow::Halt => {
+                self.halted = true;
+                self.pc
+            }
+        };
+        self.registers = registers;
+        self.visited[self.pc] = true;
+        self.pc = next;
+        self.steps += 1;
+        if self.halted() {
+            Some(Outcome::Halt)
+        } else {
+            None
+        }
+    }
+
+    /// Runs until the program ends, or the machine is stopped by a loop, a
+    /// jump out of the program, an unknown opcode, a breakpoint, or the
+    /// limit.  A breakpoint at
+    /// the current instruction doesn't stop it, so that running again goes
+    /// on past it.
+    pub fn run(&mut self) -> Outcome {
+        self.run_until(|_| false)
+    }
+
+    /// Runs as [`run`](Machine::run) does, but also stops before any
+    /// instruction, including the current one, at which the specified
+    /// condition holds.
+    pub fn run_until<F>(&mut self, mut condition: F) -> Outcome
+    where
+        F: FnMut(&Machine) -> bool,
+    {
+        let start = self.steps;
+        loop {
+            if self.halted() {
+                return Outcome::Halt;
+            } else if self.steps > start && self.breakpoints.contains(&self.pc) {
+                return Outcome::Breakpoint;
+            } else if condition(self) {
+                return Outcome::Condition;
+            } else if self.visited[self.pc] {
+                return Outcome::Loop;
+            } else if self.limit.is_some_and(|limit| self.steps >= limit) {
+                return Outcome::Limit;
+            }
+            if let Some(outcome) = self.step() {
+                return outcome;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    fn sample1() -> Machine {
+        let text = fs::read_to_string("tests/day8/sample1").unwrap();
+        Machine::new(OpcodeTable::standard().parse_program(&text).unwrap())
+    }
+
+    #[test]
+    fn run() {
+        let mut machine = sample1();
+        assert_eq!(Outcome::Loop, machine.run());
+        assert_eq!((5, 1, 7), (machine.acc(), machine.pc(), machine.steps()));
+        let mut program = machine.program().to_vec();
+        program[7].opcode = Opcode::NOP;
+        let mut machine = Machine::new(program);
+        assert_eq!(Outcome::Halt, machine.run());
+        assert_eq!((8, 9), (machine.acc(), machine.pc()));
+        assert_eq!(Some(Outcome::Halt), machine.step());
+    }
+
+    #[test]
+    fn step() {
+        let mut machine = sample1();
+        assert_eq!(None, machine.step());
+        assert_eq!(None, machine.step());
+        assert_eq!((1, 2), (machine.acc(), machine.pc()));
+        let mut machine = Machine::new(vec!["jmp -1".parse().unwrap()]);
+        assert_eq!(Some(Outcome::OutOfBounds(-1)), machine.step());
+        assert_eq!((0, 0), (machine.pc(), machine.steps()));
+        assert_eq!(Outcome::OutOfBounds(-1), machine.run());
+        let mut machine = Machine::new(vec![Instruction::new(Opcode::JMP, 2)]);
+        assert_eq!(Outcome::OutOfBounds(2), machine.run());
+        assert_eq!(Outcome::Halt, Machine::new(vec![]).run());
+        let program = vec![Instruction::new(Opcode::NOP, 0)];
+        let mut machine = Machine::with_opcodes(OpcodeTable::new(), program);
+        assert_eq!(Outcome::UnknownOpcode(Opcode::NOP), machine.run());
+        assert_eq!((0, 0), (machine.pc(), machine.steps()));
+    }
+
+    #[test]
+    fn breakpoints() {
+        let mut machine = sample1();
+        assert!(machine.add_breakpoint(4));
+        assert!(!machine.add_breakpoint(4));
+        assert_eq!(Outcome::Breakpoint, machine.run());
+        assert_eq!((4, 6), (machine.pc(), machine.steps()));
+        assert_eq!(Outcome::Loop, machine.run());
+        machine.reset();
+        assert_eq!(Outcome::Breakpoint, machine.run());
+        assert!(machine.remove_breakpoint(4));
+        assert_eq!(Outcome::Loop, machine.run());
+        machine.reset();
+        assert_eq!(Outcome::Condition, machine.run_until(|m| m.acc() > 2));
+        assert_eq!((5, 4), (machine.acc(), machine.pc()));
+    }
+
+    #[test]
+    fn limit() {
+        let mut table = OpcodeTable::standard();
+        let inc = table.define("inc", |registers, arg| {
+            registers[1] += arg;
+            Flow::Next
+        });
+        let hlt = table.define("hlt", |_, _| Flow::Halt);
+        let program = table.parse_program("inc +2\nhlt 0\nacc +1").unwrap();
+        assert_eq!(vec![inc, hlt], vec![program[0].opcode, program[1].opcode]);
+        let mut machine = Machine::with_opcodes(table, program);
+        machine.set_limit(Some(1));
+        assert_eq!(Outcome::Limit, machine.run());
+        assert_eq!([0, 2, 0, 0], *machine.registers());
+        machine.set_limit(None);
+        assert_eq!(Outcome::Halt, machine.run());
+        assert!(machine.halted());
+        assert_eq!((1, 2, 0), (machine.pc(), machine.steps(), machine.acc()));
+    }
+}
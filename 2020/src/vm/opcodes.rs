@@ -0,0 +1,214 @@
+use super::{Registers, Word, ACC};
+use crate::error::{ParseError, ParseErrorKind};
+use crate::parse;
+use std::str::FromStr;
+
+/// What the machine does once an operation is done.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Flow {
+    /// Go on to the next instruction.
+    Next,
+    /// Jump by the specified offset from the current instruction.
+    Jump(Word),
+    /// Stop, as though the program had ended.
+    Halt,
+}
+
+/// What an opcode does, given the registers and its argument.
+pub type Operation = fn(&mut Registers, Word) -> Flow;
+
+/// An opcode's number in the table that defines it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Opcode(usize);
+
+impl Opcode {
+    /// Adds the argument to the accumulator.
+    pub const ACC: Opcode = Opcode(0);
+    /// Jumps by the argument.
+    pub const JMP: Opcode = Opcode(1);
+    /// Does nothing.
+    pub const NOP: Opcode = Opcode(2);
+}
+
+/// An instruction: an opcode and its argument.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub arg: Word,
+}
+
+impl Instruction {
+    pub fn new(opcode: Opcode, arg: Word) -> Instruction {
+        Instruction { opcode, arg }
+    }
+}
+
+/// Parses an instruction with a standard opcode, such as `jmp -3`.
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        OpcodeTable::standard().parse_instruction(s)
+    }
+}
+
+pub type Program = Vec<Instruction>;
+
+/// The opcodes a machine understands, each with its mnemonic and operation,
+/// numbered in the order they were defined.
+#[derive(Clone, Debug, Default)]
+pub struct OpcodeTable {
+    entries: Vec<(&'static str, Operation)>,
+}
+
+impl OpcodeTable {
+    /// Returns a table with no opcodes at all.
+    pub fn new() -> OpcodeTable {
+        OpcodeTable::default()
+    }
+
+    /// Returns a table of day 8's opcodes, `acc`, `jmp`, and `nop`, numbered
+    /// as [`Opcode::ACC`], [`Opcode::JMP`], and [`Opcode::NOP`].  The
+    /// accumulator wraps around on overflow.
+    pub fn standard() -> OpcodeTable {
+        let mut table = OpcodeTable::new();
+        table.define("acc", |registers, arg| {
+            registers[ACC] = registers[ACC].wrapping_add(arg);
+            Flow::Next
+        });
+        table.define("jmp", |_, arg| Flow::Jump(arg));
+        table.define("nop", |_, _| Flow::Next);
+        table
+    }
+
+    /// Defines an opcode with the specified mnemonic, replacing the operation
+    /// of any opcode that already has it, and returns its number.
+    pub fn define(&mut self, mnemonic: &'static str, operation: Operation) -> Opcode {
+        match self.lookup(mnemonic) {
+            Some(opcode) => {
+                self.entries[opcode.0].1 = operation;
+                opcode
+            }
+            None => {
+                self.entries.push((mnemonic, operation));
+                Opcode(self.entries.len() - 1)
+            }
+        }
+    }
+
+    /// Returns the number of the opcode with the specified mnemonic.
+    pub fn lookup(&self, mnemonic: &str) -> Option<Opcode> {
+        let index = self.entries.iter().position(|&(m, _)| m == mnemonic)?;
+        Some(Opcode(index))
+    }
+
+    /// Returns the mnemonic of the specified opcode, or None if this table
+    /// doesn't define it.
+    pub fn mnemonic(&self, opcode: Opcode) -> Option<&'static str> {
+        self.entries.get(opcode.0).map(|&(mnemonic, _)| mnemonic)
+    }
+
+    /// Returns the operation of the specified opcode, or None if this table
+    /// doesn't define it.
+    pub fn operation(&self, opcode: Opcode) -> Option<Operation> {
+        self.entries.get(opcode.0).map(|&(_, operation)| operation)
+    }
+
+    /// Parses an instruction, such as `acc +7`, whose opcode is in this table.
+    pub fn parse_instruction(&self, s: &str) -> Result<Instruction, ParseError> {
+        let tokens: Vec<_> = s.split_whitespace().collect();
+        let (mnemonic, arg) = match tokens[..] {
+            [mnemonic, arg] => (mnemonic, arg),
+            _ => {
+                let what = "bad instruction: expected an opcode and an argument";
+                return Err(ParseError::with_kind(ParseErrorKind::Unexpected, what));
+            }
+        };
+        let opcode = self.lookup(mnemonic).ok_or_else(|| {
+            let what = format!(
+                "unknown opcode {:?}; expected {}",
+                mnemonic,
+                self.expected()
+            );
+            ParseError::with_kind(ParseErrorKind::Invalid, what).within(s, mnemonic)
+        })?;
+        let arg = parse::integer(arg).map_err(|err| err.within(s, arg))?;
+        Ok(Instruction::new(opcode, arg))
+    }
+
+    /// Parses a program, one instruction per line.
+    pub fn parse_program(&self, text: &str) -> Result<Program, ParseError> {
+        parse::lines(text, |line| self.parse_instruction(line))
+    }
+
+    /// Lists the mnemonics, as in "acc, jmp, or nop".
+    fn expected(&self) -> String {
+        let mnemonics: Vec<_> = self.entries.iter().map(|&(m, _)| m).collect();
+        match mnemonics.split_last() {
+            None => "no opcodes".to_owned(),
+            Some((last, [])) => last.to_string(),
+            Some((last, [first])) => format!("{} or {}", first, last),
+            Some((last, rest)) => format!("{}, or {}", rest.join(", "), last),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let program = OpcodeTable::standard()
+            .parse_program("nop +0\nacc -99\njmp +4")
+            .unwrap();
+        let expected = vec![
+            Instruction::new(Opcode::NOP, 0),
+            Instruction::new(Opcode::ACC, -99),
+            Instruction::new(Opcode::JMP, 4),
+        ];
+        assert_eq!(expected, program);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = "jmp".parse::<Instruction>().unwrap_err();
+        assert_eq!(ParseErrorKind::Unexpected, err.kind());
+        let err = "mul +2".parse::<Instruction>().unwrap_err();
+        assert_eq!(ParseErrorKind::Invalid, err.kind());
+        assert!(err.to_string().ends_with("expected acc, jmp, or nop"));
+        let err = OpcodeTable::standard()
+            .parse_program("acc +1\njmp x")
+            .unwrap_err();
+        assert_eq!(
+            (ParseErrorKind::Number, Some(2), Some(5)),
+            (err.kind(), err.line(), err.column())
+        );
+    }
+
+    #[test]
+    fn define() {
+        let mut table = OpcodeTable::standard();
+        let hlt = table.define("hlt", |_, _| Flow::Halt);
+        assert_eq!(Some(hlt), table.lookup("hlt"));
+        assert_eq!(Some("hlt"), table.mnemonic(hlt));
+        assert_eq!(Opcode::NOP, table.define("nop", |_, _| Flow::Halt));
+        let mut registers = Registers::default();
+        let nop = table.operation(Opcode::NOP).unwrap();
+        assert_eq!(Flow::Halt, nop(&mut registers, 0));
+        let empty = OpcodeTable::new();
+        assert_eq!(None, empty.lookup("acc"));
+        assert_eq!(None, empty.mnemonic(hlt));
+        assert!(empty.operation(hlt).is_none());
+        assert_eq!(None, OpcodeTable::default().lookup("acc"));
+    }
+
+    #[test]
+    fn overflow() {
+        let acc = OpcodeTable::standard().operation(Opcode::ACC).unwrap();
+        let mut registers = Registers::default();
+        registers[ACC] = Word::MAX;
+        assert_eq!(Flow::Next, acc(&mut registers, 1));
+        assert_eq!(Word::MIN, registers[ACC]);
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// A problem with the command line, as opposed to the puzzle input.
+#[derive(Debug)]
+pub struct UsageError(String);
+
+impl UsageError {
+    pub fn new<S: ToString>(what: S) -> UsageError {
+        UsageError(what.to_string())
+    }
+}
+
+impl Display for UsageError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Command-line flags of the form `--name value`, `--name=value`, or plain
+/// `--name` (a switch, if the next argument is another flag).
+pub struct Flags {
+    values: HashMap<String, Option<String>>,
+}
+
+impl Flags {
+    pub fn parse<I>(args: I) -> Result<Flags, UsageError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut values = HashMap::new();
+        let mut args = args.into_iter().peekable();
+        while let Some(arg) = args.next() {
+            let flag = arg
+                .strip_prefix("--")
+                .ok_or_else(|| UsageError::new(format!("{}: expected flag", arg)))?;
+            let (name, value) = match flag.find('=') {
+                Some(index) => (&flag[..index], Some(flag[(index + 1)..].to_owned())),
+                None => match args.peek() {
+                    Some(next) if !next.starts_with("--") => (flag, args.next()),
+                    _ => (flag, None),
+                },
+            };
+            if values.insert(name.to_owned(), value).is_some() {
+                return Err(UsageError::new(format!("--{}: repeated flag", name)));
+            }
+        }
+        Ok(Flags { values })
+    }
+
+    /// Removes and parses the value of the named flag, if it was specified.
+    pub fn take<T: FromStr>(&mut self, name: &str) -> Result<Option<T>, UsageError>
+    where
+        T::Err: Display,
+    {
+        match self.values.remove(name) {
+            Some(Some(value)) => value
+                .parse()
+                .map(Some)
+                .map_err(|err| UsageError::new(format!("--{}: {}", name, err))),
+            Some(None) => Err(UsageError::new(format!("--{}: expected value", name))),
+            None => Ok(None),
+        }
+    }
+
+    /// Like take, but fails if the named flag was not specified.
+    pub fn require<T: FromStr>(&mut self, name: &str) -> Result<T, UsageError>
+    where
+        T::Err: Display,
+    {
+        self.take(name)?
+            .ok_or_else(|| UsageError::new(format!("--{}: required flag", name)))
+    }
+
+    /// Removes the named switch, returning whether it was specified.
+    pub fn switch(&mut self, name: &str) -> Result<bool, UsageError> {
+        match self.values.remove(name) {
+            Some(Some(value)) => {
+                let what = format!("--{}: unexpected value: {}", name, value);
+                Err(UsageError::new(what))
+            }
+            Some(None) => Ok(true),
+            None => Ok(false),
+        }
+    }
+
+    /// Checks that every flag has been taken; i.e., that there were no
+    /// unrecognized flags.
+    pub fn finish(self) -> Result<(), UsageError> {
+        let mut names: Vec<_> = self.values.keys().collect();
+        names.sort();
+        match names.first() {
+            Some(name) => Err(UsageError::new(format!("--{}: unknown flag", name))),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn flags(args: &[&str]) -> Result<Flags, UsageError> {
+        Flags::parse(args.iter().map(|&arg| arg.to_owned()))
+    }
+
+    #[test]
+    fn values() {
+        let mut flags = flags(&["--day", "20", "--part=2"]).unwrap();
+        assert_eq!(20, flags.require::<u8>("day").unwrap());
+        assert_eq!(Some(2), flags.take::<u8>("part").unwrap());
+        assert_eq!(None, flags.take::<u8>("year").unwrap());
+        assert!(flags.finish().is_ok());
+    }
+
+    #[test]
+    fn switches() {
+        let mut flags = flags(&["--fast", "--day", "7"]).unwrap();
+        assert!(flags.switch("fast").unwrap());
+        assert!(!flags.switch("slow").unwrap());
+        assert!(flags.switch("day").is_err());
+    }
+
+    #[test]
+    fn errors() {
+        assert!(flags(&["day"]).is_err());
+        assert!(flags(&["--day", "1", "--day", "2"]).is_err());
+        assert!(flags(&["--day"]).unwrap().take::<u8>("day").is_err());
+        assert!(flags(&["--day", "x"]).unwrap().take::<u8>("day").is_err());
+        assert!(flags(&["--dya", "1"]).unwrap().finish().is_err());
+    }
+}
@@ -0,0 +1,598 @@
+//! Command-line driver for every registered puzzle.
+
+mod args;
+
+use advent_core::animate::Animation;
+use advent_core::driver::{self, Source};
+use advent_core::image::{Format, GifWriter, Image};
+use advent_core::registry::{self, Entry};
+use advent_core::{answers, bench, table, Part};
+use args::{Flags, UsageError};
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::iter;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Instant;
+
+const EXIT_USAGE: i32 = 64;
+
+const USAGE: &str = "\
+usage: aoc <command> [options]
+
+commands:
+    list            list available puzzles
+    run             solve a puzzle
+    test            check answers recorded for each puzzle's input files
+    bench           time each step of each puzzle on its full input
+    cubes           run 2020 day 17's Conway Cubes in any number of dimensions
+    seating         run 2020 day 11's seating with rules of your choice
+    check           report every problem with a puzzle's input (2020 day 7)
+    graph           print a puzzle's graph (2020 days 7, 10) in Graphviz's DOT
+                    language
+    render          save pictures of a puzzle's states (2020 days 11, 17, 20,
+                    and 24) as PPM, PNG, or an animated GIF
+    help            print this message
+
+run options:
+    --year YEAR     puzzle year (default: 2020)
+    --day DAY       puzzle day (required)
+    --part PART     1 or 2 (default: both)
+    --input PATH    puzzle input file (default: stdin)
+    --animate       show each generation in the terminal (2020 days 11, 17,
+                    and 24), instead of the answer
+    --fps N         frames per second to animate (default: 10)
+    --no-color      animate without colors
+
+test options:
+    --year YEAR     check only puzzles from this year
+    --day DAY       check only puzzles from this day
+    --fast          skip answers marked slow
+
+bench options:
+    --year YEAR     time only puzzles from this year
+    --day DAY       time only puzzles from this day
+    --runs N        number of times to run each step (default: 5)
+    --baseline PATH compare medians against a saved baseline
+    --threshold PCT percent slowdown counted as a regression (default: 10)
+    --save PATH     save timings as a new baseline
+
+cubes options:
+    --dimensions N  number of dimensions, from 2 to 6 (required)
+    --cycles N      number of cycles to simulate (default: 6)
+    --input PATH    initial slice (default: stdin)
+
+seating options:
+    --part PART     start from the rules of part 1 or 2 (default: 1)
+    --rules PATH    change the rules by settings in a file, one KEY = VALUE
+                    per line, for any of the options below
+    --neighborhood KIND
+                    seats people look at: adjacent, or sight for the first
+                    seat in each direction
+    --edges EDGES   what lies past the edges of the grid:
+                      open     nothing, as in the puzzle
+                      live     a border of seats that are always occupied
+                      wrap     the opposite edge, as on a torus
+                      reflect  the grid's mirror image
+    --crowd N       occupied neighbors that make people leave their seats
+    --tolerance N   most occupied neighbors with which people take a seat
+    --threads N     threads to compute each generation on, each taking a band
+                    of rows (default: 1)
+    --runs N        instead of the answer, print how long settling takes on
+                    1, 2, 4, and so on up to --threads threads, at best, at
+                    the median, and at worst of N runs
+    --input PATH    seat layout (default: stdin)
+
+check options:
+    --day DAY       puzzle day (required)
+    --input PATH    puzzle input file (default: stdin)
+
+graph options:
+    --day DAY       puzzle day (required)
+    --input PATH    puzzle input file (default: stdin)
+    --output PATH   file to write (default: stdout)
+
+render options:
+    --day DAY       puzzle day (required)
+    --part PART     1 or 2 (default: 1)
+    --input PATH    puzzle input file (default: stdin)
+    --output PATH   file to write, ending in .ppm, .png, or .gif (required)
+    --scale N       pixels per cell (default: 4)
+    --generation N  generation to save as PPM or PNG (default: the last)
+    --fps N         frames per second of a GIF (default: 10)
+";
+
+const DEFAULT_YEAR: u16 = 2020;
+
+/// Returns every registered puzzle, ordered by year and day.
+fn entries() -> impl Iterator<Item = &'static Entry> {
+    advent2018::registry::entries()
+        .iter()
+        .chain(advent2020::registry::entries())
+}
+
+/// Takes the `--input` flag: the file to read the puzzle input from, or
+/// stdin if it's left out.
+fn input(flags: &mut Flags) -> Result<Source, UsageError> {
+    Ok(match flags.take::<PathBuf>("input")? {
+        Some(path) => Source::File(path),
+        None => Source::Stdin,
+    })
+}
+
+/// Reads the puzzle input, or reports why it can't, and returns the exit
+/// status to fail with.
+fn read_input(source: &Source) -> Result<String, i32> {
+    source.read().map_err(|err| {
+        eprintln!("error: {}: {}", source.name(), err);
+        driver::EXIT_INPUT
+    })
+}
+
+fn list(mut flags: Flags) -> Result<i32, UsageError> {
+    let year: Option<u16> = flags.take("year")?;
+    flags.finish()?;
+    for entry in entries() {
+        if year.is_none_or(|year| year == entry.year) {
+            println!("{} {}", entry.year, entry.day);
+        }
+    }
+    Ok(0)
+}
+
+fn run(mut flags: Flags) -> Result<i32, UsageError> {
+    let year = flags.take("year")?.unwrap_or(DEFAULT_YEAR);
+    let day = flags.require("day")?;
+    let part: Option<Part> = flags.take("part")?;
+    let source = input(&mut flags)?;
+    let animate = flags.switch("animate")?;
+    let fps: Option<f64> = flags.take("fps")?;
+    let no_color = flags.switch("no-color")?;
+    flags.finish()?;
+    if animate {
+        let animation = Animation {
+            fps: fps.unwrap_or(Animation::default().fps),
+            color: !no_color,
+        };
+        return animate_run(year, day, part.unwrap_or(Part::One), &source, &animation);
+    } else if fps.is_some() || no_color {
+        return Err(UsageError::new("--fps and --no-color require --animate"));
+    }
+    let entry = registry::find(entries(), year, day)
+        .ok_or_else(|| UsageError::new(format!("{} day {}: no such puzzle", year, day)))?;
+    let parts = match part {
+        Some(part) if entry.puzzle.parts().contains(&part) => vec![part],
+        Some(part) => {
+            let what = format!("{} day {} has no part {}", year, day, part);
+            return Err(UsageError::new(what));
+        }
+        None => entry.puzzle.parts().to_vec(),
+    };
+    Ok(driver::run(entry.puzzle, &source, &parts))
+}
+
+/// Plays the generations of an animated puzzle in the terminal.
+fn animate_run(
+    year: u16,
+    day: u8,
+    part: Part,
+    source: &Source,
+    animation: &Animation,
+) -> Result<i32, UsageError> {
+    if animation.fps.is_nan() || animation.fps <= 0.0 {
+        return Err(UsageError::new("--fps must be positive"));
+    }
+    if !(year == 2020 && advent2020::animations::DAYS.contains(&day)) {
+        let what = format!("{} day {} has no animation", year, day);
+        return Err(UsageError::new(what));
+    }
+    let text = match read_input(source) {
+        Ok(text) => text,
+        Err(status) => return Ok(status),
+    };
+    let frames = match advent2020::animations::frames(day, part, &text) {
+        Some(Ok(frames)) => frames,
+        Some(Err(err)) => {
+            eprintln!("error: {}: {:#}", source.name(), err);
+            return Ok(driver::EXIT_INPUT);
+        }
+        None => return Err(UsageError::new("no animation")),
+    };
+    match animation.play(frames) {
+        Ok(()) => Ok(0),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            Ok(1)
+        }
+    }
+}
+
+fn test(mut flags: Flags) -> Result<i32, UsageError> {
+    let year: Option<u16> = flags.take("year")?;
+    let day: Option<u8> = flags.take("day")?;
+    let fast = flags.switch("fast")?;
+    flags.finish()?;
+    let mut checks = Vec::new();
+    for entry in entries() {
+        if year.is_none_or(|year| year == entry.year) && day.is_none_or(|day| day == entry.day) {
+            match answers::check(entry, |answer| !(fast && answer.slow)) {
+                Ok(more) => checks.extend(more),
+                Err(err) => {
+                    eprintln!("error: {}", err);
+                    return Ok(driver::EXIT_INPUT);
+                }
+            }
+        }
+    }
+    print!("{}", answers::table(&checks));
+    let failures = checks.iter().filter(|check| !check.passed()).count();
+    println!("\n{} checked, {} failed", checks.len(), failures);
+    Ok(if failures == 0 { 0 } else { 1 })
+}
+
+fn bench(mut flags: Flags) -> Result<i32, UsageError> {
+    let year: Option<u16> = flags.take("year")?;
+    let day: Option<u8> = flags.take("day")?;
+    let runs: usize = flags.take("runs")?.unwrap_or(5);
+    let baseline_path: Option<PathBuf> = flags.take("baseline")?;
+    let threshold: f64 = flags.take("threshold")?.unwrap_or(10.0);
+    let save_path: Option<PathBuf> = flags.take("save")?;
+    flags.finish()?;
+    if runs == 0 {
+        return Err(UsageError::new("--runs must be positive"));
+    }
+    let fail = |err: Box<dyn std::error::Error>| {
+        eprintln!("error: {}", err);
+        Ok(driver::EXIT_INPUT)
+    };
+    let baseline = match baseline_path.map(bench::load_baseline).transpose() {
+        Ok(baseline) => baseline.unwrap_or_default(),
+        Err(err) => return fail(err),
+    };
+    let mut measurements = Vec::new();
+    for entry in entries() {
+        if year.is_none_or(|year| year == entry.year) && day.is_none_or(|day| day == entry.day) {
+            let path = entry.input_path();
+            let result = Source::File(path.clone())
+                .read()
+                .map_err(|err| err.into())
+                .and_then(|text| bench::measure(entry, &text, runs));
+            match result {
+                Ok(more) => measurements.extend(more),
+                Err(err) => return fail(format!("{}: {}", path.display(), err).into()),
+            }
+        }
+    }
+    print!(
+        "{}",
+        bench::table(&measurements, &baseline, threshold / 100.0)
+    );
+    if let Some(path) = save_path {
+        if let Err(err) = bench::save_baseline(path, &measurements) {
+            return fail(err);
+        }
+    }
+    let regressions = measurements
+        .iter()
+        .filter_map(|m| bench::compare(m, &baseline, threshold / 100.0))
+        .filter(|change| change.regressed)
+        .count();
+    if regressions == 0 {
+        Ok(0)
+    } else {
+        println!(
+            "\n{} of {} steps slower than baseline",
+            regressions,
+            measurements.len()
+        );
+        Ok(1)
+    }
+}
+
+fn cubes(mut flags: Flags) -> Result<i32, UsageError> {
+    use advent2020::day17::{self, Slice};
+    let dimensions: usize = flags.require("dimensions")?;
+    let cycles = flags.take("cycles")?.unwrap_or(day17::CYCLES);
+    let source = input(&mut flags)?;
+    flags.finish()?;
+    if !day17::DIMENSIONS.contains(&dimensions) {
+        let (min, max) = day17::DIMENSIONS.into_inner();
+        let what = format!("--dimensions must be from {} to {}", min, max);
+        return Err(UsageError::new(what));
+    }
+    let fail = |err: Box<dyn std::error::Error>| {
+        eprintln!("error: {}", err);
+        Ok(driver::EXIT_INPUT)
+    };
+    let text = match read_input(&source) {
+        Ok(text) => text,
+        Err(status) => return Ok(status),
+    };
+    let slice: Slice = match text.parse() {
+        Ok(slice) => slice,
+        Err(err) => return fail(Box::new(err)),
+    };
+    let count = day17::simulate_in(dimensions, &slice, cycles)
+        .expect("dimensions checked against day17::DIMENSIONS");
+    println!("{}", count);
+    Ok(0)
+}
+
+fn seating(mut flags: Flags) -> Result<i32, UsageError> {
+    use advent2020::day11::{Grid, SeatingRules};
+    let part = flags.take("part")?.unwrap_or(Part::One);
+    let config = flags.take::<PathBuf>("rules")?;
+    let neighborhood = flags.take("neighborhood")?;
+    let edges = flags.take("edges")?;
+    let crowd = flags.take("crowd")?;
+    let tolerance = flags.take("tolerance")?;
+    let threads: usize = flags.take("threads")?.unwrap_or(1);
+    let runs: Option<usize> = flags.take("runs")?;
+    let source = input(&mut flags)?;
+    flags.finish()?;
+    if threads == 0 {
+        return Err(UsageError::new("--threads must be positive"));
+    }
+    if runs == Some(0) {
+        return Err(UsageError::new("--runs must be positive"));
+    }
+    let fail = |err: Box<dyn std::error::Error>| {
+        eprintln!("error: {}", err);
+        Ok(driver::EXIT_INPUT)
+    };
+    let mut rules = SeatingRules::preset(part);
+    if let Some(path) = config {
+        let settings = match read_input(&Source::File(path.clone())) {
+            Ok(settings) => settings,
+            Err(status) => return Ok(status),
+        };
+        rules = match rules.configure(&settings) {
+            Ok(rules) => rules,
+            Err(err) => return fail(Box::new(err.with_path(path))),
+        };
+    }
+    rules.neighborhood = neighborhood.unwrap_or(rules.neighborhood);
+    rules.edges = edges.unwrap_or(rules.edges);
+    rules.crowd = crowd.unwrap_or(rules.crowd);
+    rules.tolerance = tolerance.unwrap_or(rules.tolerance);
+    let text = match read_input(&source) {
+        Ok(text) => text,
+        Err(status) => return Ok(status),
+    };
+    let grid: Grid = match text.parse() {
+        Ok(grid) => grid,
+        Err(err) => return fail(Box::new(err)),
+    };
+    if let Some(runs) = runs {
+        print!("{}", seating_bench(&grid, &rules, threads, runs));
+        return Ok(0);
+    }
+    match grid.settle_parallel(&rules, threads) {
+        Some(grid) => {
+            println!("{}", grid.pop_count());
+            Ok(0)
+        }
+        None => {
+            eprintln!("{}: seats never settle", source.name());
+            Ok(1)
+        }
+    }
+}
+
+/// Times settling the specified grid on 1, 2, 4, and so on up to the
+/// specified number of threads, and returns a table of the timings and of
+/// each one's speedup over a single thread.
+fn seating_bench(
+    grid: &advent2020::day11::Grid,
+    rules: &advent2020::day11::SeatingRules,
+    threads: usize,
+    runs: usize,
+) -> String {
+    let counts = iter::successors(Some(1), |&n| Some(n * 2))
+        .take_while(|&n| n < threads)
+        .chain(iter::once(threads));
+    let mut rows = vec![[
+        "threads".to_owned(),
+        "min".to_owned(),
+        "median".to_owned(),
+        "max".to_owned(),
+        "speedup".to_owned(),
+    ]];
+    let mut single = None;
+    for threads in counts {
+        let samples = (0..runs)
+            .map(|_| {
+                let start = Instant::now();
+                grid.settle_parallel(rules, threads);
+                start.elapsed()
+            })
+            .collect();
+        let timing = bench::Timing::from_samples(samples).expect("runs checked positive");
+        let single = *single.get_or_insert(timing.median);
+        let speedup = single.as_secs_f64() / timing.median.as_secs_f64().max(f64::MIN_POSITIVE);
+        rows.push([
+            threads.to_string(),
+            bench::format_duration(timing.min),
+            bench::format_duration(timing.median),
+            bench::format_duration(timing.max),
+            format!("{:.2}x", speedup),
+        ]);
+    }
+    table::render(&rows)
+}
+
+fn check(mut flags: Flags) -> Result<i32, UsageError> {
+    let day = flags.require("day")?;
+    let source = input(&mut flags)?;
+    flags.finish()?;
+    if !advent2020::checks::DAYS.contains(&day) {
+        return Err(UsageError::new(format!("2020 day {} has no check", day)));
+    }
+    let text = match read_input(&source) {
+        Ok(text) => text,
+        Err(status) => return Ok(status),
+    };
+    let problems = match advent2020::checks::check(day, &text) {
+        Some(problems) => problems,
+        None => return Err(UsageError::new("no check")),
+    };
+    if problems.is_empty() {
+        println!("{}: ok", source.name());
+        return Ok(0);
+    }
+    for err in &problems {
+        eprintln!("error: {:#}\n", err.clone().with_path(source.name()));
+    }
+    let plural = if problems.len() == 1 { "" } else { "s" };
+    eprintln!("{}: {} problem{}", source.name(), problems.len(), plural);
+    Ok(driver::EXIT_INPUT)
+}
+
+fn graph(mut flags: Flags) -> Result<i32, UsageError> {
+    let day = flags.require("day")?;
+    let source = input(&mut flags)?;
+    let output: Option<PathBuf> = flags.take("output")?;
+    flags.finish()?;
+    if !advent2020::graphs::DAYS.contains(&day) {
+        return Err(UsageError::new(format!("2020 day {} has no graph", day)));
+    }
+    let text = match read_input(&source) {
+        Ok(text) => text,
+        Err(status) => return Ok(status),
+    };
+    let dot = match advent2020::graphs::dot(day, &text) {
+        Some(Ok(dot)) => dot,
+        Some(Err(err)) => {
+            eprintln!("error: {}: {:#}", source.name(), err);
+            return Ok(driver::EXIT_INPUT);
+        }
+        None => return Err(UsageError::new("no graph")),
+    };
+    match output {
+        Some(path) => {
+            if let Err(err) = fs::write(&path, dot) {
+                eprintln!("error: {}: {}", path.display(), err);
+                return Ok(1);
+            }
+        }
+        None => print!("{}", dot),
+    }
+    Ok(0)
+}
+
+fn render(mut flags: Flags) -> Result<i32, UsageError> {
+    let day = flags.require("day")?;
+    let part = flags.take("part")?.unwrap_or(Part::One);
+    let source = input(&mut flags)?;
+    let output: PathBuf = flags.require("output")?;
+    let scale: usize = flags.take("scale")?.unwrap_or(4);
+    let generation: Option<usize> = flags.take("generation")?;
+    let fps: Option<f64> = flags.take("fps")?;
+    flags.finish()?;
+    let format = Format::from_path(&output)
+        .ok_or_else(|| UsageError::new("--output must end in .ppm, .png, or .gif"))?;
+    if scale == 0 {
+        return Err(UsageError::new("--scale must be positive"));
+    } else if fps.is_some_and(|fps| fps.is_nan() || fps <= 0.0) {
+        return Err(UsageError::new("--fps must be positive"));
+    } else if format.is_animated() && generation.is_some() {
+        return Err(UsageError::new("--generation is for still images"));
+    } else if !format.is_animated() && fps.is_some() {
+        return Err(UsageError::new("--fps is for GIF animations"));
+    } else if !advent2020::images::DAYS.contains(&day) {
+        return Err(UsageError::new(format!("2020 day {} has no pictures", day)));
+    }
+    let text = match read_input(&source) {
+        Ok(text) => text,
+        Err(status) => return Ok(status),
+    };
+    let mut frames = match advent2020::images::frames(day, part, &text, scale) {
+        Some(Ok(frames)) => frames,
+        Some(Err(err)) => {
+            eprintln!("error: {}: {:#}", source.name(), err);
+            return Ok(driver::EXIT_INPUT);
+        }
+        None => return Err(UsageError::new("no pictures")),
+    };
+    let result = match format {
+        Format::Gif => {
+            let delay = (100.0 / fps.unwrap_or(10.0)).round().clamp(1.0, 65535.0);
+            write_gif(&output, frames, delay as u16)
+        }
+        still => {
+            let image = match generation {
+                Some(n) => frames
+                    .nth(n)
+                    .ok_or_else(|| UsageError::new(format!("--generation: no generation {}", n))),
+                None => frames
+                    .last()
+                    .ok_or_else(|| UsageError::new("nothing to render")),
+            };
+            write_image(&output, &image?, still)
+        }
+    };
+    match result {
+        Ok(()) => Ok(0),
+        Err(err) => {
+            eprintln!("error: {}: {}", output.display(), err);
+            Ok(1)
+        }
+    }
+}
+
+/// Saves a still image as PPM, or else as PNG.
+fn write_image(path: &Path, image: &Image, format: Format) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    if format == Format::Ppm {
+        image.write_ppm(&mut out)?;
+    } else {
+        image.write_png(&mut out)?;
+    }
+    out.flush()
+}
+
+/// Saves frames, which must all be the same size, as a GIF animation.
+fn write_gif<I>(path: &Path, frames: I, delay: u16) -> io::Result<()>
+where
+    I: IntoIterator<Item = Image>,
+{
+    let mut frames = frames.into_iter().peekable();
+    let (width, height) = match frames.peek() {
+        Some(first) => (first.width(), first.height()),
+        None => (0, 0),
+    };
+    let out = BufWriter::new(File::create(path)?);
+    let mut gif = GifWriter::new(out, width, height, delay)?;
+    for frame in frames {
+        gif.frame(&frame)?;
+    }
+    gif.finish()?.flush()
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let command = args.next();
+    let result = Flags::parse(args).and_then(|flags| match command.as_deref() {
+        Some("list") => list(flags),
+        Some("run") => run(flags),
+        Some("test") => test(flags),
+        Some("bench") => bench(flags),
+        Some("cubes") => cubes(flags),
+        Some("seating") => seating(flags),
+        Some("check") => check(flags),
+        Some("graph") => graph(flags),
+        Some("render") => render(flags),
+        Some("help") | Some("--help") => {
+            print!("{}", USAGE);
+            Ok(0)
+        }
+        Some(command) => Err(UsageError::new(format!("{}: unknown command", command))),
+        None => Err(UsageError::new("expected command")),
+    });
+    let status = result.unwrap_or_else(|err| {
+        eprintln!("error: {}\nrun 'aoc help' for usage", err);
+        EXIT_USAGE
+    });
+    process::exit(status);
+}
@@ -0,0 +1,265 @@
+//! Plays a sequence of text frames in the terminal, such as the generations
+//! of a cellular automaton rendered by their `Display` impls.  Frames are
+//! redrawn in place, with cells colored by character, and keys pause,
+//! step, speed up, slow down, or stop the animation:
+//!
+//! - space: pause or resume
+//! - n: show the next frame while paused
+//! - + and -: double or halve the frame rate
+//! - q: quit
+//!
+//! Keys are read from the controlling terminal, which is switched out of
+//! line-buffered mode for the duration by the `stty` command.  Without a
+//! terminal, frames simply play at the chosen rate.
+
+use std::fs::File;
+use std::io::{self, IsTerminal, Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const CLEAR: &str = "\x1b[H\x1b[2J";
+const RESET: &str = "\x1b[0m";
+
+/// Commands the user can give while an animation plays.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Key {
+    Pause,
+    Step,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Key {
+    pub fn from_byte(byte: u8) -> Option<Key> {
+        match byte {
+            b' ' | b'p' => Some(Key::Pause),
+            b'n' | b'.' => Some(Key::Step),
+            b'+' | b'=' => Some(Key::Faster),
+            b'-' | b'_' => Some(Key::Slower),
+            b'q' | b'\x03' => Some(Key::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the ANSI color for the specified character: bright yellow for
+/// live cells ('#'), green for empty seats ('L'), dark gray for floor and
+/// dead cells ('.'), and none for anything else.
+pub fn color(c: char) -> Option<&'static str> {
+    match c {
+        '#' => Some("\x1b[1;33m"),
+        'L' => Some("\x1b[32m"),
+        '.' => Some("\x1b[90m"),
+        _ => None,
+    }
+}
+
+/// Returns the specified frame with ANSI color codes around each run of
+/// characters of the same color.  Whitespace doesn't interrupt a run.
+pub fn colorize(frame: &str) -> String {
+    let mut out = String::with_capacity(frame.len() * 2);
+    let mut current = None;
+    for c in frame.chars() {
+        if c.is_whitespace() {
+            out.push(c);
+            continue;
+        }
+        let next = color(c);
+        if next != current {
+            out.push_str(next.unwrap_or(RESET));
+            current = next;
+        }
+        out.push(c);
+    }
+    if current.is_some() {
+        out.push_str(RESET);
+    }
+    out
+}
+
+/// How to play an animation.
+#[derive(Clone, Debug)]
+pub struct Animation {
+    /// Frames per second.
+    pub fps: f64,
+    /// Whether to color cells.
+    pub color: bool,
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Animation {
+            fps: 10.0,
+            color: true,
+        }
+    }
+}
+
+impl Animation {
+    /// Returns the text drawn for the specified frame, including a status
+    /// line.
+    pub fn render(&self, frame: &str, number: usize, paused: bool) -> String {
+        let frame = frame.trim_end_matches('\n');
+        let body = if self.color {
+            colorize(frame)
+        } else {
+            frame.to_owned()
+        };
+        let state = if paused { "paused" } else { "playing" };
+        format!(
+            "{}{}\n\nframe {}  {} at {} fps  (space: pause, n: step, +/-: speed, q: quit)\n",
+            CLEAR, body, number, state, self.fps
+        )
+    }
+
+    /// Shows each of the specified frames on stdout in turn, until they run
+    /// out or the user quits.
+    pub fn play<I>(&self, frames: I) -> io::Result<()>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let terminal = Terminal::open();
+        let keys = terminal.as_ref().map(Terminal::keys);
+        let mut settings = self.clone();
+        let mut paused = false;
+        let mut stdout = io::stdout();
+        for (number, frame) in frames.into_iter().enumerate() {
+            stdout.write_all(settings.render(&frame, number, paused).as_bytes())?;
+            stdout.flush()?;
+            let keys = match &keys {
+                Some(keys) => keys,
+                None => {
+                    thread::sleep(settings.frame_time());
+                    continue;
+                }
+            };
+            let deadline = Instant::now() + settings.frame_time();
+            loop {
+                let key = if paused {
+                    keys.recv().map_err(|_| RecvTimeoutError::Disconnected)
+                } else {
+                    let timeout = deadline.saturating_duration_since(Instant::now());
+                    keys.recv_timeout(timeout)
+                };
+                match key {
+                    Ok(Key::Pause) => paused = !paused,
+                    Ok(Key::Step) if paused => break,
+                    Ok(Key::Step) => {}
+                    Ok(Key::Faster) => settings.fps *= 2.0,
+                    Ok(Key::Slower) => settings.fps /= 2.0,
+                    Ok(Key::Quit) => return Ok(()),
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) if paused => return Ok(()),
+                    Err(RecvTimeoutError::Disconnected) => {
+                        thread::sleep(deadline.saturating_duration_since(Instant::now()));
+                        break;
+                    }
+                }
+                let status = settings.render(&frame, number, paused);
+                stdout.write_all(status.as_bytes())?;
+                stdout.flush()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn frame_time(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.fps.max(0.01))
+    }
+}
+
+/// The controlling terminal, switched to unbuffered input without echo or
+/// signals until dropped.
+struct Terminal {
+    saved: String, // settings to restore, as reported by `stty -g`
+}
+
+fn stty(args: &[&str]) -> Option<String> {
+    let tty = File::open("/dev/tty").ok()?;
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(tty)
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    if output.status.success() {
+        String::from_utf8(output.stdout).ok()
+    } else {
+        None
+    }
+}
+
+impl Terminal {
+    /// Returns the controlling terminal, or None if there is none or it
+    /// can't be switched to unbuffered input.
+    fn open() -> Option<Terminal> {
+        if !io::stdout().is_terminal() {
+            return None;
+        }
+        let saved = stty(&["-g"])?.trim().to_owned();
+        // Without isig, Ctrl-C arrives as a key, so that quitting goes
+        // through Drop and puts the terminal back as it was.
+        stty(&["-icanon", "-echo", "-isig", "min", "1"])?;
+        Some(Terminal { saved })
+    }
+
+    /// Returns the keys pressed from now on, read on a background thread.
+    fn keys(&self) -> Receiver<Key> {
+        let (sender, receiver) = mpsc::channel();
+        if let Ok(mut tty) = File::open("/dev/tty") {
+            thread::spawn(move || {
+                let mut buffer = [0; 16];
+                while let Ok(len @ 1..) = tty.read(&mut buffer) {
+                    for key in buffer[..len].iter().filter_map(|&b| Key::from_byte(b)) {
+                        if sender.send(key).is_err() {
+                            return;
+                        }
+                    }
+                }
+            });
+        }
+        receiver
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        stty(&[&self.saved]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn colorize_runs() {
+        let want = "\x1b[1;33m##\x1b[90m. \x1b[32mL\x1b[0m";
+        assert_eq!(want, colorize("##. L"));
+        assert_eq!("\x1b[32mL\x1b[0m?", colorize("L?"));
+        assert_eq!("abc", colorize("abc"));
+    }
+
+    #[test]
+    fn render() {
+        let animation = Animation {
+            fps: 4.0,
+            color: false,
+        };
+        let text = animation.render("#.\n.#\n", 3, true);
+        assert!(text.starts_with(CLEAR));
+        assert!(text.contains("#.\n.#\n\nframe 3  paused at 4 fps"));
+    }
+
+    #[test]
+    fn keys() {
+        assert_eq!(Some(Key::Pause), Key::from_byte(b' '));
+        assert_eq!(Some(Key::Step), Key::from_byte(b'n'));
+        assert_eq!(Some(Key::Quit), Key::from_byte(b'q'));
+        assert_eq!(Some(Key::Quit), Key::from_byte(b'\x03')); // Ctrl-C
+        assert_eq!(None, Key::from_byte(b'x'));
+    }
+}
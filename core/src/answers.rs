@@ -0,0 +1,231 @@
+//! Recorded answers for puzzle input files.  Each puzzle's test directory
+//! holds an `answers` manifest listing the expected answer for each input
+//! file and part, one per line:
+//!
+//! ```text
+//! # file   part  answer
+//! input    1     289
+//! sample1  2     32      slow
+//! ```
+//!
+//! Blank lines and lines beginning with '#' are ignored.  A trailing `slow`
+//! marks answers that take too long to check in unoptimized builds.
+
+use crate::error::ParseError;
+use crate::registry::Entry;
+use crate::solution::{Parsed, Part};
+use crate::table;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+
+const MANIFEST: &str = "answers";
+
+/// An expected answer from a manifest.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Answer {
+    pub file: String,
+    pub part: Part,
+    pub answer: String,
+    pub slow: bool,
+}
+
+pub fn parse_manifest(text: &str) -> Result<Vec<Answer>, ParseError> {
+    let mut answers = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let bad_line = |what: &str| ParseError::new(what).on_line(index + 1, line);
+        let (file, part, answer, slow) = match line.split_whitespace().collect::<Vec<_>>()[..] {
+            [file, part, answer] => (file, part, answer, false),
+            [file, part, answer, "slow"] => (file, part, answer, true),
+            _ => return Err(bad_line("expected: FILE PART ANSWER [slow]")),
+        };
+        answers.push(Answer {
+            file: file.to_owned(),
+            part: part.parse().map_err(|_| bad_line("expected part 1 or 2"))?,
+            answer: answer.to_owned(),
+            slow,
+        });
+    }
+    Ok(answers)
+}
+
+/// Loads the manifest from the specified directory.  Returns an empty list
+/// if the directory has no manifest.
+pub fn load_manifest<P: AsRef<Path>>(dir: P) -> Result<Vec<Answer>, Box<dyn Error>> {
+    let path = dir.as_ref().join(MANIFEST);
+    match fs::read_to_string(&path) {
+        Ok(text) => parse_manifest(&text).map_err(|err| err.with_path(&path).into()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(format!("{}: {}", path.display(), err).into()),
+    }
+}
+
+/// The result of checking one recorded answer.
+#[derive(Debug)]
+pub struct Check {
+    pub year: u16,
+    pub day: u8,
+    pub expected: Answer,
+    pub actual: Result<String, String>, // answer, or description of failure
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.actual.as_ref() == Ok(&self.expected.answer)
+    }
+}
+
+/// Runs the specified closure, converting any panic to an error message.
+fn catch<T, F>(f: F) -> Result<T, String>
+where
+    F: FnOnce() -> Result<T, crate::Error>,
+{
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result.map_err(|err| err.to_string()),
+        Err(payload) => Err(match payload.downcast_ref::<&str>() {
+            Some(what) => format!("panicked: {}", what),
+            None => match payload.downcast_ref::<String>() {
+                Some(what) => format!("panicked: {}", what),
+                None => "panicked".to_owned(),
+            },
+        }),
+    }
+}
+
+/// Checks every answer in the specified puzzle's manifest that satisfies the
+/// specified predicate.  Each input file is parsed at most once.
+pub fn check<F>(entry: &Entry, mut predicate: F) -> Result<Vec<Check>, Box<dyn Error>>
+where
+    F: FnMut(&Answer) -> bool,
+{
+    let dir = entry.test_dir();
+    let mut answers: Vec<_> = load_manifest(&dir)?
+        .into_iter()
+        .filter(|a| predicate(a))
+        .collect();
+    answers.sort_by(|a, b| (&a.file, a.part).cmp(&(&b.file, b.part)));
+    let mut checks = Vec::new();
+    let mut parsed: Option<(String, Result<Parsed, String>)> = None;
+    for expected in answers {
+        if parsed.as_ref().map(|(file, _)| file) != Some(&expected.file) {
+            let path = dir.join(&expected.file);
+            let input = catch(|| entry.puzzle.parse(&fs::read_to_string(&path)?));
+            parsed = Some((expected.file.clone(), input));
+        }
+        let actual = match &parsed {
+            Some((_, Ok(input))) => catch(|| entry.puzzle.part(expected.part, input)),
+            Some((_, Err(err))) => Err(format!("can't parse input: {}", err)),
+            None => unreachable!(),
+        };
+        checks.push(Check {
+            year: entry.year,
+            day: entry.day,
+            expected,
+            actual,
+        });
+    }
+    Ok(checks)
+}
+
+/// Checks every answer that satisfies the specified predicate, in the
+/// manifests of each of the specified puzzles.
+pub fn check_all<'a, I, F>(entries: I, mut predicate: F) -> Result<Vec<Check>, Box<dyn Error>>
+where
+    I: IntoIterator<Item = &'a Entry>,
+    F: FnMut(&Answer) -> bool,
+{
+    let mut checks = Vec::new();
+    for entry in entries {
+        checks.extend(check(entry, &mut predicate)?);
+    }
+    Ok(checks)
+}
+
+/// Renders the specified checks as a table with aligned columns.
+pub fn table<'a, I>(checks: I) -> String
+where
+    I: IntoIterator<Item = &'a Check>,
+{
+    let mut rows = vec![[
+        "year".to_owned(),
+        "day".to_owned(),
+        "part".to_owned(),
+        "file".to_owned(),
+        "expected".to_owned(),
+        "actual".to_owned(),
+        "status".to_owned(),
+    ]];
+    for check in checks {
+        let (actual, status) = match &check.actual {
+            Ok(answer) if check.passed() => (answer.clone(), "ok"),
+            Ok(answer) => (answer.clone(), "MISMATCH"),
+            Err(err) => (err.clone(), "ERROR"),
+        };
+        rows.push([
+            check.year.to_string(),
+            check.day.to_string(),
+            check.expected.part.to_string(),
+            check.expected.file.clone(),
+            check.expected.answer.clone(),
+            actual,
+            status.to_owned(),
+        ]);
+    }
+    table::render(&rows)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let text = "# file part answer\n\ninput 1 289\nsample1 2 a,b,c slow\n";
+        let answers = parse_manifest(text).unwrap();
+        assert_eq!(
+            vec![
+                Answer {
+                    file: "input".to_owned(),
+                    part: Part::One,
+                    answer: "289".to_owned(),
+                    slow: false,
+                },
+                Answer {
+                    file: "sample1".to_owned(),
+                    part: Part::Two,
+                    answer: "a,b,c".to_owned(),
+                    slow: true,
+                },
+            ],
+            answers
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(parse_manifest("input 3 289").is_err());
+        assert!(parse_manifest("input 1").is_err());
+        assert!(parse_manifest("input 1 289 fast").is_err());
+    }
+
+    #[test]
+    fn render() {
+        let check = Check {
+            year: 2020,
+            day: 7,
+            expected: parse_manifest("input 1 289").unwrap().remove(0),
+            actual: Ok("288".to_owned()),
+        };
+        assert_eq!(
+            "year  day  part  file   expected  actual  status\n\
+             2020  7    1     input  289       288     MISMATCH\n",
+            table(&[check])
+        );
+    }
+}
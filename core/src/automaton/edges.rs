@@ -0,0 +1,110 @@
+use crate::error::{ParseError, ParseErrorKind};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// What lies past the edges of a dense grid, for topologies whose cells may
+/// see or touch cells beyond them.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Edges {
+    /// Nothing: cells past the edges don't exist, so they're never live.
+    #[default]
+    Open,
+    /// A wall of live cells that never change.
+    Live,
+    /// The grid itself, again: past the right edge is the left column, and
+    /// past the bottom the top row, as on a torus.
+    Wrap,
+    /// The grid's mirror image: the cell just past an edge is the one just
+    /// inside it, so that cells along an edge touch, or see, themselves.
+    Reflect,
+}
+
+impl Edges {
+    pub const ALL: [Edges; 4] = [Edges::Open, Edges::Live, Edges::Wrap, Edges::Reflect];
+
+    /// Returns the index that the specified coordinate along an axis of the
+    /// specified length corresponds to, or None if it's past the edge of an
+    /// open or live grid.
+    pub fn fold(self, x: isize, len: usize) -> Option<usize> {
+        let n = len as isize;
+        match self {
+            _ if 0 <= x && x < n => Some(x as usize),
+            Edges::Open | Edges::Live => None,
+            Edges::Wrap => Some(x.rem_euclid(n) as usize),
+            Edges::Reflect => {
+                let x = x.rem_euclid(2 * n);
+                Some(if x < n { x } else { 2 * n - 1 - x } as usize)
+            }
+        }
+    }
+
+    /// Returns the number of steps along an axis of the specified length
+    /// after which folded coordinates repeat, if they do.
+    pub fn period(self, len: usize) -> Option<usize> {
+        match self {
+            Edges::Open | Edges::Live => None,
+            Edges::Wrap => Some(len),
+            Edges::Reflect => Some(2 * len),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Edges::Open => "open",
+            Edges::Live => "live",
+            Edges::Wrap => "wrap",
+            Edges::Reflect => "reflect",
+        }
+    }
+}
+
+impl FromStr for Edges {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim();
+        Edges::ALL
+            .iter()
+            .copied()
+            .find(|edges| edges.name() == name)
+            .ok_or_else(|| {
+                let what = format!("bad edges {:?}; expected open, live, wrap, or reflect", s);
+                ParseError::with_kind(ParseErrorKind::Invalid, what)
+            })
+    }
+}
+
+impl Display for Edges {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fold() {
+        // -1 for coordinates past the edge
+        let folded = |edges: Edges| -> Vec<isize> {
+            let fold = |x| edges.fold(x, 3).map_or(-1, |x| x as isize);
+            (-4..7).map(fold).collect()
+        };
+        let open = vec![-1, -1, -1, -1, 0, 1, 2, -1, -1, -1, -1];
+        assert_eq!(open, folded(Edges::Open));
+        assert_eq!(open, folded(Edges::Live));
+        let wrap = vec![2, 0, 1, 2, 0, 1, 2, 0, 1, 2, 0];
+        assert_eq!(wrap, folded(Edges::Wrap));
+        let reflect = vec![2, 2, 1, 0, 0, 1, 2, 2, 1, 0, 0];
+        assert_eq!(reflect, folded(Edges::Reflect));
+    }
+
+    #[test]
+    fn parse() {
+        for &edges in &Edges::ALL {
+            assert_eq!(edges, edges.to_string().parse().unwrap());
+        }
+        assert!("torus".parse::<Edges>().is_err());
+    }
+}
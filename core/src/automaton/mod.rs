@@ -0,0 +1,432 @@
+//! Cellular automata: grids of cells that all change at once, each according
+//! to its own state and how many of its neighbors are live.  An [`Automaton`]
+//! combines three independent choices:
+//!
+//! - a [`Storage`] for the cells: a dense [`Grid`] of any [`Cell`] type, or a
+//!   [`SparseGrid`] of live points for unbounded space;
+//! - a [`Topology`] saying which cells neighbor which: [`Square`], [`Hex`],
+//!   N-dimensional [`Lattice`], or, on a dense grid with any [`Edges`],
+//!   [`Adjacent`] or [`LineOfSight`];
+//! - a [`Rule`] giving each cell's next state: [`Life`] rules in B/S notation,
+//!   or any closure.
+//!
+//! Dense automata can also step on several threads at once, each computing a
+//! band of rows.
+
+mod edges;
+mod rule;
+mod topology;
+
+pub use edges::Edges;
+pub use rule::{Cell, Life, Rule, MAX_NEIGHBORS};
+pub use topology::{Adjacent, Hex, Lattice, LineOfSight, Square, Topology};
+
+use crate::cycle::Detector;
+use crate::grid::{Grid, Position};
+use crate::space::{Point, SparseGrid};
+use std::collections::HashMap;
+use std::mem;
+use std::thread;
+
+/// A collection of cells that can compute its next generation.
+pub trait Storage: Clone {
+    type Location: Copy;
+    type Cell: Cell;
+
+    /// Writes the generation after this one into next, which may hold any
+    /// earlier state of the same automaton, and returns whether any cell
+    /// changed.
+    fn step_into<T, R>(&self, topology: &T, rule: &R, next: &mut Self) -> bool
+    where
+        T: Topology<Self::Location>,
+        R: Rule<Self::Cell>;
+}
+
+/// Dense storage: every cell of a fixed rectangle.  Cells outside the grid
+/// are never live, unless the topology says so.
+impl<C: Cell> Storage for Grid<C> {
+    type Location = Position;
+    type Cell = C;
+
+    // O(C·K) time, where K is the number of neighbors per cell
+    fn step_into<T, R>(&self, topology: &T, rule: &R, next: &mut Self) -> bool
+    where
+        T: Topology<Position>,
+        R: Rule<C>,
+    {
+        if next.height() != self.height() || next.width() != self.width() {
+            *next = self.clone();
+        }
+        self.step_rows(topology, rule, next.rows_mut().enumerate())
+    }
+}
+
+impl<C: Cell> Grid<C> {
+    /// Writes the next generation of the specified rows, numbered from the
+    /// top of this grid, and returns whether any cell changed.
+    fn step_rows<'a, T, R, I>(&self, topology: &T, rule: &R, rows: I) -> bool
+    where
+        C: 'a,
+        T: Topology<Position>,
+        R: Rule<C>,
+        I: IntoIterator<Item = (usize, &'a mut [C])>,
+    {
+        let outside_is_live = topology.outside_is_live();
+        let mut changed = false;
+        for (row, out) in rows {
+            for (column, (&cell, next)) in self.row(row).iter().zip(out).enumerate() {
+                if cell.is_inert() {
+                    *next = cell;
+                    continue;
+                }
+                let mut live = 0;
+                topology.for_each_neighbor(Position::new(row, column), |p| {
+                    if self.get(p).map_or(outside_is_live, |&c| c.is_live()) {
+                        live += 1;
+                    }
+                });
+                *next = rule.next(cell, live);
+                changed |= *next != cell;
+            }
+        }
+        changed
+    }
+
+    /// Like [`Storage::step_into`], but splits the rows into as many bands as
+    /// the specified number of threads, and steps each band on its own
+    /// thread.  The result is the same as stepping on one thread, which is
+    /// what happens if threads is 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if threads is 0.
+    pub fn step_into_parallel<T, R>(
+        &self,
+        topology: &T,
+        rule: &R,
+        next: &mut Self,
+        threads: usize,
+    ) -> bool
+    where
+        C: Send + Sync,
+        T: Topology<Position> + Sync,
+        R: Rule<C> + Sync,
+    {
+        assert!(threads > 0, "stepping on zero threads");
+        if threads == 1 {
+            return self.step_into(topology, rule, next);
+        }
+        if next.height() != self.height() || next.width() != self.width() {
+            *next = self.clone();
+        }
+        let mut rows: Vec<_> = next.rows_mut().enumerate().collect();
+        let band = rows.len().div_ceil(threads).max(1);
+        thread::scope(|scope| {
+            let bands: Vec<_> = rows
+                .chunks_mut(band)
+                .map(|rows| {
+                    scope.spawn(move || {
+                        self.step_rows(
+                            topology,
+                            rule,
+                            rows.iter_mut().map(|(row, out)| (*row, &mut **out)),
+                        )
+                    })
+                })
+                .collect();
+            bands
+                .into_iter()
+                .map(|band| band.join().expect("band panicked"))
+                .fold(false, |changed, band| changed | band)
+        })
+    }
+}
+
+/// Sparse storage: the set of live points in unbounded space.  Only live
+/// cells and their neighbors are considered, so dead cells with no live
+/// neighbors stay dead even if the rule says otherwise.
+impl<const N: usize> Storage for SparseGrid<N> {
+    type Location = Point<N>;
+    type Cell = bool;
+
+    // O(A·K) time and space, where A is the number of live cells
+    fn step_into<T, R>(&self, topology: &T, rule: &R, next: &mut Self) -> bool
+    where
+        T: Topology<Point<N>>,
+        R: Rule<bool>,
+    {
+        let mut counts: HashMap<Point<N>, usize> = self.iter().map(|p| (p, 0)).collect();
+        for p in self.iter() {
+            topology.for_each_neighbor(p, |q| *counts.entry(q).or_insert(0) += 1);
+        }
+        *next = counts
+            .into_iter()
+            .filter(|&(p, n)| rule.next(self.contains(p), n))
+            .map(|(p, _)| p)
+            .collect();
+        next != self
+    }
+}
+
+/// Cells evolving under a rule, generation by generation.
+#[derive(Clone, Debug)]
+pub struct Automaton<S, T, R> {
+    cells: S,
+    spare: S, // reused as the next generation's buffer
+    topology: T,
+    rule: R,
+    generation: usize,
+}
+
+impl<S, T, R> Automaton<S, T, R>
+where
+    S: Storage,
+    T: Topology<S::Location>,
+    R: Rule<S::Cell>,
+{
+    /// Returns an automaton at generation 0, having the specified cells.
+    pub fn new(cells: S, topology: T, rule: R) -> Self {
+        Automaton {
+            spare: cells.clone(),
+            cells,
+            topology,
+            rule,
+            generation: 0,
+        }
+    }
+
+    pub fn cells(&self) -> &S {
+        &self.cells
+    }
+
+    pub fn into_cells(self) -> S {
+        self.cells
+    }
+
+    pub fn topology(&self) -> &T {
+        &self.topology
+    }
+
+    /// Returns the number of generations since this automaton was created.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Advances one generation, returning whether any cell changed.
+    pub fn step(&mut self) -> bool {
+        let changed = self
+            .cells
+            .step_into(&self.topology, &self.rule, &mut self.spare);
+        mem::swap(&mut self.cells, &mut self.spare);
+        self.generation += 1;
+        changed
+    }
+
+    /// Advances the specified number of generations.
+    pub fn run(&mut self, generations: usize) -> &S {
+        for _ in 0..generations {
+            self.step();
+        }
+        &self.cells
+    }
+
+    /// Advances the specified number of generations, like [`run`], but skips
+    /// whole cycles once the cells start repeating themselves.
+    ///
+    /// [`run`]: Automaton::run
+    pub fn jump(&mut self, generations: usize) -> &S
+    where
+        S: Eq,
+    {
+        let target = self.generation + generations;
+        let mut detector = Detector::new();
+        while self.generation < target {
+            if let Some(period) = detector.check(&self.cells) {
+                self.generation += (target - self.generation) / period * period;
+                break;
+            }
+            self.step();
+        }
+        self.run(target - self.generation)
+    }
+
+    /// Advances until a generation is the same as the one before it, and
+    /// returns the number of that generation.  Returns None if the cells
+    /// instead settle into a cycle of more than one generation.  Never returns
+    /// if the cells never repeat, as when they grow without bound.
+    pub fn run_to_fixpoint(&mut self) -> Option<usize>
+    where
+        S: Eq,
+    {
+        self.run_to_fixpoint_by(Self::step)
+    }
+
+    /// Like [`run_to_fixpoint`], but advancing by the specified function,
+    /// which returns whether any cell changed.
+    ///
+    /// [`run_to_fixpoint`]: Automaton::run_to_fixpoint
+    fn run_to_fixpoint_by<F>(&mut self, mut step: F) -> Option<usize>
+    where
+        S: Eq,
+        F: FnMut(&mut Self) -> bool,
+    {
+        let mut detector = Detector::new();
+        while step(self) {
+            if detector.check(&self.cells).is_some() {
+                return None;
+            }
+        }
+        Some(self.generation - 1)
+    }
+}
+
+/// Dense automata can step on more than one thread.
+impl<C, T, R> Automaton<Grid<C>, T, R>
+where
+    C: Cell + Send + Sync,
+    T: Topology<Position> + Sync,
+    R: Rule<C> + Sync,
+{
+    /// Like [`step`], but on the specified number of threads, as by
+    /// [`Grid::step_into_parallel`].
+    ///
+    /// [`step`]: Automaton::step
+    pub fn step_parallel(&mut self, threads: usize) -> bool {
+        let changed =
+            self.cells
+                .step_into_parallel(&self.topology, &self.rule, &mut self.spare, threads);
+        mem::swap(&mut self.cells, &mut self.spare);
+        self.generation += 1;
+        changed
+    }
+
+    /// Like [`run_to_fixpoint`], but stepping on the specified number of
+    /// threads.
+    ///
+    /// [`run_to_fixpoint`]: Automaton::run_to_fixpoint
+    pub fn run_to_fixpoint_parallel(&mut self, threads: usize) -> Option<usize> {
+        self.run_to_fixpoint_by(|automaton| automaton.step_parallel(threads))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn glider() -> Grid<bool> {
+        Grid::parse(".#.\n..#\n###", |c| Ok(c == '#')).unwrap()
+    }
+
+    fn life() -> Life {
+        "B3/S23".parse().unwrap()
+    }
+
+    #[test]
+    fn dense() {
+        // A glider moves one cell down and right every four generations.
+        let grid = Grid::from_fn(6, 6, |pos| glider().get(pos).copied().unwrap_or(false));
+        let mut automaton = Automaton::new(grid.clone(), Square, life());
+        automaton.run(4);
+        assert_eq!(4, automaton.generation());
+        let moved = Grid::from_fn(6, 6, |pos| {
+            pos.row > 0 && pos.column > 0 && grid[Position::new(pos.row - 1, pos.column - 1)]
+        });
+        assert_eq!(&moved, automaton.cells());
+    }
+
+    #[test]
+    fn sparse() {
+        let cells = SparseGrid::<2>::from_plane(&glider(), |&live| live);
+        let mut automaton = Automaton::new(cells.clone(), Lattice, life());
+        let moved: SparseGrid<2> = cells.iter().map(|p| p + Point([1, 1])).collect();
+        assert_eq!(&moved, automaton.run(4));
+    }
+
+    #[test]
+    fn dimensions() {
+        let cube = SparseGrid::<3>::from_plane(&glider(), |&live| live);
+        assert_eq!(112, Automaton::new(cube, Lattice, life()).run(6).len());
+        let tesseract = SparseGrid::<4>::from_plane(&glider(), |&live| live);
+        assert_eq!(848, Automaton::new(tesseract, Lattice, life()).run(6).len());
+    }
+
+    #[test]
+    fn fixpoint() {
+        // A block is still life; everything near it dies at once.
+        let grid = Grid::parse("##..\n##..\n....\n...#", |c| Ok(c == '#')).unwrap();
+        let mut automaton = Automaton::new(grid, Square, life());
+        assert_eq!(Some(1), automaton.run_to_fixpoint());
+        assert_eq!(2, automaton.generation());
+        assert_eq!(4, automaton.cells().iter().filter(|&&live| live).count());
+    }
+
+    #[test]
+    fn oscillator() {
+        // A blinker flips between horizontal and vertical every generation.
+        let grid = Grid::parse(".....\n.....\n.###.\n.....\n.....", |c| Ok(c == '#')).unwrap();
+        let mut automaton = Automaton::new(grid.clone(), Square, life());
+        assert_eq!(None, automaton.run_to_fixpoint());
+        let mut automaton = Automaton::new(grid.clone(), Square, life());
+        assert_eq!(&grid, automaton.jump(1_000_000_000));
+        assert_eq!(1_000_000_000, automaton.generation());
+        assert_ne!(&grid, automaton.jump(1_000_000_001));
+    }
+
+    #[test]
+    fn live_edges() {
+        // Walled in by live cells, dead cells along the edges but not in the
+        // corners have three live neighbors, and are born; with open edges,
+        // nothing happens.
+        let grid = Grid::new(3, 3, false);
+        let topology = Adjacent::new(&grid, Edges::Live);
+        let mut automaton = Automaton::new(grid.clone(), topology, life());
+        automaton.step();
+        let shown = automaton.cells().map(|&live| if live { '#' } else { '.' });
+        assert_eq!(".#.\n#.#\n.#.\n", shown.to_string());
+        let topology = Adjacent::new(&grid, Edges::Open);
+        assert!(!Automaton::new(grid, topology, life()).step());
+    }
+
+    #[test]
+    fn closure_rule() {
+        // Each cell becomes its number of live neighbors.
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        struct Counter(u8);
+        impl Cell for Counter {
+            fn is_live(self) -> bool {
+                self.0 > 0
+            }
+        }
+        let grid = Grid::from_fn(1, 3, |pos| Counter(pos.column as u8 % 2));
+        let rule = |_: Counter, n: usize| Counter(n as u8);
+        let mut automaton = Automaton::new(grid, Square, rule);
+        automaton.step();
+        let counts: Vec<_> = automaton.cells().iter().map(|c| c.0).collect();
+        assert_eq!(vec![1, 0, 1], counts);
+    }
+
+    #[test]
+    fn parallel() {
+        // Bands of any size, including empty ones, step like the whole.
+        let grid = Grid::from_fn(7, 9, |pos| (pos.row * 5 + pos.column * 3) % 7 < 3);
+        for &edges in &Edges::ALL {
+            let topology = Adjacent::new(&grid, edges);
+            let mut serial = Automaton::new(grid.clone(), topology, life());
+            serial.run(5);
+            for threads in 1..10 {
+                let mut parallel = Automaton::new(grid.clone(), topology, life());
+                for _ in 0..5 {
+                    parallel.step_parallel(threads);
+                }
+                assert_eq!(serial.cells(), parallel.cells(), "{} threads", threads);
+            }
+        }
+        let mut serial = Automaton::new(grid.clone(), Square, life());
+        let mut parallel = Automaton::new(grid, Square, life());
+        assert_eq!(
+            serial.run_to_fixpoint(),
+            parallel.run_to_fixpoint_parallel(4)
+        );
+        assert_eq!(serial.cells(), parallel.cells());
+    }
+}
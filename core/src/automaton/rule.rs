@@ -0,0 +1,217 @@
+use crate::error::{ParseError, ParseErrorKind};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// The state of a cell.  Rules see how many of a cell's neighbors are live,
+/// whatever other states cells may have.
+pub trait Cell: Copy + Eq {
+    fn is_live(self) -> bool;
+
+    /// Returns true if cells in this state never change, so that dense
+    /// storage can skip them; for example, floor in a seating chart.
+    fn is_inert(self) -> bool {
+        false
+    }
+}
+
+impl Cell for bool {
+    fn is_live(self) -> bool {
+        self
+    }
+}
+
+/// How cells change from one generation to the next.  Any closure taking a
+/// cell's state and its number of live neighbors, and returning its next
+/// state, is a rule.
+pub trait Rule<C> {
+    fn next(&self, cell: C, live_neighbors: usize) -> C;
+}
+
+impl<C, F> Rule<C> for F
+where
+    F: Fn(C, usize) -> C,
+{
+    fn next(&self, cell: C, live_neighbors: usize) -> C {
+        self(cell, live_neighbors)
+    }
+}
+
+/// The most live neighbors a cell can have: those of a point in a
+/// six-dimensional [`Lattice`](super::Lattice), 3^6 - 1, the largest
+/// neighborhood the engine is used with.  Rules ignore counts beyond it.
+pub const MAX_NEIGHBORS: usize = 728;
+
+/// A rule for live and dead cells in B/S notation: a dead cell is born if its
+/// number of live neighbors is any listed after B, and a live cell survives
+/// if its number is any listed after S.  Conway's Game of Life is "B3/S23".
+/// Counts are single digits, or separated by commas if any is more than 9,
+/// as in "B3/S2,3,10" or, for a lone count, "B3/S10,".
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Life {
+    birth: Vec<bool>,
+    survival: Vec<bool>,
+}
+
+impl Life {
+    /// Returns a rule where dead cells are born with any of the specified
+    /// numbers of live neighbors, and live cells survive with any of the
+    /// others.
+    pub fn new(birth: &[usize], survival: &[usize]) -> Life {
+        Life {
+            birth: to_mask(birth),
+            survival: to_mask(survival),
+        }
+    }
+}
+
+/// Returns a vector that is true at each of the specified indexes, leaving
+/// out any above [`MAX_NEIGHBORS`].
+fn to_mask(counts: &[usize]) -> Vec<bool> {
+    let counts: Vec<_> = counts
+        .iter()
+        .copied()
+        .filter(|&count| count <= MAX_NEIGHBORS)
+        .collect();
+    let mut mask = vec![false; counts.iter().max().map_or(0, |&max| max + 1)];
+    for count in counts {
+        mask[count] = true;
+    }
+    mask
+}
+
+fn from_mask(mask: &[bool]) -> Vec<usize> {
+    (0..mask.len()).filter(|&count| mask[count]).collect()
+}
+
+impl Rule<bool> for Life {
+    fn next(&self, live: bool, live_neighbors: usize) -> bool {
+        let mask = if live { &self.survival } else { &self.birth };
+        mask.get(live_neighbors).copied().unwrap_or(false)
+    }
+}
+
+/// Parses the counts in s, a slice of the whole rule, locating errors
+/// within the rule.
+fn parse_counts(rule: &str, s: &str) -> Result<Vec<usize>, ParseError> {
+    let bad = |count: &str| {
+        let what = format!("bad neighbor count {:?} in rule", count);
+        ParseError::with_kind(ParseErrorKind::Number, what).within(rule, count)
+    };
+    let count = |count: &str| match count.parse() {
+        Ok(n) if n <= MAX_NEIGHBORS => Ok(n),
+        Ok(_) => {
+            let what = format!(
+                "neighbor count {} in rule is more than the most possible, {}",
+                count, MAX_NEIGHBORS
+            );
+            Err(ParseError::with_kind(ParseErrorKind::Invalid, what).within(rule, count))
+        }
+        Err(_) => Err(bad(count)),
+    };
+    if s.contains(',') {
+        s.split(',')
+            .map(str::trim)
+            .filter(|count| !count.is_empty())
+            .map(count)
+            .collect()
+    } else {
+        let digit = |c: char| c.to_digit(10).map(|d| d as usize);
+        s.char_indices()
+            .map(|(at, c)| digit(c).ok_or_else(|| bad(&s[at..at + c.len_utf8()])))
+            .collect()
+    }
+}
+
+impl FromStr for Life {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bad = || {
+            let what = format!("bad rule {:?}: expected B/S notation, as in B3/S23", s);
+            ParseError::with_kind(ParseErrorKind::Invalid, what)
+        };
+        let (birth, survival) = s.trim().split_once('/').ok_or_else(bad)?;
+        let (birth, survival) = match (birth.chars().next(), survival.chars().next()) {
+            (Some('B'), Some('S')) | (Some('b'), Some('s')) => (birth, survival),
+            (Some('S'), Some('B')) | (Some('s'), Some('b')) => (survival, birth),
+            _ => return Err(bad()),
+        };
+        Ok(Life::new(
+            &parse_counts(s, &birth[1..])?,
+            &parse_counts(s, &survival[1..])?,
+        ))
+    }
+}
+
+impl Display for Life {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let show = |mask: &[bool]| {
+            let counts = from_mask(mask);
+            let counts = counts.iter().map(|count| count.to_string());
+            if mask.len() <= 10 {
+                counts.collect()
+            } else {
+                let counts: Vec<_> = counts.collect();
+                let trailer = if counts.len() == 1 { "," } else { "" };
+                counts.join(",") + trailer
+            }
+        };
+        write!(f, "B{}/S{}", show(&self.birth), show(&self.survival))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn life() {
+        let rule: Life = "B3/S23".parse().unwrap();
+        assert_eq!(Life::new(&[3], &[2, 3]), rule);
+        assert!(rule.next(false, 3));
+        assert!(!rule.next(false, 2));
+        assert!(rule.next(true, 2));
+        assert!(!rule.next(true, 4));
+        assert!(!rule.next(true, 100));
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Life::new(&[2], &[1, 2]), "s12/b2".parse().unwrap());
+        assert_eq!(Life::new(&[], &[]), "B/S".parse().unwrap());
+        assert_eq!(Life::new(&[2, 10], &[2, 3]), "B2,10/S2,3".parse().unwrap());
+        assert_eq!(Life::new(&[1, 0], &[10]), "B10/S10,".parse().unwrap());
+        let err = "B3S23".parse::<Life>().unwrap_err();
+        assert_eq!(ParseErrorKind::Invalid, err.kind());
+        let err = "B3/S2x".parse::<Life>().unwrap_err();
+        assert_eq!(ParseErrorKind::Number, err.kind());
+        assert_eq!(Some(5..6), err.span());
+        let err = "B3/S2, 99999999999".parse::<Life>().unwrap_err();
+        assert_eq!(
+            (ParseErrorKind::Invalid, Some(7..18)),
+            (err.kind(), err.span())
+        );
+        let err = "B3/S2,99999999999999999999".parse::<Life>().unwrap_err();
+        assert_eq!(
+            (ParseErrorKind::Number, Some(6..26)),
+            (err.kind(), err.span())
+        );
+        let most = format!("B{},/S", MAX_NEIGHBORS);
+        assert_eq!(Life::new(&[MAX_NEIGHBORS], &[]), most.parse().unwrap());
+        assert_eq!(Life::new(&[3], &[]), Life::new(&[3, usize::MAX], &[]));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("B3/S23", Life::new(&[3], &[3, 2]).to_string());
+        assert_eq!("B3/S2,12", Life::new(&[3], &[2, 12]).to_string());
+        let rule = Life::new(&[12], &[]);
+        assert_eq!(rule, rule.to_string().parse().unwrap());
+    }
+
+    #[test]
+    fn closure() {
+        let rule = |cell: u8, n: usize| cell + n as u8;
+        assert_eq!(5, rule.next(2, 3));
+    }
+}
@@ -0,0 +1,509 @@
+use super::Edges;
+use crate::grid::{Grid, Position, NEIGHBORS8};
+use crate::space::Point;
+
+/// An arrangement of cells: which locations neighbor which.
+///
+/// Topologies may report locations that hold no cell, such as positions past
+/// the edge of a dense grid; storage counts those as dead, unless the
+/// topology says they're live.
+pub trait Topology<L> {
+    /// Calls visit with each location adjacent to the specified one.
+    fn for_each_neighbor<F: FnMut(L)>(&self, at: L, visit: F);
+
+    /// Returns whether neighbors at locations that hold no cell count as
+    /// live, as past [`Edges::Live`].
+    fn outside_is_live(&self) -> bool {
+        false
+    }
+}
+
+/// Returns the position the specified offset away from the specified one.
+/// Offsets past the top or left edge wrap around to positions too large to
+/// be in any grid.
+fn shift(pos: Position, (dr, dc): (isize, isize)) -> Position {
+    Position::new(
+        pos.row.wrapping_add_signed(dr),
+        pos.column.wrapping_add_signed(dc),
+    )
+}
+
+/// Square cells, each touching the eight around it.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Square;
+
+impl Topology<Position> for Square {
+    fn for_each_neighbor<F: FnMut(Position)>(&self, at: Position, mut visit: F) {
+        for &offset in &NEIGHBORS8 {
+            visit(shift(at, offset));
+        }
+    }
+}
+
+impl Topology<Point<2>> for Square {
+    fn for_each_neighbor<F: FnMut(Point<2>)>(&self, at: Point<2>, visit: F) {
+        at.neighbors().for_each(visit);
+    }
+}
+
+/// Square cells of a grid of fixed size, each touching the eight around it,
+/// with the specified edges.  With open edges, this is the same as
+/// [`Square`].
+#[derive(Clone, Copy, Debug)]
+pub struct Adjacent {
+    height: usize,
+    width: usize,
+    edges: Edges,
+}
+
+impl Adjacent {
+    /// Returns the topology of the specified grid with the specified edges.
+    pub fn new<T>(grid: &Grid<T>, edges: Edges) -> Adjacent {
+        Adjacent {
+            height: grid.height(),
+            width: grid.width(),
+            edges,
+        }
+    }
+}
+
+impl Topology<Position> for Adjacent {
+    fn for_each_neighbor<F: FnMut(Position)>(&self, at: Position, mut visit: F) {
+        let inside =
+            0 < at.row && at.row + 1 < self.height && 0 < at.column && at.column + 1 < self.width;
+        if inside || matches!(self.edges, Edges::Open | Edges::Live) {
+            return Square.for_each_neighbor(at, visit);
+        }
+        for &(dr, dc) in &NEIGHBORS8 {
+            let row = self.edges.fold(at.row as isize + dr, self.height);
+            let column = self.edges.fold(at.column as isize + dc, self.width);
+            match (row, column) {
+                (Some(row), Some(column)) => visit(Position::new(row, column)),
+                _ => visit(shift(at, (dr, dc))), // past an open or live edge
+            }
+        }
+    }
+
+    fn outside_is_live(&self) -> bool {
+        self.edges == Edges::Live
+    }
+}
+
+/// Hexagonal cells in rows, each touching two cells in its own row and two in
+/// each of the rows above and below.  Odd rows are shifted half a cell right
+/// of even ones, so cells in even rows touch the cells above and below them
+/// and to the left of those, while cells in odd rows touch the cells above
+/// and below them and to the right.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Hex;
+
+impl Hex {
+    const EVEN: [(isize, isize); 6] = [(-1, -1), (-1, 0), (0, 1), (1, 0), (1, -1), (0, -1)];
+    const ODD: [(isize, isize); 6] = [(-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (0, -1)];
+
+    /// Returns the (row, column) offsets of the neighbors of any cell in the
+    /// specified row.
+    fn offsets(row: isize) -> &'static [(isize, isize); 6] {
+        if row.rem_euclid(2) == 0 {
+            &Hex::EVEN
+        } else {
+            &Hex::ODD
+        }
+    }
+}
+
+impl Topology<Position> for Hex {
+    fn for_each_neighbor<F: FnMut(Position)>(&self, at: Position, mut visit: F) {
+        for &offset in Hex::offsets(at.row as isize) {
+            visit(shift(at, offset));
+        }
+    }
+}
+
+/// Points are (column, row), as in [`SparseGrid::from_plane`].
+///
+/// [`SparseGrid::from_plane`]: crate::space::SparseGrid::from_plane
+impl Topology<Point<2>> for Hex {
+    fn for_each_neighbor<F: FnMut(Point<2>)>(&self, at: Point<2>, mut visit: F) {
+        for &(dr, dc) in Hex::offsets(at[1]) {
+            visit(at + Point([dc, dr]));
+        }
+    }
+}
+
+/// Points in N-space, each touching every point within one step along each
+/// axis: 8 in a plane, 26 in 3-space, and 3^N - 1 in general.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Lattice;
+
+impl<const N: usize> Topology<Point<N>> for Lattice {
+    fn for_each_neighbor<F: FnMut(Point<N>)>(&self, at: Point<N>, visit: F) {
+        at.neighbors().for_each(visit);
+    }
+}
+
+/// Cells of a dense grid that see, rather than touch, each other: each cell's
+/// neighbors are the first opaque cells in each of the eight directions
+/// around it.  Transparent cells see and are seen like any others, but don't
+/// block the view.  Past live edges, cells see the wall; past wrapping or
+/// reflecting ones, they see on into the grid, and so may see themselves.
+///
+/// What each cell sees is worked out once, when the topology is created, and
+/// stored as a flat adjacency list in compressed sparse row form: the
+/// neighbors of the cell at row-major index i are `seen[start[i]..start[i +
+/// 1]]`.  Visiting them is then a slice walk, however far apart seats are.
+#[derive(Clone, Debug)]
+pub struct LineOfSight {
+    transparent: Grid<bool>,
+    edges: Edges,
+    start: Vec<usize>, // index into seen of each cell's first neighbor
+    seen: Vec<Position>,
+}
+
+impl LineOfSight {
+    /// Returns the topology of the specified grid, where cells for which
+    /// is_transparent returns true don't block the view, and nothing lies
+    /// past the edges.
+    // O(C) time and space
+    pub fn new<T, F>(grid: &Grid<T>, is_transparent: F) -> LineOfSight
+    where
+        F: Fn(&T) -> bool,
+    {
+        LineOfSight::with_edges(grid, is_transparent, Edges::Open)
+    }
+
+    /// Like [`LineOfSight::new`], but with the specified edges.
+    // O(C) time
+    pub fn with_edges<T, F>(grid: &Grid<T>, is_transparent: F, edges: Edges) -> LineOfSight
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut sight = LineOfSight {
+            transparent: grid.map(is_transparent),
+            edges,
+            start: Vec::new(),
+            seen: Vec::new(),
+        };
+        let views: Vec<_> = NEIGHBORS8
+            .iter()
+            .map(|&direction| match edges.period(1) {
+                None => sight.view(direction),
+                Some(_) => sight.cyclic_view(direction),
+            })
+            .collect();
+        let mut start = Vec::with_capacity(grid.len() + 1);
+        let mut seen = Vec::new();
+        for pos in sight.transparent.positions() {
+            start.push(seen.len());
+            seen.extend(views.iter().filter_map(|view| view[pos]));
+        }
+        start.push(seen.len());
+        sight.start = start;
+        sight.seen = seen;
+        sight
+    }
+
+    /// Returns the position of the first opaque cell visible from the
+    /// specified position in the specified direction: possibly a position
+    /// past a live edge, or None if there is nothing but transparent cells
+    /// between it and an open edge, or in every cell a wrapping or
+    /// reflected view passes through.
+    pub fn first_visible(&self, pos: Position, (dr, dc): (isize, isize)) -> Option<Position> {
+        let (height, width) = (self.transparent.height(), self.transparent.width());
+        let period = |len: usize, step: isize| match self.edges.period(len) {
+            Some(period) if step != 0 => period,
+            _ => 1,
+        };
+        // Open and live views leave the grid within this many steps, and
+        // others repeat after it.
+        let limit = match self.edges.period(1) {
+            None => height.max(width),
+            Some(_) => lcm(period(height, dr), period(width, dc)),
+        };
+        for k in 1..=(limit as isize) {
+            let row = self.edges.fold(pos.row as isize + k * dr, height);
+            let column = self.edges.fold(pos.column as isize + k * dc, width);
+            match (row, column) {
+                (Some(row), Some(column)) => {
+                    let p = Position::new(row, column);
+                    if !self.transparent[p] {
+                        return Some(p);
+                    }
+                }
+                _ if self.edges == Edges::Live => return Some(shift(pos, (k * dr, k * dc))),
+                _ => return None,
+            }
+        }
+        None
+    }
+
+    /// Returns the cells visible from the specified position, which must be
+    /// in the grid, in the order of [`NEIGHBORS8`].
+    pub fn visible(&self, pos: Position) -> &[Position] {
+        let i = pos.row * self.transparent.width() + pos.column;
+        &self.seen[self.start[i]..self.start[i + 1]]
+    }
+
+    /// Returns the first opaque cell visible from each cell in the specified
+    /// direction, if any, when the edges are open or live.  Cells are
+    /// visited starting from the edge the direction points to, so that each
+    /// can look up the answer of the cell next to it rather than walk the
+    /// whole ray.
+    // O(C) time
+    fn view(&self, direction: (isize, isize)) -> Grid<Option<Position>> {
+        let transparent = &self.transparent;
+        let (height, width) = (transparent.height(), transparent.width());
+        let mut view = Grid::new(height, width, None);
+        let rows: Vec<_> = if direction.0 > 0 {
+            (0..height).rev().collect()
+        } else {
+            (0..height).collect()
+        };
+        let columns: Vec<_> = if direction.1 > 0 {
+            (0..width).rev().collect()
+        } else {
+            (0..width).collect()
+        };
+        for &row in &rows {
+            for &column in &columns {
+                let pos = Position::new(row, column);
+                view[pos] = match transparent.offset(pos, direction) {
+                    Some(next) if transparent[next] => view[next],
+                    Some(next) => Some(next),
+                    None if self.edges == Edges::Live => Some(shift(pos, direction)),
+                    None => None,
+                };
+            }
+        }
+        view
+    }
+
+    /// Like [`view`](LineOfSight::view), but for edges that wrap or
+    /// reflect.  A view then follows unfolded coordinates, which repeat
+    /// after a period along each axis it moves along; stepping through them
+    /// is a permutation, so they fall into cycles, each of which is resolved
+    /// by walking round it twice, backward, carrying the nearest opaque cell
+    /// ahead.
+    // O(C) time: the unfolded coordinates number at most 4C.
+    fn cyclic_view(&self, (dr, dc): (isize, isize)) -> Grid<Option<Position>> {
+        let transparent = &self.transparent;
+        let (height, width) = (transparent.height(), transparent.width());
+        let span = |len: usize, step: isize| match self.edges.period(len) {
+            Some(period) if step != 0 => period,
+            _ => len,
+        };
+        let (rows, columns) = (span(height, dr), span(width, dc));
+        let next = |state: usize| {
+            let (row, column) = (state / columns, state % columns);
+            let row = (row as isize + dr).rem_euclid(rows as isize) as usize;
+            let column = (column as isize + dc).rem_euclid(columns as isize) as usize;
+            row * columns + column
+        };
+        let cell = |state: usize| {
+            let row = self.edges.fold((state / columns) as isize, height);
+            let column = self.edges.fold((state % columns) as isize, width);
+            let (row, column) = row.zip(column).expect("wrapped edges fold everything");
+            Position::new(row, column)
+        };
+        let mut seen = vec![None; rows * columns];
+        let mut done = vec![false; rows * columns];
+        let mut cycle = Vec::new();
+        for first in 0..rows * columns {
+            if done[first] {
+                continue;
+            }
+            cycle.clear();
+            let mut state = first;
+            loop {
+                done[state] = true;
+                cycle.push(state);
+                state = next(state);
+                if state == first {
+                    break;
+                }
+            }
+            let len = cycle.len();
+            let mut ahead = None;
+            for i in (0..2 * len).rev() {
+                let after = cell(cycle[(i + 1) % len]);
+                if !transparent[after] {
+                    ahead = Some(after);
+                }
+                if i < len {
+                    seen[cycle[i]] = ahead;
+                }
+            }
+        }
+        let mut view = Grid::new(height, width, None);
+        for pos in transparent.positions() {
+            view[pos] = seen[pos.row * columns + pos.column];
+        }
+        view
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    a / gcd(a, b) * b
+}
+
+impl Topology<Position> for LineOfSight {
+    fn for_each_neighbor<F: FnMut(Position)>(&self, at: Position, visit: F) {
+        if self.transparent.contains(at) {
+            self.visible(at).iter().copied().for_each(visit);
+        }
+    }
+
+    fn outside_is_live(&self) -> bool {
+        self.edges == Edges::Live
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn neighbors<L, T: Topology<L>>(topology: &T, at: L) -> Vec<L> {
+        let mut out = Vec::new();
+        topology.for_each_neighbor(at, |p| out.push(p));
+        out
+    }
+
+    #[test]
+    fn square() {
+        let grid = Grid::new(3, 3, ());
+        let all = neighbors(&Square, Position::new(1, 1));
+        assert_eq!(8, all.into_iter().filter(|&p| grid.contains(p)).count());
+        let corner = neighbors(&Square, Position::new(0, 0));
+        assert_eq!(3, corner.into_iter().filter(|&p| grid.contains(p)).count());
+        assert_eq!(8, neighbors(&Square, Point([-5, 7])).len());
+    }
+
+    #[test]
+    fn hex() {
+        // Each cell's neighbors must count it as a neighbor in turn.
+        for row in 1..3 {
+            let center = Position::new(row, 5);
+            for pos in neighbors(&Hex, center) {
+                assert!(neighbors(&Hex, pos).contains(&center));
+            }
+        }
+        let even: HashSet<_> = neighbors(&Hex, Position::new(2, 5)).into_iter().collect();
+        assert!(even.contains(&Position::new(1, 4)));
+        assert!(!even.contains(&Position::new(1, 6)));
+        let odd: HashSet<_> = neighbors(&Hex, Point([5, -1])).into_iter().collect();
+        assert!(odd.contains(&Point([6, -2])));
+        assert!(!odd.contains(&Point([4, -2])));
+    }
+
+    #[test]
+    fn lattice() {
+        assert_eq!(80, neighbors(&Lattice, Point::<4>::ORIGIN).len());
+    }
+
+    #[test]
+    fn line_of_sight() {
+        let grid = Grid::parse("#..#\n....\n#.##", |c| Ok(c == '.')).unwrap();
+        let topology = LineOfSight::new(&grid, |&floor| floor);
+        let seen = neighbors(&topology, Position::new(0, 0));
+        let expected = [(0, 3), (2, 2), (2, 0)]
+            .iter()
+            .map(|&(r, c)| Position::new(r, c));
+        assert_eq!(expected.collect::<Vec<_>>(), seen);
+        let up = topology.first_visible(Position::new(2, 3), (-1, 0));
+        assert_eq!(Some(Position::new(0, 3)), up);
+        assert_eq!(None, topology.first_visible(Position::new(2, 3), (0, 1)));
+    }
+
+    #[test]
+    fn line_of_sight_matches_rays() {
+        // Every cell must see what walking each ray finds.
+        let grids = [
+            "#....#..\n..#.....\n........\n#..#...#\n.....#..",
+            ".......\n.......\n...#...\n.......\n.......",
+            ".....\n.....\n.....",
+            "..#..",
+        ];
+        for text in &grids {
+            let grid = Grid::parse(text, |c| Ok(c == '.')).unwrap();
+            for &edges in &Edges::ALL {
+                let topology = LineOfSight::with_edges(&grid, |&floor| floor, edges);
+                for pos in grid.positions() {
+                    let rays = NEIGHBORS8
+                        .iter()
+                        .filter_map(|&d| topology.first_visible(pos, d));
+                    let rays: Vec<_> = rays.collect();
+                    assert_eq!(rays, topology.visible(pos), "{:?} {}", pos, edges);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn line_of_sight_coprime() {
+        // Diagonal views round a torus this size pass through every cell
+        // before they repeat, which is too far to walk from each cell.
+        let mut grid = Grid::new(301, 299, true);
+        let seat = Position::new(150, 150);
+        grid[seat] = false;
+        for &edges in &[Edges::Wrap, Edges::Reflect] {
+            let topology = LineOfSight::with_edges(&grid, |&floor| floor, edges);
+            for &pos in &[Position::new(0, 0), seat, Position::new(300, 298)] {
+                let rays: Vec<_> = NEIGHBORS8
+                    .iter()
+                    .filter_map(|&d| topology.first_visible(pos, d))
+                    .collect();
+                assert_eq!(rays, topology.visible(pos), "{:?} {}", pos, edges);
+                // At least every diagonal view finds the one seat.
+                assert!(rays.len() >= 4);
+            }
+        }
+    }
+
+    #[test]
+    fn adjacent_edges() {
+        let grid = Grid::new(3, 4, ());
+        let corner = Position::new(0, 0);
+        let open = neighbors(&Adjacent::new(&grid, Edges::Open), corner);
+        assert_eq!(3, open.iter().filter(|&&p| grid.contains(p)).count());
+        let live = Adjacent::new(&grid, Edges::Live);
+        assert!(live.outside_is_live());
+        assert!(!Topology::<Position>::outside_is_live(&Square));
+        let wrap = neighbors(&Adjacent::new(&grid, Edges::Wrap), corner);
+        assert!(wrap.contains(&Position::new(2, 3)));
+        assert!(wrap.iter().all(|&p| grid.contains(p)));
+        // The corner's reflections across both edges and the corner itself
+        let reflect = neighbors(&Adjacent::new(&grid, Edges::Reflect), corner);
+        assert_eq!(3, reflect.iter().filter(|&&p| p == corner).count());
+    }
+
+    #[test]
+    fn line_of_sight_edges() {
+        let grid = Grid::parse("#...\n....\n..#.", |c| Ok(c == '.')).unwrap();
+        let sight = |edges| LineOfSight::with_edges(&grid, |&floor| floor, edges);
+        let (top, bottom) = (Position::new(0, 0), Position::new(2, 2));
+        assert_eq!(&[bottom], sight(Edges::Open).visible(top));
+        // Past live edges, every direction ends at the wall.
+        let live = sight(Edges::Live);
+        assert_eq!(8, live.visible(top).len());
+        let outside = live.visible(top).iter().filter(|&&p| !grid.contains(p));
+        assert_eq!(7, outside.count());
+        // Wrapping left, the top seat sees itself; up and left, it sees the
+        // bottom one.
+        let wrap = sight(Edges::Wrap);
+        assert_eq!(Some(top), wrap.first_visible(top, (0, -1)));
+        assert_eq!(Some(bottom), wrap.first_visible(top, (-1, -1)));
+        // Looking up from the top row, a seat sees itself in the mirror.
+        let reflect = sight(Edges::Reflect);
+        assert_eq!(Some(top), reflect.first_visible(top, (-1, 0)));
+        assert_eq!(None, reflect.first_visible(Position::new(1, 3), (0, 1)));
+    }
+}
@@ -0,0 +1,353 @@
+//! Timing of each puzzle's parse, part 1, and part 2 steps.  Each step is run
+//! repeatedly, and the fastest, median, and slowest runs are reported.
+//!
+//! Timings can be saved to a baseline file and compared against later runs.
+//! A baseline holds one line per step, with durations in nanoseconds:
+//!
+//! ```text
+//! # year  day  step   min      median   max
+//! 2020    7    parse  812345   830112   901776
+//! ```
+//!
+//! Blank lines and lines beginning with '#' are ignored.
+
+use crate::error::ParseError;
+use crate::registry::Entry;
+use crate::solution::Part;
+use crate::table;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// A separately timed step of a puzzle solution.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Step {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+impl FromStr for Step {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Step::Parse),
+            "part1" => Ok(Step::Part(Part::One)),
+            "part2" => Ok(Step::Part(Part::Two)),
+            _ => Err(ParseError::new(format!(
+                "{}: expected parse, part1, or part2",
+                s
+            ))),
+        }
+    }
+}
+
+/// Summary of repeated runs of one step.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Timing {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timing {
+    /// Summarizes the specified samples, or returns None if there are none.
+    /// The median of an even number of samples is the mean of the middle two.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Timing> {
+        samples.sort_unstable();
+        let n = samples.len();
+        let median = match n {
+            0 => return None,
+            _ if n % 2 == 1 => samples[n / 2],
+            _ => (samples[n / 2 - 1] + samples[n / 2]) / 2,
+        };
+        Some(Timing {
+            min: samples[0],
+            median,
+            max: samples[n - 1],
+        })
+    }
+}
+
+/// Timing of one step of one puzzle.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Measurement {
+    pub year: u16,
+    pub day: u8,
+    pub step: Step,
+    pub timing: Timing,
+}
+
+/// Runs the specified function the specified number of times, returning the
+/// result of the last run along with the duration of each.  The number of
+/// runs must be positive.
+fn repeat<T, F>(runs: usize, mut f: F) -> Result<(T, Vec<Duration>), crate::Error>
+where
+    F: FnMut() -> Result<T, crate::Error>,
+{
+    let mut samples = Vec::with_capacity(runs);
+    let mut result = None;
+    for _ in 0..runs {
+        let start = Instant::now();
+        let value = f()?;
+        samples.push(start.elapsed());
+        result = Some(value);
+    }
+    Ok((result.expect("runs were checked"), samples))
+}
+
+/// Times each step of the specified puzzle on the specified input text.
+pub fn measure(entry: &Entry, text: &str, runs: usize) -> Result<Vec<Measurement>, Box<dyn Error>> {
+    if runs == 0 {
+        return Err("number of runs must be positive".into());
+    }
+    let measurement = |step, samples| Measurement {
+        year: entry.year,
+        day: entry.day,
+        step,
+        timing: Timing::from_samples(samples).expect("runs were checked"),
+    };
+    let (input, samples) =
+        repeat(runs, || entry.puzzle.parse(text)).map_err(|err| format!("parse: {}", err))?;
+    let mut measurements = vec![measurement(Step::Parse, samples)];
+    for &part in entry.puzzle.parts() {
+        let (_, samples) = repeat(runs, || entry.puzzle.part(part, &input))
+            .map_err(|err| format!("part {}: {}", part, err))?;
+        measurements.push(measurement(Step::Part(part), samples));
+    }
+    Ok(measurements)
+}
+
+pub fn parse_baseline(text: &str) -> Result<Vec<Measurement>, ParseError> {
+    let mut measurements = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let bad_line = |what: &dyn Display| ParseError::new(what).on_line(index + 1, line);
+        let fields: Vec<_> = line.split_whitespace().collect();
+        let (year, day, step, min, median, max) = match fields[..] {
+            [year, day, step, min, median, max] => (year, day, step, min, median, max),
+            _ => return Err(bad_line(&"expected: YEAR DAY STEP MIN MEDIAN MAX")),
+        };
+        let nanos = |s: &str| {
+            s.parse()
+                .map(Duration::from_nanos)
+                .map_err(|err| bad_line(&err))
+        };
+        measurements.push(Measurement {
+            year: year.parse().map_err(|err| bad_line(&err))?,
+            day: day.parse().map_err(|err| bad_line(&err))?,
+            step: step.parse().map_err(|err| bad_line(&err))?,
+            timing: Timing {
+                min: nanos(min)?,
+                median: nanos(median)?,
+                max: nanos(max)?,
+            },
+        });
+    }
+    Ok(measurements)
+}
+
+pub fn format_baseline(measurements: &[Measurement]) -> String {
+    let mut rows = vec![[
+        "# year".to_owned(),
+        "day".to_owned(),
+        "step".to_owned(),
+        "min".to_owned(),
+        "median".to_owned(),
+        "max".to_owned(),
+    ]];
+    for m in measurements {
+        rows.push([
+            m.year.to_string(),
+            m.day.to_string(),
+            m.step.to_string(),
+            m.timing.min.as_nanos().to_string(),
+            m.timing.median.as_nanos().to_string(),
+            m.timing.max.as_nanos().to_string(),
+        ]);
+    }
+    table::render(&rows)
+}
+
+pub fn load_baseline<P: AsRef<Path>>(path: P) -> Result<Vec<Measurement>, Box<dyn Error>> {
+    let path = path.as_ref();
+    let text = fs::read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+    Ok(parse_baseline(&text).map_err(|err| err.with_path(path))?)
+}
+
+pub fn save_baseline<P: AsRef<Path>>(
+    path: P,
+    measurements: &[Measurement],
+) -> Result<(), Box<dyn Error>> {
+    let path = path.as_ref();
+    fs::write(path, format_baseline(measurements))
+        .map_err(|err| format!("{}: {}", path.display(), err).into())
+}
+
+/// Comparison of a measurement's median against its baseline.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Change {
+    /// Relative change of the median; 0.25 means 25% slower.
+    pub ratio: f64,
+    pub regressed: bool,
+}
+
+/// Compares the specified measurement to its entry in the specified
+/// baseline, if any.  A measurement regresses if its median exceeds the
+/// baseline median by more than the specified fraction.
+pub fn compare(
+    measurement: &Measurement,
+    baseline: &[Measurement],
+    threshold: f64,
+) -> Option<Change> {
+    let base = baseline.iter().find(|base| {
+        (base.year, base.day, base.step) == (measurement.year, measurement.day, measurement.step)
+    })?;
+    let before = base.timing.median.as_secs_f64();
+    let after = measurement.timing.median.as_secs_f64();
+    let ratio = if before > 0.0 {
+        after / before - 1.0
+    } else {
+        0.0
+    };
+    Some(Change {
+        ratio,
+        regressed: ratio > threshold,
+    })
+}
+
+/// Formats a duration with three significant digits and a suitable unit.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    let (value, unit) = if nanos < 1e3 {
+        (nanos, "ns")
+    } else if nanos < 1e6 {
+        (nanos / 1e3, "µs")
+    } else if nanos < 1e9 {
+        (nanos / 1e6, "ms")
+    } else {
+        (nanos / 1e9, "s")
+    };
+    let precision = if value < 10.0 {
+        2
+    } else if value < 100.0 {
+        1
+    } else {
+        0
+    };
+    format!("{:.*}{}", precision, value, unit)
+}
+
+/// Renders the specified measurements as a table with aligned columns,
+/// comparing each to the specified baseline.
+pub fn table(measurements: &[Measurement], baseline: &[Measurement], threshold: f64) -> String {
+    let mut rows = vec![[
+        "year".to_owned(),
+        "day".to_owned(),
+        "step".to_owned(),
+        "min".to_owned(),
+        "median".to_owned(),
+        "max".to_owned(),
+        "change".to_owned(),
+        "status".to_owned(),
+    ]];
+    for m in measurements {
+        let (change, status) = match compare(m, baseline, threshold) {
+            Some(change) => (
+                format!("{:+.1}%", change.ratio * 100.0),
+                if change.regressed { "SLOWER" } else { "ok" },
+            ),
+            None => ("-".to_owned(), "-"),
+        };
+        rows.push([
+            m.year.to_string(),
+            m.day.to_string(),
+            m.step.to_string(),
+            format_duration(m.timing.min),
+            format_duration(m.timing.median),
+            format_duration(m.timing.max),
+            change,
+            status.to_owned(),
+        ]);
+    }
+    table::render(&rows)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    fn measurement(step: Step, median: u64) -> Measurement {
+        Measurement {
+            year: 2020,
+            day: 7,
+            step,
+            timing: Timing {
+                min: ms(median - 1),
+                median: ms(median),
+                max: ms(median + 1),
+            },
+        }
+    }
+
+    #[test]
+    fn timing() {
+        assert_eq!(None, Timing::from_samples(vec![]));
+        let odd = Timing::from_samples(vec![ms(5), ms(1), ms(3)]).unwrap();
+        assert_eq!((ms(1), ms(3), ms(5)), (odd.min, odd.median, odd.max));
+        let even = Timing::from_samples(vec![ms(4), ms(1), ms(2), ms(9)]).unwrap();
+        assert_eq!((ms(1), ms(3), ms(9)), (even.min, even.median, even.max));
+    }
+
+    #[test]
+    fn baseline() {
+        let measurements = vec![
+            measurement(Step::Parse, 2),
+            measurement(Step::Part(Part::Two), 40),
+        ];
+        let text = format_baseline(&measurements);
+        assert_eq!(measurements, parse_baseline(&text).unwrap());
+        assert!(parse_baseline("2020 7 part3 1 2 3").is_err());
+        assert!(parse_baseline("2020 7 parse 1 2").is_err());
+    }
+
+    #[test]
+    fn comparison() {
+        let baseline = [measurement(Step::Parse, 10)];
+        let slower = compare(&measurement(Step::Parse, 12), &baseline, 0.1).unwrap();
+        assert!(slower.regressed);
+        assert!((slower.ratio - 0.2).abs() < 1e-9);
+        let noise = compare(&measurement(Step::Parse, 11), &baseline, 0.15).unwrap();
+        assert!(!noise.regressed);
+        assert_eq!(
+            None,
+            compare(&measurement(Step::Part(Part::One), 10), &baseline, 0.1)
+        );
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!("999ns", format_duration(Duration::from_nanos(999)));
+        assert_eq!("1.23ms", format_duration(Duration::from_micros(1234)));
+        assert_eq!("45.7s", format_duration(ms(45_678)));
+    }
+}
@@ -0,0 +1,221 @@
+//! Cycle detection for deterministic state machines, whose every state
+//! determines the next.  Such a machine with finitely many states must
+//! eventually revisit one, after which it repeats itself forever; knowing
+//! where that happens lets simulations skip straight to the answer.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a sequence of states starts repeating: state number prefix + period
+/// is the same as state number prefix, and no earlier pair of states match.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// Returns the number of the earliest state equal to state number n.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+/// Finds the cycle in the sequence of states starting with the specified one,
+/// by remembering every state.  Returns None if no state repeats within the
+/// first limit steps.
+// O(μ + λ) time and space
+pub fn find<S, F>(start: S, mut step: F, limit: usize) -> Option<Cycle>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut state = start;
+    for index in 0..=limit {
+        if let Some(&prefix) = seen.get(&state) {
+            let period = index - prefix;
+            return Some(Cycle { prefix, period });
+        }
+        seen.insert(state.clone(), index);
+        state = step(state);
+    }
+    None
+}
+
+/// Like [`find`], but using Brent's algorithm, which remembers only one state
+/// at a time and needs no hashing, at the cost of stepping through the
+/// sequence more than once.  Returns None if no repeat is detected within the
+/// first limit steps.
+// O(μ + λ) time, O(1) space
+pub fn find_brent<S, F>(start: S, mut step: F, limit: usize) -> Option<Cycle>
+where
+    S: Clone + Eq,
+    F: FnMut(S) -> S,
+{
+    let mut detector = Detector::new();
+    let mut state = start.clone();
+    let mut period = None;
+    for _ in 0..=limit {
+        period = detector.check(&state);
+        if period.is_some() {
+            break;
+        }
+        state = step(state);
+    }
+    let period = period?;
+    let (prefix, _) = first_repeated(start, period, step);
+    Some(Cycle { prefix, period })
+}
+
+/// Returns the number of the first state to repeat in the sequence starting
+/// with the specified state, and the state itself, given the period of the
+/// cycle the sequence enters; for example, as reported by a [`Detector`].
+// O(μ + λ) time, O(1) space
+pub fn first_repeated<S, F>(start: S, period: usize, mut step: F) -> (usize, S)
+where
+    S: Clone + Eq,
+    F: FnMut(S) -> S,
+{
+    // Walk two states period apart from the start until they meet.
+    let mut behind = start.clone();
+    let mut ahead = (0..period).fold(start, |state, _| step(state));
+    let mut prefix = 0;
+    while behind != ahead {
+        behind = step(behind);
+        ahead = step(ahead);
+        prefix += 1;
+    }
+    (prefix, behind)
+}
+
+/// Returns state number n of the sequence starting with the specified state,
+/// skipping whole periods once the sequence is seen to repeat.
+// O(min(n, μ + λ)) time, O(1) space
+pub fn nth<S, F>(start: S, n: usize, mut step: F) -> S
+where
+    S: Clone + Eq,
+    F: FnMut(S) -> S,
+{
+    let mut detector = Detector::new();
+    let mut state = start;
+    let mut index = 0;
+    while index < n {
+        if let Some(period) = detector.check(&state) {
+            return (0..(n - index) % period).fold(state, |state, _| step(state));
+        }
+        state = step(state);
+        index += 1;
+    }
+    state
+}
+
+/// Watches a sequence of states go by, one at a time, to tell when it starts
+/// repeating, by Brent's algorithm: it remembers a single state, replaced
+/// after each power of two steps, and compares each new state against it.
+/// A repeat is detected within about μ + 2λ states of its occurring.
+#[derive(Clone, Debug)]
+pub struct Detector<S> {
+    saved: Option<S>,
+    power: usize, // steps to compare before the saved state is replaced
+    steps: usize, // since the saved state
+}
+
+impl<S: Clone + Eq> Detector<S> {
+    pub fn new() -> Detector<S> {
+        Detector {
+            saved: None,
+            power: 1,
+            steps: 0,
+        }
+    }
+
+    /// Records the next state in the sequence.  Returns the period if this
+    /// state matches an earlier one, in which case every later state will
+    /// match the one that many steps before it.
+    pub fn check(&mut self, state: &S) -> Option<usize> {
+        match &self.saved {
+            Some(saved) if saved == state => return Some(self.steps),
+            Some(_) if self.steps < self.power => {}
+            _ => {
+                self.saved = Some(state.clone());
+                self.power *= 2;
+                self.steps = 0;
+            }
+        }
+        self.steps += 1;
+        None
+    }
+}
+
+impl<S: Clone + Eq> Default for Detector<S> {
+    fn default() -> Self {
+        Detector::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // From 0: 7 states, then a cycle of 11.
+    fn square1(n: u32) -> u32 {
+        (n * n + 1) % 71
+    }
+
+    #[test]
+    fn reduce() {
+        let cycle = Cycle {
+            prefix: 2,
+            period: 3,
+        };
+        assert_eq!(1, cycle.reduce(1));
+        assert_eq!(4, cycle.reduce(4));
+        assert_eq!(2, cycle.reduce(5));
+        assert_eq!(4, cycle.reduce(1_000_000));
+    }
+
+    #[test]
+    fn find_cycles() {
+        let want = Some(Cycle {
+            prefix: 7,
+            period: 11,
+        });
+        assert_eq!(want, find(0, square1, 18));
+        assert_eq!(want, find_brent(0, square1, 100));
+        assert_eq!(None, find(0, square1, 17));
+        let fixed = Some(Cycle {
+            prefix: 4,
+            period: 1,
+        });
+        assert_eq!(fixed, find(8, |n: u32| n / 2, 10));
+        assert_eq!(fixed, find_brent(8, |n: u32| n / 2, 10));
+        assert_eq!((7, 58), first_repeated(0, 11, square1));
+    }
+
+    #[test]
+    fn nth_state() {
+        let mut naive = 0;
+        for n in 0..100 {
+            assert_eq!(naive, nth(0, n, square1));
+            naive = square1(naive);
+        }
+        assert_eq!(nth(0, 8, square1), nth(0, 1_100_000_008, square1));
+        assert_eq!(1 << 40, nth(1u64, 40, |n| n * 2));
+    }
+
+    #[test]
+    fn detector() {
+        let mut detector = Detector::new();
+        let periods: Vec<_> = [1, 2, 3, 4, 2, 3, 4, 2, 3, 4]
+            .iter()
+            .map(|state| detector.check(state))
+            .collect();
+        assert!(periods[..5].iter().all(Option::is_none));
+        assert_eq!(Some(3), periods[5]);
+    }
+}
@@ -0,0 +1,101 @@
+//! Runs puzzles on behalf of command-line programs, reporting answers on
+//! stdout and failures on stderr.  Exit statuses are shared by every program:
+//! 1 means part 1 failed, 2 means part 2 failed, and 3 means the input could
+//! not be read or parsed.
+
+use crate::error::Error;
+use crate::solution::{Part, Puzzle};
+use std::fs;
+use std::io::{self, Read as _};
+use std::path::{Path, PathBuf};
+use std::process;
+
+pub const EXIT_INPUT: i32 = 3;
+
+/// Returns the exit status indicating failure of the specified part.
+pub fn exit_status(part: Part) -> i32 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+/// Where to read puzzle input from.
+#[derive(Clone, Debug)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Source::File(path) => fs::read_to_string(path),
+            Source::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                Ok(text)
+            }
+        }
+    }
+
+    /// Returns a name for this source, suitable for error messages.
+    pub fn name(&self) -> String {
+        match self {
+            Source::File(path) => path.display().to_string(),
+            Source::Stdin => "<stdin>".to_owned(),
+        }
+    }
+}
+
+/// Prints the specified error on stderr.  Errors in puzzle input are shown
+/// with an excerpt of the offending line.
+fn report(source: &Source, context: &str, err: &Error) {
+    match err {
+        Error::Parse(err) if err.path().is_none() => {
+            eprintln!(
+                "error: {}{:#}",
+                context,
+                err.clone().with_path(source.name())
+            )
+        }
+        _ => eprintln!("error: {}: {}{:#}", source.name(), context, err),
+    }
+}
+
+/// Solves the specified parts of a puzzle, printing each answer on its own
+/// line.  Stops at the first failure, and returns the exit status the calling
+/// program should report.
+pub fn run(puzzle: &dyn Puzzle, source: &Source, parts: &[Part]) -> i32 {
+    let parsed = source
+        .read()
+        .map_err(Error::from)
+        .and_then(|text| puzzle.parse(&text));
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            report(source, "", &err);
+            return EXIT_INPUT;
+        }
+    };
+    for &part in parts {
+        match puzzle.part(part, &parsed) {
+            Ok(answer) => println!("{}", answer),
+            Err(err) => {
+                report(source, &format!("part {}: ", part), &err);
+                return exit_status(part);
+            }
+        }
+    }
+    0
+}
+
+/// Solves both parts of the specified puzzle for the input file at the
+/// specified path, and exits on failure.
+pub fn main<P: AsRef<Path>>(puzzle: &dyn Puzzle, input: P) {
+    let source = Source::File(input.as_ref().to_owned());
+    let status = run(puzzle, &source, puzzle.parts());
+    if status != 0 {
+        process::exit(status);
+    }
+}
@@ -0,0 +1,96 @@
+//! Errors shared by every puzzle.  Solvers return [`Error`], so callers can
+//! tell bad input from unsolvable input without downcasting.
+
+mod parse_error;
+
+pub use parse_error::{ParseError, ParseErrorKind};
+use std::convert::Infallible;
+use std::error;
+use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::num::ParseIntError;
+
+/// Any failure to solve a puzzle.
+#[derive(Debug)]
+pub enum Error {
+    /// The input could not be read.
+    Io(io::Error),
+    /// The input is malformed.
+    Parse(ParseError),
+    /// The input is well formed, but has no answer.
+    NoSolution,
+    /// The input, or some part of it the puzzle requires, is empty.
+    EmptyInput,
+    /// An invariant was violated; this is a bug, not bad input.
+    Internal(String),
+}
+
+impl Error {
+    pub fn internal<S: ToString>(what: S) -> Error {
+        Error::Internal(what.to_string())
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Error::Io(err) => Display::fmt(err, f),
+            Error::Parse(err) => Display::fmt(err, f), // honors {:#}
+            Error::NoSolution => write!(f, "no solution"),
+            Error::EmptyInput => write!(f, "empty input"),
+            Error::Internal(what) => write!(f, "internal error: {}", what),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Parse(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl From<Infallible> for Error {
+    fn from(never: Infallible) -> Self {
+        match never {}
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(err: ParseIntError) -> Self {
+        Error::Parse(err.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!("no solution", Error::NoSolution.to_string());
+        assert_eq!("empty input", Error::EmptyInput.to_string());
+        assert_eq!(
+            "internal error: bad state",
+            Error::internal("bad state").to_string()
+        );
+        let err = Error::from(ParseError::new("bad digit").on_line(2, "1x"));
+        assert_eq!("line 2: bad digit", err.to_string());
+        assert_eq!("bad digit\n --> line 2\n  |\n2 | 1x", format!("{:#}", err));
+    }
+}
@@ -0,0 +1,251 @@
+//! Errors in puzzle input.  A `ParseError` can say where in the input it
+//! occurred: the file, the line number, and the span of bytes within that
+//! line.  Parsers that see only part of a line report spans relative to the
+//! text they were given; callers shift them into place with
+//! [`ParseError::within`] and attach the line with [`ParseError::on_line`].
+//!
+//! The alternate form (`{:#}`) renders an excerpt of the offending line with
+//! the span underlined, in the style of rustc:
+//!
+//! ```text
+//! unexpected character 'x'
+//!  --> tests/day18/input:3:10
+//!   |
+//! 3 | 1 + (2 * x)
+//!   |          ^
+//! ```
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+use std::num::ParseIntError;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// What went wrong, independent of where.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseErrorKind {
+    /// The input, or some required part of it, is empty.
+    Empty,
+    /// The input ended in the middle of something.
+    UnexpectedEnd,
+    /// A character or word that isn't allowed where it appears.
+    Unexpected,
+    /// A malformed or out-of-range number.
+    Number,
+    /// Anything else.
+    Invalid,
+}
+
+#[derive(Clone, Debug)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    what: String,
+    path: Option<PathBuf>,
+    line: Option<usize>,    // 1-based line number
+    source: Option<String>, // text of the line, for excerpts
+    span: Option<Range<usize>>,
+}
+
+/// Returns the byte offset of `inner` within `outer`, if `inner` is a slice
+/// of `outer`.
+fn offset_of(outer: &str, inner: &str) -> Option<usize> {
+    let start = outer.as_ptr() as usize;
+    let offset = (inner.as_ptr() as usize).checked_sub(start)?;
+    if offset + inner.len() <= outer.len() {
+        Some(offset)
+    } else {
+        None
+    }
+}
+
+impl ParseError {
+    pub fn new<S: ToString>(what: S) -> ParseError {
+        ParseError::with_kind(ParseErrorKind::Invalid, what)
+    }
+
+    pub fn with_kind<S: ToString>(kind: ParseErrorKind, what: S) -> ParseError {
+        ParseError {
+            kind,
+            what: what.to_string(),
+            path: None,
+            line: None,
+            source: None,
+            span: None,
+        }
+    }
+
+    /// Sets the file in which this error occurred.
+    pub fn with_path<P: AsRef<Path>>(mut self, path: P) -> ParseError {
+        self.path = Some(path.as_ref().to_owned());
+        self
+    }
+
+    /// Sets the number (counting from 1) and text of the line on which this
+    /// error occurred.  Any span is taken to be relative to that text.
+    pub fn on_line(mut self, number: usize, text: &str) -> ParseError {
+        self.line = Some(number);
+        self.source = Some(text.to_owned());
+        self
+    }
+
+    /// Sets the byte range of the offending text.
+    pub fn with_span(mut self, span: Range<usize>) -> ParseError {
+        self.span = Some(span);
+        self
+    }
+
+    /// Makes a span relative to `inner` relative to `outer` instead, where
+    /// `inner` is a slice of `outer`.  If there is no span yet, all of
+    /// `inner` becomes the span.
+    pub fn within(mut self, outer: &str, inner: &str) -> ParseError {
+        if let Some(offset) = offset_of(outer, inner) {
+            let span = self.span.take().unwrap_or(0..inner.len());
+            self.span = Some((span.start + offset)..(span.end + offset));
+        }
+        self
+    }
+
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span.clone()
+    }
+
+    /// Returns the 1-based column, in characters, at which the span starts.
+    /// Requires both a line and a span.
+    pub fn column(&self) -> Option<usize> {
+        let source = self.source.as_ref()?;
+        let start = self.span.as_ref()?.start.min(source.len());
+        Some(source.get(..start)?.chars().count() + 1)
+    }
+
+    fn fmt_location(&self, f: &mut Formatter) -> Result {
+        match (&self.path, self.line, self.column()) {
+            (Some(path), Some(line), Some(column)) => {
+                write!(f, "{}:{}:{}", path.display(), line, column)
+            }
+            (Some(path), Some(line), None) => write!(f, "{}:{}", path.display(), line),
+            (Some(path), None, _) => write!(f, "{}", path.display()),
+            (None, Some(line), Some(column)) => write!(f, "line {}, column {}", line, column),
+            (None, Some(line), None) => write!(f, "line {}", line),
+            (None, None, _) => Ok(()),
+        }
+    }
+
+    fn fmt_excerpt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}", self.what)?;
+        if self.path.is_none() && self.line.is_none() {
+            return Ok(());
+        }
+        let number = self.line.map(|line| line.to_string()).unwrap_or_default();
+        let gutter = " ".repeat(number.len());
+        write!(f, "\n{}--> ", gutter)?;
+        self.fmt_location(f)?;
+        let source = match &self.source {
+            Some(source) => source,
+            None => return Ok(()),
+        };
+        write!(f, "\n{} |\n{} | {}", gutter, number, source)?;
+        if let Some(column) = self.column() {
+            let span = self.span.clone().unwrap_or(0..0);
+            let end = span.end.clamp(span.start, source.len());
+            let width = source
+                .get(span.start.min(end)..end)
+                .map_or(0, |text| text.chars().count())
+                .max(1);
+            let indent = " ".repeat(column - 1);
+            write!(f, "\n{} | {}{}", gutter, indent, "^".repeat(width))?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if f.alternate() {
+            return self.fmt_excerpt(f);
+        }
+        if self.path.is_some() || self.line.is_some() {
+            self.fmt_location(f)?;
+            write!(f, ": ")?;
+        }
+        write!(f, "{}", self.what)
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseIntError> for ParseError {
+    fn from(err: ParseIntError) -> Self {
+        Self::with_kind(ParseErrorKind::Number, err)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn plain() {
+        let err = ParseError::new("bad input");
+        assert_eq!("bad input", err.to_string());
+        assert_eq!("bad input", format!("{:#}", err));
+        let err = err.on_line(3, "1 + x");
+        assert_eq!("line 3: bad input", err.to_string());
+        let err = err.with_path("input");
+        assert_eq!("input:3: bad input", err.to_string());
+    }
+
+    #[test]
+    fn excerpt() {
+        let line = "1 + (2 * xy)";
+        let word = &line[9..11];
+        let err = ParseError::with_kind(ParseErrorKind::Unexpected, "unexpected word")
+            .within(line, word)
+            .on_line(12, line)
+            .with_path("tests/day18/input");
+        assert_eq!(Some(10), err.column());
+        assert_eq!(Some(9..11), err.span());
+        assert_eq!("tests/day18/input:12:10: unexpected word", err.to_string());
+        assert_eq!(
+            "unexpected word\n  \
+             --> tests/day18/input:12:10\n   \
+             |\n\
+             12 | 1 + (2 * xy)\n   \
+             |          ^^",
+            format!("{:#}", err)
+        );
+    }
+
+    #[test]
+    fn nested_spans() {
+        let line = "mem[8] = 1x";
+        let value = &line[9..];
+        let err = ParseError::new("bad digit")
+            .with_span(1..2)
+            .within(line, value)
+            .on_line(1, line);
+        assert_eq!(Some(10..11), err.span());
+        assert_eq!(Some(11), err.column());
+    }
+
+    #[test]
+    fn end_of_line() {
+        let line = "mem[8";
+        let err = ParseError::with_kind(ParseErrorKind::UnexpectedEnd, "missing ']'")
+            .with_span(line.len()..line.len())
+            .on_line(1, line);
+        assert_eq!(Some(6), err.column());
+        assert!(format!("{:#}", err).ends_with("\n  |      ^"));
+    }
+}
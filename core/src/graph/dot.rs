@@ -0,0 +1,80 @@
+//! Graphviz's DOT language, for drawing graphs with `dot -Tsvg` and the like.
+
+use super::Graph;
+use std::fmt::Write;
+use std::hash::Hash;
+
+/// Returns the specified text as a quoted DOT string.
+fn quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '\n' => quoted.push_str("\\n"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+impl<N, W> Graph<N, W>
+where
+    N: Clone + Eq + Hash,
+{
+    /// Returns this graph in DOT, labeling each node by node_label, and each
+    /// edge by edge_label if it returns a label for the edge's weight.
+    /// Nodes are named by the order they were added, so labels needn't be
+    /// unique.
+    pub fn to_dot<F, G>(&self, mut node_label: F, mut edge_label: G) -> String
+    where
+        F: FnMut(&N) -> String,
+        G: FnMut(&W) -> Option<String>,
+    {
+        let mut dot = String::from("digraph {\n");
+        for (index, node) in self.nodes.iter().enumerate() {
+            let label = quote(&node_label(node));
+            writeln!(dot, "    n{} [label={}];", index, label).unwrap();
+        }
+        for (from, edges) in self.outgoing.iter().enumerate() {
+            for (to, weight) in edges {
+                write!(dot, "    n{} -> n{}", from, to).unwrap();
+                if let Some(label) = edge_label(weight) {
+                    write!(dot, " [label={}]", quote(&label)).unwrap();
+                }
+                dot.push_str(";\n");
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dot() {
+        let graph: Graph<&str, u32> = vec![
+            ("shiny gold", "dark \"red\"", 2),
+            ("dark \"red\"", "shiny gold", 0),
+        ]
+        .into_iter()
+        .collect();
+        let label = |&n: &u32| if n > 0 { Some(n.to_string()) } else { None };
+        let expected = "\
+digraph {
+    n0 [label=\"shiny gold\"];
+    n1 [label=\"dark \\\"red\\\"\"];
+    n0 -> n1 [label=\"2\"];
+    n1 -> n0;
+}
+";
+        assert_eq!(expected, graph.to_dot(|node| node.to_string(), label));
+    }
+}
@@ -0,0 +1,587 @@
+//! Directed graphs, for puzzles about things that contain, lead to, or
+//! depend on other things.  A [`Graph`]'s nodes are any hashable values, and
+//! its edges carry weights of any type, or `()` if they need none.  Besides
+//! building and walking graphs, this module finds topological orders,
+//! strongly connected components, path counts, and shortest paths, and draws
+//! graphs in Graphviz's DOT language.
+
+mod dot;
+mod paths;
+
+pub use paths::Weight;
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::iter::FromIterator;
+
+/// The progress of a depth-first search through a node.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Mark {
+    Unseen,
+    Open, // on the search's stack
+    Done,
+}
+
+/// A directed graph with at most one edge from any node to any other, each
+/// edge having a weight.  Nodes are kept in the order they were added, and
+/// each node's edges in the order they were added, so that every traversal
+/// is deterministic.
+#[derive(Clone, Debug)]
+pub struct Graph<N, W = ()> {
+    nodes: Vec<N>,
+    indexes: HashMap<N, usize>,
+    outgoing: Vec<Vec<(usize, W)>>,
+    incoming: Vec<Vec<usize>>,
+}
+
+impl<N, W> Graph<N, W>
+where
+    N: Clone + Eq + Hash,
+{
+    pub fn new() -> Self {
+        Graph {
+            nodes: Vec::new(),
+            indexes: HashMap::new(),
+            outgoing: Vec::new(),
+            incoming: Vec::new(),
+        }
+    }
+
+    /// Returns the index of the specified node, adding it if it's new.
+    fn intern(&mut self, node: N) -> usize {
+        if let Some(&index) = self.indexes.get(&node) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.nodes.push(node.clone());
+        self.indexes.insert(node, index);
+        self.outgoing.push(Vec::new());
+        self.incoming.push(Vec::new());
+        index
+    }
+
+    /// Adds the specified node, with no edges, returning true if it's new.
+    pub fn add_node(&mut self, node: N) -> bool {
+        let len = self.nodes.len();
+        self.intern(node) == len
+    }
+
+    /// Adds an edge of the specified weight, adding its nodes if they're new.
+    /// If there already was an edge between the same nodes, its weight is
+    /// replaced and returned.
+    pub fn add_edge(&mut self, from: N, to: N, weight: W) -> Option<W> {
+        let (from, to) = (self.intern(from), self.intern(to));
+        match self.outgoing[from].iter_mut().find(|(next, _)| *next == to) {
+            Some((_, old)) => Some(std::mem::replace(old, weight)),
+            None => {
+                self.outgoing[from].push((to, weight));
+                self.incoming[to].push(from);
+                None
+            }
+        }
+    }
+
+    /// Returns a graph of the same nodes, in the same order, with every edge
+    /// turned around.
+    pub fn reversed(&self) -> Self
+    where
+        W: Clone,
+    {
+        let mut reversed = Graph::new();
+        for node in &self.nodes {
+            reversed.add_node(node.clone());
+        }
+        for (from, to, weight) in self.edges() {
+            reversed.add_edge(to.clone(), from.clone(), weight.clone());
+        }
+        reversed
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.indexes.contains_key(node)
+    }
+
+    /// Returns the weight of the edge between the specified nodes, if any.
+    pub fn weight(&self, from: &N, to: &N) -> Option<&W> {
+        let (from, to) = (self.indexes.get(from)?, self.indexes.get(to)?);
+        let edge = self.outgoing[*from].iter().find(|(next, _)| next == to);
+        edge.map(|(_, weight)| weight)
+    }
+
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.outgoing.iter().map(Vec::len).sum()
+    }
+
+    /// Returns the nodes in the order they were added.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    /// Returns each edge as its source, destination, and weight.
+    pub fn edges(&self) -> impl Iterator<Item = (&N, &N, &W)> {
+        self.outgoing
+            .iter()
+            .enumerate()
+            .flat_map(move |(from, edges)| {
+                let from = &self.nodes[from];
+                edges
+                    .iter()
+                    .map(move |(to, weight)| (from, &self.nodes[*to], weight))
+            })
+    }
+
+    /// Returns the nodes that the specified node has edges to, with the
+    /// weights of those edges.  A node not in the graph has none.
+    pub fn successors(&self, node: &N) -> impl Iterator<Item = (&N, &W)> {
+        let edges = self
+            .indexes
+            .get(node)
+            .map_or(&[][..], |&i| &self.outgoing[i]);
+        edges
+            .iter()
+            .map(move |(to, weight)| (&self.nodes[*to], weight))
+    }
+
+    /// Returns the nodes that have edges to the specified node.
+    pub fn predecessors(&self, node: &N) -> impl Iterator<Item = &N> {
+        let edges = self
+            .indexes
+            .get(node)
+            .map_or(&[][..], |&i| &self.incoming[i]);
+        edges.iter().map(move |&from| &self.nodes[from])
+    }
+
+    /// Returns the nodes reachable from the specified one, starting with it,
+    /// in breadth-first order, each with its number of edges from the start.
+    pub fn bfs(&self, start: &N) -> Vec<(&N, usize)> {
+        let start = match self.indexes.get(start) {
+            Some(&start) => start,
+            None => return Vec::new(),
+        };
+        let mut seen = vec![false; self.len()];
+        seen[start] = true;
+        let mut queue = VecDeque::from(vec![(start, 0)]);
+        let mut order = Vec::new();
+        while let Some((node, depth)) = queue.pop_front() {
+            order.push((&self.nodes[node], depth));
+            for &(next, _) in &self.outgoing[node] {
+                if !seen[next] {
+                    seen[next] = true;
+                    queue.push_back((next, depth + 1));
+                }
+            }
+        }
+        order
+    }
+
+    /// Returns the nodes reachable from the specified one, starting with it,
+    /// in depth-first preorder.
+    pub fn dfs(&self, start: &N) -> Vec<&N> {
+        let mut order = Vec::new();
+        if let Some(&start) = self.indexes.get(start) {
+            let mut marks = vec![Mark::Unseen; self.len()];
+            self.search(
+                start,
+                &mut marks,
+                |node| order.push(&self.nodes[node]),
+                |_| (),
+            );
+        }
+        order
+    }
+
+    /// Returns the nodes reachable from the specified one in depth-first
+    /// postorder, each after all the nodes it has edges to, ending with the
+    /// start; or None if a cycle is reachable, so that there's no such order.
+    /// Working through this order, anything computed for a node can build on
+    /// what was computed for its successors.
+    pub fn postorder(&self, start: &N) -> Option<Vec<&N>> {
+        let mut order = Vec::new();
+        if let Some(&start) = self.indexes.get(start) {
+            let mut marks = vec![Mark::Unseen; self.len()];
+            let post = |node| order.push(&self.nodes[node]);
+            if self.search(start, &mut marks, |_| (), post) {
+                return None;
+            }
+        }
+        Some(order)
+    }
+
+    /// Returns a shortest cycle through the specified node, as its nodes
+    /// from that one around and back to it, or None if it's on no cycle.
+    pub fn cycle_through(&self, node: &N) -> Option<Vec<&N>> {
+        let start = *self.indexes.get(node)?;
+        // The node before each on a shortest path from the start.
+        let mut before = vec![None; self.len()];
+        let mut queue = VecDeque::from(vec![start]);
+        while let Some(node) = queue.pop_front() {
+            for &(next, _) in &self.outgoing[node] {
+                if next == start {
+                    let mut cycle = vec![&self.nodes[start], &self.nodes[node]];
+                    let mut node = node;
+                    while let Some(prev) = before[node] {
+                        cycle.push(&self.nodes[prev]);
+                        node = prev;
+                    }
+                    cycle.reverse();
+                    return Some(cycle);
+                }
+                if before[next].is_none() {
+                    before[next] = Some(node);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Walks the nodes reachable from start that are still unseen, depth
+    /// first, calling pre as each is entered and post once all of its
+    /// successors are done.  Returns true if the walk found a cycle.
+    fn search<Pre, Post>(
+        &self,
+        start: usize,
+        marks: &mut [Mark],
+        mut pre: Pre,
+        mut post: Post,
+    ) -> bool
+    where
+        Pre: FnMut(usize),
+        Post: FnMut(usize),
+    {
+        if marks[start] != Mark::Unseen {
+            return false;
+        }
+        let mut cycle = false;
+        // Each entry is a node and the number of its edges followed so far.
+        let mut stack = vec![(start, 0)];
+        marks[start] = Mark::Open;
+        pre(start);
+        while let Some((node, edge)) = stack.last_mut() {
+            match self.outgoing[*node].get(*edge) {
+                Some(&(next, _)) => {
+                    *edge += 1;
+                    match marks[next] {
+                        Mark::Unseen => {
+                            marks[next] = Mark::Open;
+                            pre(next);
+                            stack.push((next, 0));
+                        }
+                        Mark::Open => cycle = true,
+                        Mark::Done => (),
+                    }
+                }
+                None => {
+                    let node = *node;
+                    marks[node] = Mark::Done;
+                    post(node);
+                    stack.pop();
+                }
+            }
+        }
+        cycle
+    }
+
+    /// Returns the number of edges to each node.
+    fn in_degrees(&self) -> Vec<usize> {
+        self.incoming.iter().map(Vec::len).collect()
+    }
+
+    /// Returns every node in an order where each comes before all the nodes
+    /// it has edges to, or None if there's no such order because the graph
+    /// has a cycle.  Of the nodes that could come next, the earliest added
+    /// does.
+    pub fn topological_order(&self) -> Option<Vec<&N>> {
+        let mut degrees = self.in_degrees();
+        let mut ready: VecDeque<usize> = (0..self.len()).filter(|&i| degrees[i] == 0).collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(node) = ready.pop_front() {
+            order.push(&self.nodes[node]);
+            for &(next, _) in &self.outgoing[node] {
+                degrees[next] -= 1;
+                if degrees[next] == 0 {
+                    ready.push_back(next);
+                }
+            }
+        }
+        if order.len() == self.len() {
+            Some(order)
+        } else {
+            None
+        }
+    }
+
+    /// Like [`topological_order`], but of the nodes that could come next,
+    /// the least does, making the order the least there is.
+    ///
+    /// [`topological_order`]: Graph::topological_order
+    pub fn least_topological_order(&self) -> Option<Vec<&N>>
+    where
+        N: Ord,
+    {
+        let mut degrees = self.in_degrees();
+        let mut ready: BinaryHeap<_> = (0..self.len())
+            .filter(|&i| degrees[i] == 0)
+            .map(|i| Reverse((&self.nodes[i], i)))
+            .collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(Reverse((node, index))) = ready.pop() {
+            order.push(node);
+            for &(next, _) in &self.outgoing[index] {
+                degrees[next] -= 1;
+                if degrees[next] == 0 {
+                    ready.push(Reverse((&self.nodes[next], next)));
+                }
+            }
+        }
+        if order.len() == self.len() {
+            Some(order)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of distinct paths from one node to another, or
+    /// None if there are infinitely many, because some path between them
+    /// passes through a cycle, or too many to count in a usize.  A node has
+    /// one path to itself.
+    // O(V + E) time: each node's count is computed once, after those of all
+    // its successors.
+    pub fn count_paths(&self, from: &N, to: &N) -> Option<usize> {
+        let (from, to) = match (self.indexes.get(from), self.indexes.get(to)) {
+            (Some(&from), Some(&to)) => (from, to),
+            _ => return Some(0),
+        };
+        // Nodes with no path to the target count as done, with no paths, so
+        // that cycles among them don't matter.
+        let mut marks = vec![Mark::Done; self.len()];
+        let mut stack = vec![to];
+        marks[to] = Mark::Unseen;
+        while let Some(node) = stack.pop() {
+            for &prev in &self.incoming[node] {
+                if marks[prev] == Mark::Done {
+                    marks[prev] = Mark::Unseen;
+                    stack.push(prev);
+                }
+            }
+        }
+        // A count of None is too many.
+        let mut counts = vec![Some(0); self.len()];
+        let cycle = self.search(
+            from,
+            &mut marks,
+            |_| (),
+            |node| {
+                counts[node] = if node == to {
+                    Some(1)
+                } else {
+                    self.outgoing[node]
+                        .iter()
+                        .try_fold(0usize, |sum, &(next, _)| sum.checked_add(counts[next]?))
+                };
+            },
+        );
+        if cycle {
+            None
+        } else {
+            counts[from]
+        }
+    }
+
+    /// Returns the strongly connected components: the largest sets of nodes
+    /// that each have paths to all the others.  Components come in
+    /// topological order, so that edges from any component lead only to
+    /// itself or to later ones.  Each component lists its nodes in the order
+    /// they were added.
+    // O(V + E) time, by Kosaraju's algorithm.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<&N>> {
+        // Nodes in decreasing order of when their depth-first search ended
+        // are in topological order of their components.
+        let mut finished = Vec::with_capacity(self.len());
+        let mut marks = vec![Mark::Unseen; self.len()];
+        for start in 0..self.len() {
+            self.search(start, &mut marks, |_| (), |node| finished.push(node));
+        }
+        // Searching backward from each in that order finds just the nodes of
+        // its component that no earlier search claimed.
+        let mut component_of = vec![usize::MAX; self.len()];
+        let mut count = 0;
+        for &start in finished.iter().rev() {
+            if component_of[start] != usize::MAX {
+                continue;
+            }
+            let mut stack = vec![start];
+            component_of[start] = count;
+            while let Some(node) = stack.pop() {
+                for &prev in &self.incoming[node] {
+                    if component_of[prev] == usize::MAX {
+                        component_of[prev] = count;
+                        stack.push(prev);
+                    }
+                }
+            }
+            count += 1;
+        }
+        let mut components = vec![Vec::new(); count];
+        for (node, &component) in self.nodes.iter().zip(&component_of) {
+            components[component].push(node);
+        }
+        components
+    }
+}
+
+impl<N, W> Default for Graph<N, W>
+where
+    N: Clone + Eq + Hash,
+{
+    fn default() -> Self {
+        Graph::new()
+    }
+}
+
+/// Collects edges, as source, destination, and weight.
+impl<N, W> FromIterator<(N, N, W)> for Graph<N, W>
+where
+    N: Clone + Eq + Hash,
+{
+    fn from_iter<I: IntoIterator<Item = (N, N, W)>>(edges: I) -> Self {
+        let mut graph = Graph::new();
+        for (from, to, weight) in edges {
+            graph.add_edge(from, to, weight);
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// a → b → d, a → c → d, d → e, and a cycle e → f → e.
+    fn sample() -> Graph<char> {
+        "ab bd ac cd de ef fe"
+            .split(' ')
+            .map(|edge| {
+                let mut ends = edge.chars();
+                (ends.next().unwrap(), ends.next().unwrap(), ())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn build() {
+        let mut graph: Graph<&str, u32> = Graph::new();
+        assert!(graph.add_node("a"));
+        assert!(!graph.add_node("a"));
+        assert_eq!(None, graph.add_edge("a", "b", 1));
+        assert_eq!(Some(1), graph.add_edge("a", "b", 2));
+        graph.add_edge("c", "b", 3);
+        assert_eq!((3, 2), (graph.len(), graph.edge_count()));
+        assert_eq!(Some(&2), graph.weight(&"a", &"b"));
+        assert_eq!(None, graph.weight(&"b", &"a"));
+        let preds: Vec<_> = graph.predecessors(&"b").collect();
+        assert_eq!(vec![&"a", &"c"], preds);
+        assert_eq!(0, graph.successors(&"z").count());
+        let edges: Vec<_> = graph.edges().map(|(a, b, &w)| (*a, *b, w)).collect();
+        assert_eq!(vec![("a", "b", 2), ("c", "b", 3)], edges);
+    }
+
+    #[test]
+    fn traversal() {
+        let graph = sample();
+        let bfs: Vec<_> = graph.bfs(&'a').into_iter().map(|(&n, d)| (n, d)).collect();
+        let expected = vec![('a', 0), ('b', 1), ('c', 1), ('d', 2), ('e', 3), ('f', 4)];
+        assert_eq!(expected, bfs);
+        let dfs: String = graph.dfs(&'a').into_iter().collect();
+        assert_eq!("abdefc", dfs);
+        assert_eq!("", graph.dfs(&'z').into_iter().collect::<String>());
+    }
+
+    #[test]
+    fn postorder() {
+        let graph = sample();
+        assert_eq!(None, graph.postorder(&'a'));
+        let dag: Graph<char> = graph
+            .edges()
+            .filter(|&(_, &to, _)| to < 'e')
+            .map(|(&from, &to, _)| (from, to, ()))
+            .collect();
+        let order: String = dag.postorder(&'a').unwrap().into_iter().collect();
+        assert_eq!("dbca", order);
+        assert_eq!(Some(vec![]), dag.postorder(&'z'));
+    }
+
+    #[test]
+    fn cycle_through() {
+        let mut graph = sample();
+        assert_eq!(Some(vec![&'f', &'e', &'f']), graph.cycle_through(&'f'));
+        assert_eq!(None, graph.cycle_through(&'a'));
+        graph.add_edge('d', 'a', ());
+        graph.add_edge('d', 'd', ());
+        let cycle: String = graph.cycle_through(&'a').unwrap().into_iter().collect();
+        assert_eq!("abda", cycle);
+        assert_eq!(Some(vec![&'d', &'d']), graph.cycle_through(&'d'));
+        assert_eq!(None, graph.cycle_through(&'z'));
+    }
+
+    #[test]
+    fn reversed() {
+        let reversed = sample().reversed();
+        let nodes: String = reversed.nodes().collect();
+        assert_eq!("abdcef", nodes);
+        let successors: String = reversed.successors(&'d').map(|(&node, _)| node).collect();
+        assert_eq!("bc", successors);
+        assert_eq!(sample().edge_count(), reversed.edge_count());
+    }
+
+    #[test]
+    fn topological_order() {
+        let graph = sample();
+        assert_eq!(None, graph.topological_order());
+        let dag: Graph<char> = vec![('c', 'a', ()), ('b', 'a', ()), ('c', 'd', ())]
+            .into_iter()
+            .collect();
+        let order: String = dag.topological_order().unwrap().into_iter().collect();
+        assert_eq!("cbda", order);
+        let order: String = dag.least_topological_order().unwrap().into_iter().collect();
+        assert_eq!("bcad", order);
+    }
+
+    #[test]
+    fn count_paths() {
+        let graph = sample();
+        assert_eq!(Some(2), graph.count_paths(&'a', &'d'));
+        assert_eq!(Some(1), graph.count_paths(&'b', &'b'));
+        assert_eq!(Some(0), graph.count_paths(&'d', &'a'));
+        assert_eq!(None, graph.count_paths(&'a', &'e'));
+        assert_eq!(Some(0), graph.count_paths(&'a', &'z'));
+        // Paths up a ladder are Fibonacci numbers, soon too many to count.
+        let ladder: Graph<usize> = (0..200)
+            .flat_map(|n| vec![(n, n + 1, ()), (n, n + 2, ())])
+            .collect();
+        assert_eq!(Some(89), ladder.count_paths(&0, &10));
+        assert_eq!(None, ladder.count_paths(&0, &200));
+    }
+
+    #[test]
+    fn components() {
+        let mut graph = sample();
+        graph.add_edge('d', 'a', ());
+        let components: Vec<String> = graph
+            .strongly_connected_components()
+            .into_iter()
+            .map(|component| component.into_iter().collect())
+            .collect();
+        assert_eq!(vec!["abdc", "ef"], components);
+    }
+}
@@ -0,0 +1,155 @@
+//! Shortest paths by total edge weight.
+
+use super::Graph;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+/// An edge weight that paths can add up, such as a distance or a cost.  The
+/// default weight is zero.
+pub trait Weight: Copy + Ord + Default {
+    /// Returns the sum of two weights, or None if it overflows.
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! weight {
+    ($($t:ty)*) => {
+        $(
+            impl Weight for $t {
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+            }
+        )*
+    };
+}
+
+weight!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+impl<N, W> Graph<N, W>
+where
+    N: Clone + Eq + Hash,
+    W: Weight,
+{
+    /// Returns the least total weight of any path from the specified node to
+    /// each node reachable from it, along with the node before it on such a
+    /// path, if it's not the start.  Weights must not be negative.  Paths
+    /// whose total weight overflows are left out.
+    // O((V + E) log V) time, by Dijkstra's algorithm.
+    fn dijkstra(&self, start: usize) -> Vec<Option<(W, Option<usize>)>> {
+        let mut best = vec![None; self.len()];
+        let mut done = vec![false; self.len()];
+        best[start] = Some((W::default(), None));
+        let mut queue = BinaryHeap::from(vec![Reverse((W::default(), start))]);
+        while let Some(Reverse((distance, node))) = queue.pop() {
+            if done[node] {
+                continue;
+            }
+            done[node] = true;
+            for &(next, weight) in &self.outgoing[node] {
+                let through = match distance.checked_add(weight) {
+                    Some(through) => through,
+                    None => continue,
+                };
+                if best[next].is_none_or(|(known, _)| through < known) {
+                    best[next] = Some((through, Some(node)));
+                    queue.push(Reverse((through, next)));
+                }
+            }
+        }
+        best
+    }
+
+    /// Returns the least total weight of any path from the specified node to
+    /// each node reachable from it, including itself, at a distance of zero.
+    /// Weights must not be negative.
+    pub fn distances(&self, from: &N) -> HashMap<&N, W> {
+        let from = match self.indexes.get(from) {
+            Some(&from) => from,
+            None => return HashMap::new(),
+        };
+        let best = self.dijkstra(from);
+        let reached = best.iter().enumerate();
+        reached
+            .filter_map(|(node, best)| best.map(|(distance, _)| (&self.nodes[node], distance)))
+            .collect()
+    }
+
+    /// Returns a path of least total weight from one node to another, as its
+    /// weight and its nodes from first to last, or None if there's no path.
+    /// Weights must not be negative.
+    pub fn shortest_path(&self, from: &N, to: &N) -> Option<(W, Vec<&N>)> {
+        let (from, to) = (*self.indexes.get(from)?, *self.indexes.get(to)?);
+        let best = self.dijkstra(from);
+        let (distance, _) = best[to]?;
+        let mut path = vec![&self.nodes[to]];
+        let mut node = to;
+        while let Some((_, Some(prev))) = best[node] {
+            path.push(&self.nodes[prev]);
+            node = prev;
+        }
+        path.reverse();
+        Some((distance, path))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn roads() -> Graph<&'static str, u32> {
+        vec![
+            ("home", "shop", 7),
+            ("home", "park", 2),
+            ("park", "shop", 3),
+            ("shop", "work", 1),
+            ("park", "work", 9),
+            ("work", "home", 4),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn distances() {
+        let roads = roads();
+        let distances = roads.distances(&"home");
+        assert_eq!(4, distances.len());
+        assert_eq!(0, distances[&"home"]);
+        assert_eq!(5, distances[&"shop"]);
+        assert_eq!(6, distances[&"work"]);
+        assert!(roads.distances(&"mall").is_empty());
+    }
+
+    #[test]
+    fn shortest_path() {
+        let roads = roads();
+        let (distance, path) = roads.shortest_path(&"home", &"work").unwrap();
+        assert_eq!(
+            (6, vec![&"home", &"park", &"shop", &"work"]),
+            (distance, path)
+        );
+        assert_eq!(
+            Some((0, vec![&"park"])),
+            roads.shortest_path(&"park", &"park")
+        );
+        let mut roads = roads;
+        roads.add_node("mall");
+        assert_eq!(None, roads.shortest_path(&"home", &"mall"));
+    }
+
+    #[test]
+    fn overflow() {
+        let roads: Graph<&str, u8> = vec![
+            ("home", "park", 200),
+            ("park", "work", 100),
+            ("home", "shop", 1),
+            ("shop", "mall", 254),
+        ]
+        .into_iter()
+        .collect();
+        let distances = roads.distances(&"home");
+        assert_eq!(None, distances.get(&"work"));
+        assert_eq!(Some(&255), distances.get(&"mall"));
+    }
+}
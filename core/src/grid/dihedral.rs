@@ -0,0 +1,133 @@
+//! Rotations and reflections.  A square has eight symmetries (the dihedral
+//! group of order 8): four rotations, each with or without a mirror flip.
+
+use super::{Grid, Position};
+
+/// One of the eight ways to rotate and flip a grid: an optional mirror flip
+/// across the vertical axis, followed by some number of clockwise quarter
+/// turns.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Transform {
+    pub flip: bool,
+    pub turns: u8,
+}
+
+impl Transform {
+    /// Every transform, starting with the identity.
+    pub const ALL: [Transform; 8] = [
+        Transform::new(false, 0),
+        Transform::new(false, 1),
+        Transform::new(false, 2),
+        Transform::new(false, 3),
+        Transform::new(true, 0),
+        Transform::new(true, 1),
+        Transform::new(true, 2),
+        Transform::new(true, 3),
+    ];
+
+    pub const fn new(flip: bool, turns: u8) -> Transform {
+        Transform {
+            flip,
+            turns: turns % 4,
+        }
+    }
+
+    /// Returns where the cell at the specified position moves, in a grid of
+    /// the specified height and width.
+    fn apply(self, pos: Position, height: usize, width: usize) -> Position {
+        let (mut row, mut column) = (pos.row, pos.column);
+        let (mut height, mut width) = (height, width);
+        if self.flip {
+            column = width - 1 - column;
+        }
+        for _ in 0..self.turns {
+            // A clockwise turn sends the left column to the top row.
+            let turned = (column, height - 1 - row);
+            row = turned.0;
+            column = turned.1;
+            std::mem::swap(&mut height, &mut width);
+        }
+        Position { row, column }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Returns a copy of this grid rotated and flipped by the specified
+    /// transform.
+    pub fn transformed(&self, transform: Transform) -> Grid<T> {
+        let (height, width) = if transform.turns.is_multiple_of(2) {
+            (self.height, self.width)
+        } else {
+            (self.width, self.height)
+        };
+        let mut cells: Vec<Option<T>> = vec![None; self.len()];
+        for (pos, cell) in self.enumerate() {
+            let to = transform.apply(pos, self.height, self.width);
+            cells[to.row * width + to.column] = Some(cell.clone());
+        }
+        Grid {
+            height,
+            width,
+            cells: cells.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Returns every distinct-looking orientation of this grid, in the
+    /// order of [`Transform::ALL`].
+    pub fn orientations(&self) -> impl Iterator<Item = Grid<T>> + '_ {
+        Transform::ALL.iter().map(move |&t| self.transformed(t))
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        self.transformed(Transform::new(false, 1))
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Grid<T> {
+        self.transformed(Transform::new(false, 3))
+    }
+
+    /// Mirrors this grid left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        self.transformed(Transform::new(true, 0))
+    }
+
+    /// Mirrors this grid top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        self.transformed(Transform::new(true, 2))
+    }
+
+    /// Swaps rows with columns, mirroring across the main diagonal.
+    pub fn transpose(&self) -> Grid<T> {
+        self.transformed(Transform::new(true, 3))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test::sample;
+
+    #[test]
+    fn rotations() {
+        let grid = sample();
+        assert_eq!("41\n52\n63\n", grid.rotate_clockwise().to_string());
+        assert_eq!("36\n25\n14\n", grid.rotate_counterclockwise().to_string());
+        assert_eq!(grid, grid.rotate_clockwise().rotate_counterclockwise());
+    }
+
+    #[test]
+    fn reflections() {
+        let grid = sample();
+        assert_eq!("321\n654\n", grid.flip_horizontal().to_string());
+        assert_eq!("456\n123\n", grid.flip_vertical().to_string());
+        assert_eq!("14\n25\n36\n", grid.transpose().to_string());
+    }
+
+    #[test]
+    fn orientations_are_distinct() {
+        let grid = sample();
+        let mut all: Vec<String> = grid.orientations().map(|g| g.to_string()).collect();
+        all.sort();
+        all.dedup();
+        assert_eq!(8, all.len());
+    }
+}
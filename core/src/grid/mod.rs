@@ -0,0 +1,448 @@
+//! A dense, rectangular grid of cells, stored row by row.  Puzzles use grids
+//! for maps, seating charts, and images; this module gives them one
+//! implementation of the bookkeeping: bounds-checked and wrapping indexing,
+//! row and column iteration, neighborhoods, and the rotations and flips that
+//! image puzzles need.
+
+mod dihedral;
+mod view;
+
+pub use dihedral::Transform;
+pub use view::View;
+
+use crate::error::{ParseError, ParseErrorKind};
+use crate::parse;
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+use std::iter;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// The location of a cell in a grid, counting from the top left corner.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Position {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl Position {
+    pub fn new(row: usize, column: usize) -> Position {
+        Position { row, column }
+    }
+}
+
+/// The offsets of the eight cells surrounding any cell, clockwise from the
+/// one above it.
+pub const NEIGHBORS8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// The offsets of the four cells sharing an edge with any cell, clockwise
+/// from the one above it.
+pub const NEIGHBORS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    height: usize,
+    width: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid of the specified size, initializing each cell from
+    /// its position.
+    pub fn from_fn<F>(height: usize, width: usize, mut f: F) -> Grid<T>
+    where
+        F: FnMut(Position) -> T,
+    {
+        let mut cells = Vec::with_capacity(height * width);
+        for row in 0..height {
+            for column in 0..width {
+                cells.push(f(Position { row, column }));
+            }
+        }
+        Grid {
+            height,
+            width,
+            cells,
+        }
+    }
+
+    /// Creates a grid from the specified rows, which must all have the same
+    /// length.  Rows are numbered from 1 in errors, as lines are.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, ParseError> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(index) = rows.iter().position(|row| row.len() != width) {
+            let what = format!(
+                "jagged grid: expected {} columns in row {}",
+                width,
+                index + 1
+            );
+            return Err(ParseError::new(what));
+        }
+        let cells = rows.into_iter().flatten().collect();
+        Ok(Grid {
+            height,
+            width,
+            cells,
+        })
+    }
+
+    /// Parses a grid of characters, converting each with the specified
+    /// function.  Errors are located as by [`parse::grid`].
+    pub fn parse<F>(text: &str, f: F) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(char) -> Result<T, ParseError>,
+    {
+        Grid::from_rows(parse::grid(text, f)?)
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of cells in this grid.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.row < self.height && pos.column < self.width
+    }
+
+    fn index_of(&self, pos: Position) -> usize {
+        pos.row * self.width + pos.column
+    }
+
+    /// Returns the cell at the specified position, or None if the position
+    /// is outside the grid.
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[self.index_of(pos)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        if self.contains(pos) {
+            let index = self.index_of(pos);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the cell at the specified row and column, wrapping around
+    /// the edges as if the grid repeated forever in every direction.  The
+    /// grid must not be empty.
+    pub fn get_wrapping(&self, row: isize, column: isize) -> &T {
+        let row = row.rem_euclid(self.height as isize) as usize;
+        let column = column.rem_euclid(self.width as isize) as usize;
+        &self[Position { row, column }]
+    }
+
+    /// Returns the position the specified offset away from the specified
+    /// position, or None if it would be outside the grid.
+    pub fn offset(&self, pos: Position, (dr, dc): (isize, isize)) -> Option<Position> {
+        let row = pos.row.checked_add_signed(dr)?;
+        let column = pos.column.checked_add_signed(dc)?;
+        Some(Position { row, column }).filter(|&pos| self.contains(pos))
+    }
+
+    /// Returns the positions of the cells at the specified offsets from the
+    /// specified position, skipping any outside the grid.
+    pub fn neighbors<'a>(
+        &'a self,
+        pos: Position,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Position> + 'a {
+        offsets.iter().filter_map(move |&d| self.offset(pos, d))
+    }
+
+    /// Returns the positions of the up to eight cells surrounding the
+    /// specified position.
+    pub fn neighbors8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbors(pos, &NEIGHBORS8)
+    }
+
+    /// Returns the positions of the up to four cells sharing an edge with the
+    /// specified position.
+    pub fn neighbors4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbors(pos, &NEIGHBORS4)
+    }
+
+    /// Returns the positions of the cells in a straight line from the
+    /// specified position, not including it, up to the edge of the grid.
+    pub fn ray(&self, pos: Position, step: (isize, isize)) -> impl Iterator<Item = Position> + '_ {
+        let mut next = self.offset(pos, step);
+        std::iter::from_fn(move || {
+            let pos = next?;
+            next = self.offset(pos, step);
+            Some(pos)
+        })
+    }
+
+    /// Returns every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.len()).map(move |i| Position {
+            row: i / width,
+            column: i % width,
+        })
+    }
+
+    /// Returns the cells of this grid row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Returns each cell along with its position, row by row.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[(row * self.width)..((row + 1) * self.width)]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() panics on a chunk size of zero, and a grid of width zero
+        // has no cells to chunk, so its rows are made up separately.
+        let empty = iter::repeat_with(<&[T]>::default).take(self.empty_rows());
+        self.cells.chunks(self.width.max(1)).chain(empty)
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        let empty = iter::repeat_with(<&mut [T]>::default).take(self.empty_rows());
+        self.cells.chunks_mut(self.width.max(1)).chain(empty)
+    }
+
+    /// Returns the number of rows that have no cells: all of them if the
+    /// grid has width zero, or else none.
+    fn empty_rows(&self) -> usize {
+        if self.width == 0 {
+            self.height
+        } else {
+            0
+        }
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.width, "column {} out of bounds", column);
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |column| self.column(column))
+    }
+
+    /// Returns a new grid of the same size, with each cell mapped by the
+    /// specified function.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            height: self.height,
+            width: self.width,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Returns a view of the rectangle of the specified size whose top left
+    /// cell is at the specified position.  The rectangle must fit within
+    /// this grid.
+    pub fn view(&self, top_left: Position, height: usize, width: usize) -> View<'_, T> {
+        View::new(self, top_left, height, width)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid of the specified size, with every cell set to the
+    /// specified value.
+    pub fn new(height: usize, width: usize, value: T) -> Grid<T> {
+        Grid {
+            height,
+            width,
+            cells: vec![value; height * width],
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        assert!(self.contains(pos), "{:?} out of bounds", pos);
+        &self.cells[self.index_of(pos)]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        assert!(self.contains(pos), "{:?} out of bounds", pos);
+        let index = self.index_of(pos);
+        &mut self.cells[index]
+    }
+}
+
+/// Parses a grid of cells that know how to parse themselves from single
+/// characters.
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char, Error = ParseError>,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, T::try_from)
+    }
+}
+
+/// Writes each row on its own line.  Cells are expected to display as
+/// single characters, so that the output parses back into the same grid.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                cell.fmt(f)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns an error for a character that isn't one of the expected ones,
+/// for use by cell types' `TryFrom<char>` implementations.
+pub fn bad_cell(c: char, expected: &str) -> ParseError {
+    let what = format!("bad cell {:?}: expected any of {}", c, expected);
+    ParseError::with_kind(ParseErrorKind::Unexpected, what)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A cell type for tests: a digit.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub struct Digit(pub u8);
+
+    impl TryFrom<char> for Digit {
+        type Error = ParseError;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c.to_digit(10) {
+                Some(d) => Ok(Digit(d as u8)),
+                None => Err(bad_cell(c, "0123456789")),
+            }
+        }
+    }
+
+    impl Display for Digit {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    pub fn sample() -> Grid<Digit> {
+        "123\n456\n".parse().unwrap()
+    }
+
+    fn digits<'a, I: IntoIterator<Item = &'a Digit>>(cells: I) -> Vec<u8> {
+        cells.into_iter().map(|d| d.0).collect()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = sample();
+        assert_eq!((2, 3), (grid.height(), grid.width()));
+        assert_eq!("123\n456\n", grid.to_string());
+        let err = "12\n3x\n".parse::<Grid<Digit>>().unwrap_err();
+        assert_eq!((Some(2), Some(2)), (err.line(), err.column()));
+        let err = "12\n345\n".parse::<Grid<Digit>>().unwrap_err();
+        assert_eq!(Some(2), err.line());
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+    }
+
+    #[test]
+    fn indexing() {
+        let mut grid = sample();
+        assert_eq!(Digit(6), grid[Position::new(1, 2)]);
+        assert_eq!(None, grid.get(Position::new(2, 0)));
+        assert_eq!(None, grid.get(Position::new(0, 3)));
+        assert_eq!(&Digit(6), grid.get_wrapping(-1, -1));
+        assert_eq!(&Digit(2), grid.get_wrapping(4, 7));
+        grid[Position::new(0, 0)] = Digit(9);
+        *grid.get_mut(Position::new(1, 0)).unwrap() = Digit(8);
+        assert_eq!("923\n856\n", grid.to_string());
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = sample();
+        let rows: Vec<_> = grid.rows().map(digits).collect();
+        assert_eq!(vec![vec![1, 2, 3], vec![4, 5, 6]], rows);
+        let columns: Vec<_> = grid.columns().map(digits).collect();
+        assert_eq!(vec![vec![1, 4], vec![2, 5], vec![3, 6]], columns);
+        assert_eq!(vec![4, 5, 6], digits(grid.row(1)));
+        let positions: Vec<_> = grid.enumerate().map(|(p, _)| p).collect();
+        assert_eq!(Position::new(1, 0), positions[3]);
+    }
+
+    #[test]
+    fn neighborhoods() {
+        let grid = sample();
+        let values = |positions: Vec<Position>| digits(positions.iter().map(|&p| &grid[p]));
+        let corner = Position::new(0, 0);
+        assert_eq!(vec![2, 5, 4], values(grid.neighbors8(corner).collect()));
+        assert_eq!(vec![2, 4], values(grid.neighbors4(corner).collect()));
+        let middle = Position::new(1, 1);
+        assert_eq!(
+            vec![2, 3, 6, 4, 1],
+            values(grid.neighbors8(middle).collect())
+        );
+        assert_eq!(vec![2, 3], values(grid.ray(corner, (0, 1)).collect()));
+        assert_eq!(0, grid.ray(corner, (-1, 0)).count());
+    }
+
+    #[test]
+    fn empty() {
+        let grid: Grid<u8> = Grid::new(0, 0, 0);
+        assert!(grid.is_empty());
+        assert_eq!(0, grid.rows().count());
+        assert_eq!(0, grid.positions().count());
+        let mut grid: Grid<u8> = Grid::from_rows(vec![vec![], vec![]]).unwrap();
+        assert_eq!((2, 0), (grid.height(), grid.width()));
+        assert_eq!(vec![&[] as &[u8]; 2], grid.rows().collect::<Vec<_>>());
+        assert_eq!(2, grid.rows_mut().count());
+        assert_eq!("\n\n", grid.to_string());
+    }
+
+    #[test]
+    fn jagged() {
+        let err = Grid::from_rows(vec![vec![1], vec![2], vec![3, 4]]).unwrap_err();
+        assert!(err.to_string().ends_with("expected 1 columns in row 3"));
+    }
+}
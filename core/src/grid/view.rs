@@ -0,0 +1,106 @@
+use super::{Grid, Position};
+use std::fmt::{self, Display, Formatter};
+
+/// A borrowed rectangle within a grid, indexed relative to its own top left
+/// corner.
+#[derive(Clone, Copy, Debug)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    top_left: Position,
+    height: usize,
+    width: usize,
+}
+
+impl<'a, T> View<'a, T> {
+    pub(super) fn new(grid: &'a Grid<T>, top_left: Position, height: usize, width: usize) -> Self {
+        assert!(
+            top_left.row + height <= grid.height && top_left.column + width <= grid.width,
+            "{}x{} view at {:?} exceeds {}x{} grid",
+            height,
+            width,
+            top_left,
+            grid.height,
+            grid.width
+        );
+        View {
+            grid,
+            top_left,
+            height,
+            width,
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the cell at the specified position relative to this view, or
+    /// None if it's outside the view.
+    pub fn get(&self, pos: Position) -> Option<&'a T> {
+        if pos.row < self.height && pos.column < self.width {
+            let row = self.top_left.row + pos.row;
+            let column = self.top_left.column + pos.column;
+            self.grid.get(Position { row, column })
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &'a [T] {
+        let start = self.top_left.column;
+        &self.grid.row(self.top_left.row + row)[start..(start + self.width)]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.height).map(move |row| self.row(row))
+    }
+}
+
+impl<T: Clone> View<'_, T> {
+    /// Copies this view into a grid of its own.
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid {
+            height: self.height,
+            width: self.width,
+            cells: self.rows().flatten().cloned().collect(),
+        }
+    }
+}
+
+impl<T: Display> Display for View<'_, T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                cell.fmt(f)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test::{sample, Digit};
+    use super::*;
+
+    #[test]
+    fn interior() {
+        let grid = sample();
+        let view = grid.view(Position::new(0, 1), 2, 2);
+        assert_eq!("23\n56\n", view.to_string());
+        assert_eq!(Some(&Digit(5)), view.get(Position::new(1, 0)));
+        assert_eq!(None, view.get(Position::new(0, 2)));
+        assert_eq!("23\n56\n", view.to_grid().to_string());
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_big() {
+        sample().view(Position::new(1, 1), 2, 2);
+    }
+}
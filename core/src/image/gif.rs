@@ -0,0 +1,281 @@
+//! GIF encoding, for animations: each frame gets its own color table, so
+//! frames may use different colors, up to 256 apiece.
+
+use super::{BitWriter, Image, Rgb};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::io::{self, Write};
+
+const MAX_CODES: u16 = 1 << 12;
+
+fn invalid(what: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, what)
+}
+
+/// Writes a looping GIF animation one frame at a time.
+pub struct GifWriter<W: Write> {
+    out: W,
+    width: usize,
+    height: usize,
+    delay: u16, // hundredths of a second per frame
+}
+
+impl<W: Write> GifWriter<W> {
+    /// Writes the header of an animation of the specified size, in pixels,
+    /// that shows each frame for delay hundredths of a second, and loops
+    /// forever.
+    pub fn new(mut out: W, width: usize, height: usize, delay: u16) -> io::Result<Self> {
+        let (w, h) = match (u16::try_from(width), u16::try_from(height)) {
+            (Ok(w), Ok(h)) => (w, h),
+            _ => return Err(invalid(format!("{}x{}: too big for GIF", width, height))),
+        };
+        out.write_all(b"GIF89a")?;
+        out.write_all(&w.to_le_bytes())?;
+        out.write_all(&h.to_le_bytes())?;
+        out.write_all(&[0, 0, 0])?; // no global color table
+                                    // Application extension: loop forever.
+        out.write_all(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\0\0\0")?;
+        Ok(GifWriter {
+            out,
+            width,
+            height,
+            delay,
+        })
+    }
+
+    /// Writes the next frame, which must fit within the animation; smaller
+    /// frames are drawn at the top left.
+    pub fn frame(&mut self, image: &Image) -> io::Result<()> {
+        if image.width() > self.width || image.height() > self.height {
+            let what = format!(
+                "{}x{} frame is bigger than {}x{} animation",
+                image.width(),
+                image.height(),
+                self.width,
+                self.height
+            );
+            return Err(invalid(what));
+        }
+        let (palette, indexes) = index(image.pixels())?;
+        // The color table has 2^bits entries, from 2 to 256.
+        let bits = (1..=8)
+            .find(|&bits| palette.len() <= 1 << bits)
+            .unwrap_or(8);
+        let out = &mut self.out;
+        // Graphic control extension: the delay.
+        out.write_all(b"\x21\xf9\x04\0")?;
+        out.write_all(&self.delay.to_le_bytes())?;
+        out.write_all(b"\0\0")?;
+        // Image descriptor, with a local color table.
+        out.write_all(b"\x2c\0\0\0\0")?;
+        out.write_all(&(image.width() as u16).to_le_bytes())?;
+        out.write_all(&(image.height() as u16).to_le_bytes())?;
+        out.write_all(&[0x80 | (bits - 1)])?;
+        for k in 0..(1 << bits) {
+            let Rgb(r, g, b) = palette.get(k).copied().unwrap_or_default();
+            out.write_all(&[r, g, b])?;
+        }
+        let min_code_size = bits.max(2);
+        out.write_all(&[min_code_size])?;
+        for block in lzw(&indexes, min_code_size).chunks(255) {
+            out.write_all(&[block.len() as u8])?;
+            out.write_all(block)?;
+        }
+        out.write_all(&[0])
+    }
+
+    /// Ends the animation, and returns the writer it was written to.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.out.write_all(b"\x3b")?;
+        Ok(self.out)
+    }
+}
+
+/// Returns the distinct colors of the specified pixels, in order of first
+/// appearance, and the index of each pixel's color.  Fails if there are more
+/// than 256 colors.
+fn index(pixels: &[Rgb]) -> io::Result<(Vec<Rgb>, Vec<u8>)> {
+    let mut palette = Vec::new();
+    let mut indexes_by_color = HashMap::new();
+    let mut indexes = Vec::with_capacity(pixels.len());
+    for &pixel in pixels {
+        let index = match indexes_by_color.get(&pixel) {
+            Some(&index) => index,
+            None if palette.len() < 256 => {
+                let index = palette.len() as u8;
+                indexes_by_color.insert(pixel, index);
+                palette.push(pixel);
+                index
+            }
+            None => return Err(invalid("more than 256 colors in GIF frame".to_owned())),
+        };
+        indexes.push(index);
+    }
+    Ok((palette, indexes))
+}
+
+/// Compresses color indexes by LZW with variable-length codes, as GIF
+/// requires.  Codes grow a bit wider whenever the decoder's table would
+/// outgrow them, and the table starts over once it holds 4096 codes.
+struct Lzw {
+    bits: BitWriter,
+    min_code_size: u8,
+    width: u32, // bits per code
+    table: HashMap<(u16, u8), u16>,
+    next: u16,    // code for the next string added to the table
+    decoded: u16, // next code in the decoder's table, which lags one behind
+    fresh: bool,  // whether the next code will be the first since a clear
+}
+
+impl Lzw {
+    fn new(min_code_size: u8) -> Lzw {
+        let mut lzw = Lzw {
+            bits: BitWriter::default(),
+            min_code_size,
+            width: u32::from(min_code_size) + 1,
+            table: HashMap::new(),
+            next: 0,
+            decoded: 0,
+            fresh: true,
+        };
+        lzw.clear();
+        lzw
+    }
+
+    fn clear_code(&self) -> u16 {
+        1 << self.min_code_size
+    }
+
+    fn clear(&mut self) {
+        self.bits.write(u32::from(self.clear_code()), self.width);
+        self.width = u32::from(self.min_code_size) + 1;
+        self.table.clear();
+        self.next = self.clear_code() + 2;
+        self.decoded = self.next;
+        self.fresh = true;
+    }
+
+    fn emit(&mut self, code: u16) {
+        self.bits.write(u32::from(code), self.width);
+        // Having read a code, the decoder adds a string to its table, unless
+        // the code was the first since a clear.
+        if !self.fresh {
+            self.decoded += 1;
+            if self.decoded == 1 << self.width && self.width < 12 {
+                self.width += 1;
+            }
+        }
+        self.fresh = false;
+    }
+}
+
+fn lzw(indexes: &[u8], min_code_size: u8) -> Vec<u8> {
+    let mut lzw = Lzw::new(min_code_size);
+    let mut indexes = indexes.iter().copied();
+    let mut prefix = match indexes.next() {
+        Some(index) => u16::from(index),
+        None => 0,
+    };
+    for index in indexes {
+        if let Some(&code) = lzw.table.get(&(prefix, index)) {
+            prefix = code;
+            continue;
+        }
+        lzw.emit(prefix);
+        if lzw.next < MAX_CODES {
+            lzw.table.insert((prefix, index), lzw.next);
+            lzw.next += 1;
+        } else {
+            lzw.clear();
+        }
+        prefix = u16::from(index);
+    }
+    lzw.emit(prefix);
+    let end = lzw.clear_code() + 1;
+    lzw.bits.write(u32::from(end), lzw.width);
+    lzw.bits.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Decompresses the output of lzw the way GIF decoders do.
+    fn unlzw(data: &[u8], min_code_size: u8) -> Vec<u8> {
+        let mut pos = 0; // in bits
+        let mut read = |width: u32| {
+            (0..width).fold(0, |code, k| {
+                let bit = (data[pos / 8] >> (pos % 8)) & 1;
+                pos += 1;
+                code | u16::from(bit) << k
+            })
+        };
+        let clear = 1u16 << min_code_size;
+        let mut table: Vec<Vec<u8>> = Vec::new();
+        let mut width = 0;
+        let mut prev: Option<Vec<u8>> = None;
+        let mut out = Vec::new();
+        loop {
+            let code = read(width.max(u32::from(min_code_size) + 1));
+            if code == clear {
+                table = (0..clear).map(|index| vec![index as u8]).collect();
+                table.extend(vec![vec![], vec![]]);
+                width = u32::from(min_code_size) + 1;
+                prev = None;
+                continue;
+            } else if code == clear + 1 {
+                return out;
+            }
+            let string = match (table.get(code as usize), &prev) {
+                (Some(string), _) => string.clone(),
+                (None, Some(prev)) => [&prev[..], &prev[..1]].concat(),
+                (None, None) => panic!("bad first code {}", code),
+            };
+            if let Some(prev) = prev {
+                if table.len() < MAX_CODES as usize {
+                    table.push([&prev[..], &string[..1]].concat());
+                }
+                if table.len() == 1 << width && width < 12 {
+                    width += 1;
+                }
+            }
+            out.extend(&string);
+            prev = Some(string);
+        }
+    }
+
+    #[test]
+    fn lzw_round_trip() {
+        let noise: Vec<u8> = (0..50_000u32).map(|n| (n * n % 9973) as u8).collect();
+        let stripes: Vec<u8> = (0..20_000).map(|n| (n / 7 % 3) as u8).collect();
+        for (indexes, min_code_size) in [(&noise, 8), (&stripes, 2)] {
+            for len in [1, 2, 3, 10, 1000, indexes.len()] {
+                let data = &indexes[..len];
+                assert_eq!(data, &unlzw(&lzw(data, min_code_size), min_code_size)[..]);
+            }
+        }
+    }
+
+    #[test]
+    fn gif() {
+        let mut image = Image::new(3, 2, Rgb::WHITE);
+        image.fill(0, 0, 1, 1, Rgb(1, 2, 3));
+        let mut gif = GifWriter::new(Vec::new(), 3, 2, 10).unwrap();
+        gif.frame(&image).unwrap();
+        assert!(gif.frame(&Image::new(4, 1, Rgb::BLACK)).is_err());
+        let out = gif.finish().unwrap();
+        assert!(out.starts_with(b"GIF89a\x03\0\x02\0"));
+        assert!(out.ends_with(b"\0\x3b"));
+        // Two colors, in order of appearance, padded to a 2-entry table.
+        let table = b"\x2c\0\0\0\0\x03\0\x02\0\x80\x01\x02\x03\xff\xff\xff\x02";
+        assert!(out.windows(table.len()).any(|window| window == table));
+    }
+
+    #[test]
+    fn too_many_colors() {
+        let pixels: Vec<_> = (0..257).map(|n| Rgb(n as u8, (n >> 8) as u8, 0)).collect();
+        let (palette, indexes) = index(&pixels[..256]).unwrap();
+        assert_eq!((256, 255), (palette.len(), indexes[255]));
+        assert!(index(&pixels).is_err());
+    }
+}
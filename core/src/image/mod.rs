@@ -0,0 +1,214 @@
+//! Pictures of puzzle states, for saving to disk: still images as PPM or
+//! PNG, and animations as GIF.  The encoders are written from scratch, so
+//! they support only what puzzles need: 8-bit RGB pixels and, for GIF, at
+//! most 256 colors per frame.
+
+mod gif;
+mod png;
+
+pub use gif::GifWriter;
+
+use crate::grid::Grid;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// A color, as red, green, and blue intensities.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+}
+
+/// A file format for images.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    Ppm,
+    Png,
+    Gif,
+}
+
+impl Format {
+    /// Returns the format named by the extension of the specified path, if
+    /// any: ".ppm", ".png", or ".gif".
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Format> {
+        let extension = path.as_ref().extension()?.to_str()?;
+        match extension.to_ascii_lowercase().as_str() {
+            "ppm" => Some(Format::Ppm),
+            "png" => Some(Format::Png),
+            "gif" => Some(Format::Gif),
+            _ => None,
+        }
+    }
+
+    /// Returns whether files of this format can hold more than one frame.
+    pub fn is_animated(self) -> bool {
+        self == Format::Gif
+    }
+}
+
+/// A rectangle of pixels, stored row by row from the top left.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Returns an image of the specified size, filled with one color.
+    pub fn new(width: usize, height: usize, background: Rgb) -> Image {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// Returns a picture of the specified grid, drawing each cell as a square
+    /// scale pixels on a side, in the color returned for it by color.
+    pub fn from_grid<T, F>(grid: &Grid<T>, scale: usize, mut color: F) -> Image
+    where
+        F: FnMut(&T) -> Rgb,
+    {
+        let mut image = Image::new(grid.width() * scale, grid.height() * scale, Rgb::BLACK);
+        for (pos, cell) in grid.enumerate() {
+            let color = color(cell);
+            image.fill(pos.column * scale, pos.row * scale, scale, scale, color);
+        }
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the color of the pixel x pixels from the left edge and y from
+    /// the top, if it's in the image.
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        if x < self.width && y < self.height {
+            Some(self.pixels[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Colors the pixels of the specified rectangle, or of as much of it as
+    /// lies within the image.
+    pub fn fill(&mut self, x: usize, y: usize, width: usize, height: usize, color: Rgb) {
+        let right = (x + width).min(self.width);
+        for row in y..(y + height).min(self.height) {
+            let start = row * self.width;
+            for pixel in &mut self.pixels[(start + x.min(right))..(start + right)] {
+                *pixel = color;
+            }
+        }
+    }
+
+    pub fn pixels(&self) -> &[Rgb] {
+        &self.pixels
+    }
+
+    /// Returns the rows of pixels, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[Rgb]> {
+        self.pixels.chunks(self.width.max(1))
+    }
+
+    /// Writes this image in binary PPM format: a short text header followed
+    /// by the raw RGB bytes.
+    pub fn write_ppm<W: Write>(&self, mut out: W) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        let bytes: Vec<u8> = self.pixels.iter().flat_map(|p| [p.0, p.1, p.2]).collect();
+        out.write_all(&bytes)
+    }
+
+    /// Writes this image in PNG format, as 8-bit RGB.
+    pub fn write_png<W: Write>(&self, out: W) -> io::Result<()> {
+        png::write(self, out)
+    }
+}
+
+/// Packs codes of any length up to 32 bits into bytes, least significant bit
+/// first, as both DEFLATE and GIF's LZW do.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u64,
+    len: u32, // bits in buffer
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u32, len: u32) {
+        self.buffer |= u64::from(code) << self.len;
+        self.len += len;
+        while self.len >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.len -= 8;
+        }
+    }
+
+    /// Returns the bytes written, padding the last one with zero bits.
+    fn finish(mut self) -> Vec<u8> {
+        if self.len > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bits() {
+        let mut bits = BitWriter::default();
+        bits.write(0b101, 3);
+        bits.write(0b11111, 5);
+        bits.write(0x1ff, 9);
+        assert_eq!(vec![0b1111_1101, 0xff, 0x01], bits.finish());
+    }
+
+    #[test]
+    fn from_grid() {
+        let grid = Grid::parse("#.\n..", |c| Ok(c == '#')).unwrap();
+        let image = Image::from_grid(&grid, 2, |&on| if on { Rgb::WHITE } else { Rgb::BLACK });
+        assert_eq!((4, 4), (image.width(), image.height()));
+        assert_eq!(Some(Rgb::WHITE), image.get(1, 1));
+        assert_eq!(Some(Rgb::BLACK), image.get(2, 1));
+        assert_eq!(None, image.get(4, 0));
+    }
+
+    #[test]
+    fn fill_clips() {
+        let mut image = Image::new(3, 2, Rgb::BLACK);
+        image.fill(2, 1, 5, 5, Rgb::WHITE);
+        image.fill(7, 0, 1, 1, Rgb::WHITE);
+        let lit: Vec<_> = image.pixels().iter().map(|&p| p == Rgb::WHITE).collect();
+        assert_eq!(vec![false, false, false, false, false, true], lit);
+    }
+
+    #[test]
+    fn ppm() {
+        let mut image = Image::new(2, 1, Rgb(1, 2, 3));
+        image.fill(1, 0, 1, 1, Rgb::WHITE);
+        let mut out = Vec::new();
+        image.write_ppm(&mut out).unwrap();
+        assert_eq!(b"P6\n2 1\n255\n\x01\x02\x03\xff\xff\xff", &out[..]);
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(Some(Format::Png), Format::from_path("out/seats.PNG"));
+        assert_eq!(Some(Format::Gif), Format::from_path("floor.gif"));
+        assert_eq!(None, Format::from_path("floor.jpg"));
+        assert_eq!(None, Format::from_path("floor"));
+        assert!(Format::Gif.is_animated() && !Format::Ppm.is_animated());
+    }
+}
@@ -0,0 +1,310 @@
+//! PNG encoding: the image's rows, compressed by DEFLATE with fixed Huffman
+//! codes, which suits pictures of grids, being mostly long runs of repeated
+//! bytes, almost as well as the custom codes general-purpose encoders build.
+
+use super::{BitWriter, Image};
+use std::io::{self, Write};
+
+const SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+const WINDOW: usize = 32 * 1024; // farthest back a match may start
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+const MAX_CHAIN: usize = 64; // earlier matches tried at each position
+const HASH_BITS: u32 = 15;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+pub fn write<W: Write>(image: &Image, mut out: W) -> io::Result<()> {
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(image.width() as u32).to_be_bytes());
+    header.extend_from_slice(&(image.height() as u32).to_be_bytes());
+    // 8 bits per sample, truecolor, no interlacing
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+    let mut raw = Vec::with_capacity(image.height() * (1 + 3 * image.width()));
+    for row in image.rows().take(image.height()) {
+        raw.push(0); // filter type: none
+        raw.extend(row.iter().flat_map(|p| [p.0, p.1, p.2]));
+    }
+    out.write_all(SIGNATURE)?;
+    write_chunk(&mut out, b"IHDR", &header)?;
+    write_chunk(&mut out, b"IDAT", &zlib(&raw))?;
+    write_chunk(&mut out, b"IEND", &[])
+}
+
+fn write_chunk<W: Write>(out: &mut W, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+    let crc = crc32(kind.iter().chain(data));
+    out.write_all(&crc.to_be_bytes())
+}
+
+/// Returns the CRC-32 checksum of the specified bytes, as used by PNG, zip,
+/// and gzip.
+fn crc32<'a, I: IntoIterator<Item = &'a u8>>(bytes: I) -> u32 {
+    let mut table = [0u32; 256];
+    for (n, entry) in table.iter_mut().enumerate() {
+        *entry = (0..8).fold(n as u32, |c, _| {
+            if c & 1 == 1 {
+                0xedb8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            }
+        });
+    }
+    let crc = bytes.into_iter().fold(!0u32, |crc, &byte| {
+        table[((crc ^ u32::from(byte)) & 0xff) as usize] ^ (crc >> 8)
+    });
+    !crc
+}
+
+/// Returns the Adler-32 checksum of the specified bytes, as used by zlib.
+fn adler32(bytes: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    let (mut a, mut b) = (1u32, 0u32);
+    // 5552 is the most bytes that can be summed before b could overflow.
+    for chunk in bytes.chunks(5552) {
+        for &byte in chunk {
+            a += u32::from(byte);
+            b += a;
+        }
+        a %= MOD;
+        b %= MOD;
+    }
+    (b << 16) | a
+}
+
+/// Returns the specified bytes compressed in zlib format.
+fn zlib(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01]; // DEFLATE, 32K window, fastest
+    out.extend(deflate(data));
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+/// Returns the specified bytes compressed as a single DEFLATE block with
+/// fixed Huffman codes, replacing repeated strings by back-references.
+// O(n·MAX_CHAIN·MAX_MATCH) time, though far less for typical images
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut bits = BitWriter::default();
+    bits.write(0b011, 3); // final block, fixed codes
+    let mut chains = Chains::new(data);
+    let mut i = 0;
+    while i < data.len() {
+        let (len, distance) = chains.longest_match(i);
+        if len >= MIN_MATCH {
+            write_length(&mut bits, len);
+            write_distance(&mut bits, distance);
+            (i..(i + len)).for_each(|j| chains.insert(j));
+            i += len;
+        } else {
+            write_symbol(&mut bits, u16::from(data[i]));
+            chains.insert(i);
+            i += 1;
+        }
+    }
+    write_symbol(&mut bits, 256); // end of block
+    bits.finish()
+}
+
+/// The positions seen so far in some data, chained together by a hash of
+/// the three bytes starting at each, so that strings can be matched against
+/// earlier ones without comparing every pair of positions.
+struct Chains<'a> {
+    data: &'a [u8],
+    head: Vec<usize>, // latest position with each hash
+    prev: Vec<usize>, // next latest position with the same hash
+}
+
+impl<'a> Chains<'a> {
+    fn new(data: &'a [u8]) -> Chains<'a> {
+        Chains {
+            data,
+            head: vec![usize::MAX; 1 << HASH_BITS],
+            prev: vec![usize::MAX; data.len()],
+        }
+    }
+
+    fn hash(&self, i: usize) -> usize {
+        let key = u32::from_le_bytes([self.data[i], self.data[i + 1], self.data[i + 2], 0]);
+        (key.wrapping_mul(0x9e37_79b1) >> (32 - HASH_BITS)) as usize
+    }
+
+    fn insert(&mut self, i: usize) {
+        if i + MIN_MATCH <= self.data.len() {
+            let hash = self.hash(i);
+            self.prev[i] = self.head[hash];
+            self.head[hash] = i;
+        }
+    }
+
+    /// Returns the length and distance of the longest string starting at
+    /// the specified position that also starts at an earlier one within the
+    /// window, or a length of 0 if there is none.
+    fn longest_match(&self, i: usize) -> (usize, usize) {
+        let data = self.data;
+        let (mut best_len, mut best_distance) = (0, 0);
+        if i + MIN_MATCH > data.len() {
+            return (best_len, best_distance);
+        }
+        let limit = (data.len() - i).min(MAX_MATCH);
+        let mut candidate = self.head[self.hash(i)];
+        for _ in 0..MAX_CHAIN {
+            if candidate == usize::MAX || i - candidate > WINDOW {
+                break;
+            }
+            let len = (0..limit)
+                .take_while(|&k| data[candidate + k] == data[i + k])
+                .count();
+            if len > best_len {
+                best_len = len;
+                best_distance = i - candidate;
+                if len == limit {
+                    break;
+                }
+            }
+            candidate = self.prev[candidate];
+        }
+        (best_len, best_distance)
+    }
+}
+
+/// Writes the fixed Huffman code for the specified literal/length symbol.
+fn write_symbol(bits: &mut BitWriter, symbol: u16) {
+    let (code, len) = match symbol {
+        0..=143 => (0x30 + symbol, 8),
+        144..=255 => (0x190 + symbol - 144, 9),
+        256..=279 => (symbol - 256, 7),
+        _ => (0xc0 + symbol - 280, 8),
+    };
+    // Huffman codes are packed most significant bit first.
+    bits.write(reverse(u32::from(code), len), len);
+}
+
+fn write_length(bits: &mut BitWriter, len: usize) {
+    let index = LENGTH_BASE.iter().rposition(|&base| base as usize <= len);
+    let index = index.expect("match lengths are at least 3");
+    write_symbol(bits, 257 + index as u16);
+    let extra = len as u32 - u32::from(LENGTH_BASE[index]);
+    bits.write(extra, u32::from(LENGTH_EXTRA[index]));
+}
+
+fn write_distance(bits: &mut BitWriter, distance: usize) {
+    let index = DISTANCE_BASE
+        .iter()
+        .rposition(|&base| base as usize <= distance);
+    let index = index.expect("distances are at least 1");
+    bits.write(reverse(index as u32, 5), 5);
+    let extra = distance as u32 - u32::from(DISTANCE_BASE[index]);
+    bits.write(extra, u32::from(DISTANCE_EXTRA[index]));
+}
+
+/// Returns the low len bits of code in reverse order.
+fn reverse(code: u32, len: u32) -> u32 {
+    code.reverse_bits() >> (32 - len)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::image::Rgb;
+
+    /// Decompresses the output of deflate, which uses only fixed codes.
+    fn inflate(data: &[u8]) -> Vec<u8> {
+        let mut pos = 0; // in bits
+        let mut read = |len: u32| {
+            (0..len).fold(0, |value, k| {
+                let bit = (data[pos / 8] >> (pos % 8)) & 1;
+                pos += 1;
+                value | u32::from(bit) << k
+            })
+        };
+        assert_eq!(0b011, read(3));
+        let mut out = Vec::new();
+        loop {
+            // Read a Huffman code bit by bit, most significant first.
+            let mut code = 0;
+            let mut len = 0;
+            let symbol = loop {
+                code = code << 1 | read(1);
+                len += 1;
+                match (len, code) {
+                    (7, 0..=0x17) => break code + 256,
+                    (8, 0x30..=0xbf) => break code - 0x30,
+                    (8, 0xc0..=0xc7) => break code - 0xc0 + 280,
+                    (9, 0x190..=0x1ff) => break code - 0x190 + 144,
+                    _ => assert!(len < 9),
+                }
+            };
+            match symbol {
+                0..=255 => out.push(symbol as u8),
+                256 => return out,
+                _ => {
+                    let index = symbol as usize - 257;
+                    let extra = read(u32::from(LENGTH_EXTRA[index]));
+                    let len = (u32::from(LENGTH_BASE[index]) + extra) as usize;
+                    let index = reverse(read(5), 5) as usize;
+                    let extra = read(u32::from(DISTANCE_EXTRA[index]));
+                    let distance = (u32::from(DISTANCE_BASE[index]) + extra) as usize;
+                    for _ in 0..len {
+                        out.push(out[out.len() - distance]);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(0xcbf4_3926, crc32(b"123456789"));
+        assert_eq!(0x11e6_0398, adler32(b"Wikipedia"));
+        assert_eq!(0x0000_0001, adler32(b""));
+    }
+
+    #[test]
+    fn deflate_round_trip() {
+        let mut data = b"abcabcabcabcabcabc, xyz; abcabc".to_vec();
+        data.extend((0..5000).map(|n| (n % 7 * n % 251) as u8));
+        data.extend(vec![0x42; 1000]);
+        for len in [0, 1, 2, 3, 4, 20, data.len()] {
+            assert_eq!(&data[..len], &inflate(&deflate(&data[..len]))[..]);
+        }
+        let compressed = deflate(&data[data.len() - 1000..]);
+        assert!(compressed.len() < 20, "{} bytes", compressed.len());
+    }
+
+    #[test]
+    fn png() {
+        let mut image = Image::new(3, 2, Rgb::BLACK);
+        image.fill(1, 1, 1, 1, Rgb(10, 20, 30));
+        let mut out = Vec::new();
+        write(&image, &mut out).unwrap();
+        assert!(out.starts_with(SIGNATURE));
+        assert_eq!(b"\0\0\0\x0dIHDR\0\0\0\x03\0\0\0\x02\x08\x02", &out[8..26]);
+        assert!(out.ends_with(b"\0\0\0\0IEND\xae\x42\x60\x82"));
+        let idat = &out[33..];
+        let len = u32::from_be_bytes([idat[0], idat[1], idat[2], idat[3]]) as usize;
+        assert_eq!(b"IDAT", &idat[4..8]);
+        let zlib = &idat[8..(8 + len)];
+        let raw = inflate(&zlib[2..(zlib.len() - 4)]);
+        let want = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 20, 30, 0, 0, 0,
+        ];
+        assert_eq!(&want[..], &raw[..]);
+    }
+}
@@ -0,0 +1,21 @@
+//! Code shared by every year's solutions: errors, input parsing, the
+//! [`Solution`] interface, and the tools that run and check solutions.
+
+pub mod animate;
+pub mod answers;
+pub mod automaton;
+pub mod bench;
+pub mod cycle;
+pub mod driver;
+pub mod error;
+pub mod graph;
+pub mod grid;
+pub mod image;
+pub mod parse;
+pub mod registry;
+pub mod solution;
+pub mod space;
+pub mod table;
+
+pub use error::Error;
+pub use solution::{Part, Puzzle, Solution};
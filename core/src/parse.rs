@@ -0,0 +1,235 @@
+//! Parsers for the shapes puzzle input tends to take: one value per line,
+//! paragraphs separated by blank lines, `MIN-MAX` ranges, and rectangular
+//! grids of characters.  Errors are located at the line, and where possible
+//! the column, that caused them.
+
+use crate::error::{ParseError, ParseErrorKind};
+use std::num::ParseIntError;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// Parses each line of the specified text with the specified function.
+/// Errors are located on the line that caused them.
+pub fn lines<T, F>(text: &str, mut f: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| f(line).map_err(|err| err.on_line(index + 1, line)))
+        .collect()
+}
+
+/// Parses an integer, ignoring surrounding whitespace.  Errors span the
+/// whole of the specified text.
+pub fn integer<T>(s: &str) -> Result<T, ParseError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    let trimmed = s.trim();
+    trimmed
+        .parse()
+        .map_err(|err| ParseError::from(err).within(s, trimmed))
+}
+
+/// Parses a file holding one integer per line.
+pub fn integers<T>(text: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    lines(text, integer)
+}
+
+/// Parses an inclusive range written as `MIN-MAX`, such as `1-3`.
+pub fn range<T>(s: &str) -> Result<RangeInclusive<T>, ParseError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    // Skip the first character, which may be a minus sign.
+    let dash = s.char_indices().skip(1).find(|&(_, c)| c == '-');
+    let (min, max) = match dash {
+        Some((i, _)) => (&s[..i], &s[(i + 1)..]),
+        None => {
+            let what = "bad range: expected MIN-MAX";
+            let err = ParseError::with_kind(ParseErrorKind::Unexpected, what);
+            return Err(err.with_span(0..s.len()));
+        }
+    };
+    let bound = |part| integer(part).map_err(|err| err.within(s, part));
+    Ok(bound(min)?..=bound(max)?)
+}
+
+/// A run of consecutive non-blank lines.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Paragraph<'a> {
+    /// The number, counting from 1, of this paragraph's first line.
+    pub first_line: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Paragraph<'a> {
+    /// Locates the specified error on the line at the specified index within
+    /// this paragraph.
+    pub fn locate(&self, index: usize, err: ParseError) -> ParseError {
+        match self.lines.get(index) {
+            Some(line) => err.on_line(self.first_line + index, line),
+            None => err,
+        }
+    }
+
+    /// Splits off this paragraph's first line, which is often a heading such
+    /// as `Player 1:`, and returns it along with the remaining lines.
+    pub fn split_heading(&self) -> (&'a str, Paragraph<'a>) {
+        let heading = self.lines.first().copied().unwrap_or_default();
+        let body = Paragraph {
+            first_line: self.first_line + 1,
+            lines: self.lines.get(1..).unwrap_or_default().to_vec(),
+        };
+        (heading, body)
+    }
+
+    /// Parses this paragraph as a grid of characters, as [`grid`] does.
+    pub fn grid<T, F>(&self, f: F) -> Result<Vec<Vec<T>>, ParseError>
+    where
+        F: FnMut(char) -> Result<T, ParseError>,
+    {
+        grid_rows(&self.lines, self.first_line, f)
+    }
+
+    /// Parses each line of this paragraph with the specified function,
+    /// locating errors as [`lines`] does.
+    pub fn parse_lines<T, F>(&self, mut f: F) -> Result<Vec<T>, ParseError>
+    where
+        F: FnMut(&str) -> Result<T, ParseError>,
+    {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| f(line).map_err(|err| self.locate(index, err)))
+            .collect()
+    }
+}
+
+/// Splits the specified text into paragraphs separated by blank lines.
+/// Lines holding only whitespace count as blank.
+pub fn paragraphs(text: &str) -> Vec<Paragraph<'_>> {
+    let mut paragraphs = Vec::new();
+    let mut current: Option<Paragraph> = None;
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            paragraphs.extend(current.take());
+        } else {
+            current
+                .get_or_insert_with(|| Paragraph {
+                    first_line: index + 1,
+                    lines: Vec::new(),
+                })
+                .lines
+                .push(line);
+        }
+    }
+    paragraphs.extend(current);
+    paragraphs
+}
+
+/// Parses a rectangular grid of characters, converting each with the
+/// specified function, and returns its rows from top to bottom.  Trailing
+/// blank lines are ignored.  Empty grids and rows of different lengths are
+/// errors, as is any error from the function, which is located at the
+/// offending character.
+pub fn grid<T, F>(text: &str, f: F) -> Result<Vec<Vec<T>>, ParseError>
+where
+    F: FnMut(char) -> Result<T, ParseError>,
+{
+    let mut rows: Vec<&str> = text.lines().collect();
+    while rows.last().is_some_and(|row| row.trim().is_empty()) {
+        rows.pop();
+    }
+    grid_rows(&rows, 1, f)
+}
+
+/// Parses the specified rows as for [`grid`], numbering lines from
+/// `first_line`.
+fn grid_rows<T, F>(rows: &[&str], first_line: usize, mut f: F) -> Result<Vec<Vec<T>>, ParseError>
+where
+    F: FnMut(char) -> Result<T, ParseError>,
+{
+    let width = match rows.first() {
+        Some(row) => row.chars().count(),
+        None => return Err(ParseError::with_kind(ParseErrorKind::Empty, "empty grid")),
+    };
+    let mut grid = Vec::with_capacity(rows.len());
+    for (index, &row) in rows.iter().enumerate() {
+        let locate = |err: ParseError| err.on_line(first_line + index, row);
+        let cells = row
+            .char_indices()
+            .map(|(i, c)| f(c).map_err(|err| locate(err.with_span(i..(i + c.len_utf8())))))
+            .collect::<Result<Vec<T>, _>>()?;
+        if cells.len() != width {
+            let what = format!("jagged grid: expected {} columns", width);
+            return Err(locate(ParseError::new(what).with_span(0..row.len())));
+        }
+        grid.push(cells);
+    }
+    Ok(grid)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_integers() {
+        assert_eq!(vec![1, -2, 3], integers::<i32>("1\n -2\n3\n").unwrap());
+        let err = integers::<u32>("1\n2x\n").unwrap_err();
+        assert_eq!(ParseErrorKind::Number, err.kind());
+        assert_eq!((Some(2), Some(1)), (err.line(), err.column()));
+    }
+
+    #[test]
+    fn parse_range() {
+        assert_eq!(1..=3, range::<u32>("1-3").unwrap());
+        assert_eq!(-5..=-2, range::<i32>("-5--2").unwrap());
+        assert_eq!(Some(2..3), range::<u32>("1-x").unwrap_err().span());
+        assert!(range::<u32>("13").is_err());
+    }
+
+    #[test]
+    fn parse_paragraphs() {
+        let text = "a\nb\n\n\nc\n  \nd\n";
+        let paragraphs = paragraphs(text);
+        assert_eq!(3, paragraphs.len());
+        assert_eq!(
+            (1, vec!["a", "b"]),
+            (paragraphs[0].first_line, paragraphs[0].lines.clone())
+        );
+        assert_eq!(
+            (5, vec!["c"]),
+            (paragraphs[1].first_line, paragraphs[1].lines.clone())
+        );
+        assert_eq!(
+            (7, vec!["d"]),
+            (paragraphs[2].first_line, paragraphs[2].lines.clone())
+        );
+        let err = paragraphs[2].parse_lines(integer::<u8>).unwrap_err();
+        assert_eq!(Some(7), err.line());
+        let (heading, body) = paragraphs[0].split_heading();
+        assert_eq!(("a", 2, vec!["b"]), (heading, body.first_line, body.lines));
+    }
+
+    #[test]
+    fn parse_grid() {
+        let bit = |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ParseError::new("bad cell")),
+        };
+        let rows = grid("#.\n.#\n\n", bit).unwrap();
+        assert_eq!(vec![vec![true, false], vec![false, true]], rows);
+        let err = grid("#.\n.x\n", bit).unwrap_err();
+        assert_eq!((Some(2), Some(2)), (err.line(), err.column()));
+        let err = grid("#.\n.\n", bit).unwrap_err();
+        assert_eq!(Some(2), err.line());
+        assert_eq!(ParseErrorKind::Empty, grid("\n", bit).unwrap_err().kind());
+    }
+}
@@ -0,0 +1,53 @@
+//! Registration of puzzles, keyed by year and day.  Each year's crate keeps
+//! a static list of [`Entry`] values, built with the [`entry!`] macro so that
+//! every puzzle knows where its crate's test files live.
+
+use crate::solution::Puzzle;
+use std::path::{Path, PathBuf};
+
+/// A registered puzzle.
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub puzzle: &'static dyn Puzzle,
+    /// The manifest directory of the crate that registered this puzzle.
+    pub root: &'static str,
+}
+
+impl Entry {
+    /// Returns the directory holding this puzzle's input and sample files.
+    pub fn test_dir(&self) -> PathBuf {
+        Path::new(self.root)
+            .join("tests")
+            .join(format!("day{}", self.day))
+    }
+
+    /// Returns the path of this puzzle's full input file.
+    pub fn input_path(&self) -> PathBuf {
+        self.test_dir().join("input")
+    }
+}
+
+/// Makes an [`Entry`] for the specified year, day, and puzzle, rooted in the
+/// manifest directory of the crate invoking the macro.
+#[macro_export]
+macro_rules! entry {
+    ($year:expr, $day:expr, $puzzle:expr) => {
+        $crate::registry::Entry {
+            year: $year,
+            day: $day,
+            puzzle: &$puzzle,
+            root: env!("CARGO_MANIFEST_DIR"),
+        }
+    };
+}
+
+/// Returns the entry for the specified year and day, if any.
+pub fn find<'a, I>(entries: I, year: u16, day: u8) -> Option<&'a Entry>
+where
+    I: IntoIterator<Item = &'a Entry>,
+{
+    entries
+        .into_iter()
+        .find(|entry| entry.year == year && entry.day == day)
+}
@@ -0,0 +1,163 @@
+//! A common interface to every day's puzzle.  Each day implements
+//! [`Solution`], whose associated types say exactly what the day parses and
+//! what each part produces.  Tools that don't care about those types (such
+//! as a command-line driver or a test runner) use the type-erased [`Puzzle`]
+//! interface instead, which every `Solution` implements automatically.
+
+use crate::error::Error;
+use crate::error::ParseError;
+use std::any::Any;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// A puzzle solution, split into separately callable steps.  The parse step
+/// turns puzzle input text into whatever representation both parts share.
+pub trait Solution {
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    /// The parts this puzzle has.  Every day has two, except day 25.
+    const PARTS: &'static [Part] = &[Part::One, Part::Two];
+
+    fn parse(&self, text: &str) -> Result<Self::Input, Error>;
+    fn part1(&self, input: &Self::Input) -> Result<Self::Output1, Error>;
+    fn part2(&self, input: &Self::Input) -> Result<Self::Output2, Error>;
+}
+
+/// Either half of a day's puzzle.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn all() -> [Part; 2] {
+        [Part::One, Part::Two]
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(ParseError::new(format!("{}: expected part 1 or 2", s))),
+        }
+    }
+}
+
+/// Parsed input of unknown type, as returned by [`Puzzle::parse`].
+pub type Parsed = Box<dyn Any>;
+
+/// Type-erased [`Solution`].  Answers are rendered as strings, and parsed
+/// input is passed around as a [`Parsed`] box.
+pub trait Puzzle: Sync {
+    fn parts(&self) -> &'static [Part];
+    fn parse(&self, text: &str) -> Result<Parsed, Error>;
+    fn part1(&self, input: &Parsed) -> Result<String, Error>;
+    fn part2(&self, input: &Parsed) -> Result<String, Error>;
+
+    fn part(&self, part: Part, input: &Parsed) -> Result<String, Error> {
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+
+    /// Parses the specified text and solves the specified part of it.
+    fn solve(&self, part: Part, text: &str) -> Result<String, Error> {
+        self.part(part, &self.parse(text)?)
+    }
+}
+
+fn downcast<T: 'static>(input: &Parsed) -> Result<&T, Error> {
+    input
+        .downcast_ref()
+        .ok_or_else(|| Error::internal("input was parsed by a different puzzle"))
+}
+
+impl<S> Puzzle for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn parts(&self) -> &'static [Part] {
+        S::PARTS
+    }
+
+    fn parse(&self, text: &str) -> Result<Parsed, Error> {
+        Ok(Box::new(Solution::parse(self, text)?))
+    }
+
+    fn part1(&self, input: &Parsed) -> Result<String, Error> {
+        Ok(Solution::part1(self, downcast(input)?)?.to_string())
+    }
+
+    fn part2(&self, input: &Parsed) -> Result<String, Error> {
+        Ok(Solution::part2(self, downcast(input)?)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i32>;
+        type Output1 = i32;
+        type Output2 = usize;
+
+        fn parse(&self, text: &str) -> Result<Self::Input, Error> {
+            Ok(text
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn part1(&self, input: &Self::Input) -> Result<Self::Output1, Error> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(&self, input: &Self::Input) -> Result<Self::Output2, Error> {
+            Ok(input.len())
+        }
+    }
+
+    #[test]
+    fn solve() {
+        let puzzle: &dyn Puzzle = &Sum;
+        assert_eq!("6", puzzle.solve(Part::One, "1 2 3").unwrap());
+        assert_eq!("3", puzzle.solve(Part::Two, "1 2 3").unwrap());
+        let err = puzzle.solve(Part::One, "1 two 3").unwrap_err();
+        assert!(matches!(err, Error::Parse(_)));
+    }
+
+    #[test]
+    fn parse_part() {
+        assert_eq!(Part::One, "1".parse().unwrap());
+        assert_eq!(Part::Two, "2".parse().unwrap());
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn foreign_input() {
+        let parsed: Parsed = Box::new("not a Vec<i32>");
+        let err = Puzzle::part1(&Sum, &parsed).unwrap_err();
+        assert!(matches!(err, Error::Internal(_)));
+    }
+}
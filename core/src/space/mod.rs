@@ -0,0 +1,254 @@
+//! Unbounded N-dimensional space, for automata whose cells may spread in any
+//! direction.  A [`SparseGrid`] stores only the points that are active, so
+//! the same code handles a plane, Conway Cubes' 3 and 4 dimensions, or more.
+//! Stepping a sparse grid from one generation to the next is the job of
+//! [`crate::automaton`].
+
+mod point;
+
+pub use point::{Neighbors, Point};
+
+use crate::grid::Grid;
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+use std::iter::FromIterator;
+
+/// An infinite set of cells in N-space, each either active or inactive.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SparseGrid<const N: usize> {
+    active: HashSet<Point<N>>,
+}
+
+impl<const N: usize> SparseGrid<N> {
+    pub fn new() -> SparseGrid<N> {
+        SparseGrid {
+            active: HashSet::new(),
+        }
+    }
+
+    /// Returns a grid whose active cells are those of the specified plane for
+    /// which is_active returns true, placed in the plane through the origin
+    /// spanned by the first two axes: columns along the first axis, and rows
+    /// along the second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if N is less than 2.
+    pub fn from_plane<T, F>(plane: &Grid<T>, mut is_active: F) -> SparseGrid<N>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        plane
+            .enumerate()
+            .filter(|(_, cell)| is_active(cell))
+            .map(|(pos, _)| Point::on_plane(pos.column as isize, pos.row as isize))
+            .collect()
+    }
+
+    pub fn contains(&self, point: Point<N>) -> bool {
+        self.active.contains(&point)
+    }
+
+    /// Activates the specified point, returning true if it was inactive.
+    pub fn insert(&mut self, point: Point<N>) -> bool {
+        self.active.insert(point)
+    }
+
+    /// Deactivates the specified point, returning true if it was active.
+    pub fn remove(&mut self, point: Point<N>) -> bool {
+        self.active.remove(&point)
+    }
+
+    /// Returns the number of active cells.
+    pub fn len(&self) -> usize {
+        self.active.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    /// Returns the active points, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = Point<N>> + '_ {
+        self.active.iter().copied()
+    }
+
+    /// Returns the least and greatest corners of the smallest box containing
+    /// every active cell, or None if no cell is active.
+    pub fn bounds(&self) -> Option<(Point<N>, Point<N>)> {
+        let mut points = self.iter();
+        let first = points.next()?;
+        Some(points.fold((first, first), |(min, max), p| (min.min(p), max.max(p))))
+    }
+
+    /// Returns the number of active cells adjacent to the specified point.
+    pub fn count_neighbors(&self, point: Point<N>) -> usize {
+        point.neighbors().filter(|&p| self.contains(p)).count()
+    }
+
+    /// Lays out the 2D slices of the box between the specified corners in a
+    /// plane, as for a picture: slices along the first two axes, like those
+    /// of [`from_plane`], are placed left to right by their third coordinate
+    /// and top to bottom by the rest, with the fourth axis varying fastest.
+    /// Cells of the result are whether each cell is active, or None for the
+    /// one-cell gutters between slices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if N is less than 2, or if min is greater than max along any
+    /// axis.
+    ///
+    /// [`from_plane`]: SparseGrid::from_plane
+    pub fn mosaic(&self, min: Point<N>, max: Point<N>) -> Grid<Option<bool>> {
+        assert!(N >= 2, "mosaic of {}-dimensional space", N);
+        let extents: Vec<usize> = (0..N)
+            .map(|axis| {
+                assert!(min[axis] <= max[axis], "empty box: {} to {}", min, max);
+                (max[axis] - min[axis] + 1) as usize
+            })
+            .collect();
+        let (width, height) = (extents[0], extents[1]);
+        let across = extents.get(2).copied().unwrap_or(1);
+        let down: usize = extents.iter().skip(3).product();
+        let total_height = down * (height + 1) - 1;
+        let total_width = across * (width + 1) - 1;
+        Grid::from_fn(total_height, total_width, |pos| {
+            let (tile_row, y) = (pos.row / (height + 1), pos.row % (height + 1));
+            let (tile_column, x) = (pos.column / (width + 1), pos.column % (width + 1));
+            if x == width || y == height {
+                return None;
+            }
+            let mut point = min;
+            point[0] += x as isize;
+            point[1] += y as isize;
+            if N > 2 {
+                point[2] += tile_column as isize;
+            }
+            let mut rest = tile_row;
+            for axis in 3..N {
+                point[axis] += (rest % extents[axis]) as isize;
+                rest /= extents[axis];
+            }
+            Some(self.contains(point))
+        })
+    }
+}
+
+impl<const N: usize> FromIterator<Point<N>> for SparseGrid<N> {
+    fn from_iter<I: IntoIterator<Item = Point<N>>>(points: I) -> Self {
+        SparseGrid {
+            active: points.into_iter().collect(),
+        }
+    }
+}
+
+impl<const N: usize> Extend<Point<N>> for SparseGrid<N> {
+    fn extend<I: IntoIterator<Item = Point<N>>>(&mut self, points: I) {
+        self.active.extend(points)
+    }
+}
+
+/// Shows the bounding box of the active cells as 2D slices ('#' active, '.'
+/// inactive) along the first two axes, separated by blank lines.  Slices are
+/// ordered by their remaining coordinates, with the third axis varying
+/// fastest.  An empty grid shows nothing.
+impl<const N: usize> Display for SparseGrid<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (min, max) = match self.bounds() {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+        let (rows, columns) = match N {
+            0 => (0..=0, 0..=0),
+            1 => (0..=0, min[0]..=max[0]),
+            _ => (min[1]..=max[1], min[0]..=max[0]),
+        };
+        let mut slice = min;
+        loop {
+            for y in rows.clone() {
+                for x in columns.clone() {
+                    if N > 0 {
+                        slice[0] = x;
+                    }
+                    if N > 1 {
+                        slice[1] = y;
+                    }
+                    write!(f, "{}", if self.contains(slice) { '#' } else { '.' })?;
+                }
+                if y != *rows.end() {
+                    writeln!(f)?;
+                }
+            }
+            // Advance the remaining coordinates like an odometer.
+            let mut axis = 2;
+            while axis < N && slice[axis] == max[axis] {
+                slice[axis] = min[axis];
+                axis += 1;
+            }
+            if axis >= N {
+                return Ok(());
+            }
+            slice[axis] += 1;
+            write!(f, "\n\n")?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn glider<const N: usize>() -> SparseGrid<N> {
+        let plane = Grid::parse(".#.\n..#\n###", |c| Ok(c == '#')).unwrap();
+        SparseGrid::from_plane(&plane, |&active| active)
+    }
+
+    #[test]
+    fn count_neighbors() {
+        let grid = glider::<3>();
+        assert_eq!(1, grid.count_neighbors(Point([0, 0, -1])));
+        assert_eq!(2, grid.count_neighbors(Point([1, 0, -1])));
+        assert_eq!(5, grid.count_neighbors(Point([1, 1, 1])));
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(None, SparseGrid::<3>::new().bounds());
+        let grid = glider::<4>();
+        assert_eq!(
+            Some((Point([0, 0, 0, 0]), Point([2, 2, 0, 0]))),
+            grid.bounds()
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(".#.\n..#\n###", glider::<2>().to_string());
+        assert_eq!(".#.\n..#\n###", glider::<5>().to_string());
+        let mut grid = SparseGrid::<3>::new();
+        grid.insert(Point([0, 0, 0]));
+        grid.insert(Point([1, 0, 1]));
+        assert_eq!("#.\n\n.#", grid.to_string());
+    }
+
+    #[test]
+    fn mosaic() {
+        let show = |mosaic: Grid<Option<bool>>| {
+            mosaic
+                .map(|cell| match cell {
+                    Some(true) => '#',
+                    Some(false) => '.',
+                    None => ' ',
+                })
+                .to_string()
+        };
+        let (min, max) = (Point([0, 0]), Point([2, 2]));
+        assert_eq!(".#.\n..#\n###\n", show(glider::<2>().mosaic(min, max)));
+        let mut grid = SparseGrid::<4>::new();
+        grid.insert(Point([0, 0, 0, 0]));
+        grid.insert(Point([1, 0, 1, 0]));
+        grid.insert(Point([0, 0, 0, 1]));
+        let mosaic = grid.mosaic(Point([0, 0, 0, 0]), Point([1, 0, 1, 1]));
+        assert_eq!("#. .#\n     \n#. ..\n", show(mosaic));
+    }
+}
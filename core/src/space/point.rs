@@ -0,0 +1,181 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, Index, IndexMut, Sub};
+
+/// A location in N-dimensional integer space.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point<const N: usize>(pub [isize; N]);
+
+impl<const N: usize> Point<N> {
+    pub const ORIGIN: Point<N> = Point([0; N]);
+
+    /// Returns the point at column x and row y of the plane through the
+    /// origin spanned by the first two axes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if N is less than 2.
+    pub fn on_plane(x: isize, y: isize) -> Point<N> {
+        assert!(N >= 2, "a plane needs at least 2 dimensions, not {}", N);
+        let mut point = Point::ORIGIN;
+        point.0[0] = x;
+        point.0[1] = y;
+        point
+    }
+
+    /// Returns the 3^N - 1 points adjacent to this one, including diagonally.
+    pub fn neighbors(self) -> Neighbors<N> {
+        Neighbors {
+            origin: self,
+            index: 0,
+            count: 3usize.pow(N as u32),
+        }
+    }
+
+    /// Returns the point whose every coordinate is the lesser of the
+    /// corresponding coordinates of this point and other.
+    pub fn min(self, other: Point<N>) -> Point<N> {
+        let mut point = self;
+        for (mine, &theirs) in point.0.iter_mut().zip(other.0.iter()) {
+            *mine = (*mine).min(theirs);
+        }
+        point
+    }
+
+    /// Returns the point whose every coordinate is the greater of the
+    /// corresponding coordinates of this point and other.
+    pub fn max(self, other: Point<N>) -> Point<N> {
+        let mut point = self;
+        for (mine, &theirs) in point.0.iter_mut().zip(other.0.iter()) {
+            *mine = (*mine).max(theirs);
+        }
+        point
+    }
+}
+
+impl<const N: usize> Default for Point<N> {
+    fn default() -> Self {
+        Point::ORIGIN
+    }
+}
+
+impl<const N: usize> Add for Point<N> {
+    type Output = Point<N>;
+
+    fn add(mut self, other: Point<N>) -> Self::Output {
+        for (mine, theirs) in self.0.iter_mut().zip(other.0.iter()) {
+            *mine += theirs;
+        }
+        self
+    }
+}
+
+impl<const N: usize> Sub for Point<N> {
+    type Output = Point<N>;
+
+    fn sub(mut self, other: Point<N>) -> Self::Output {
+        for (mine, theirs) in self.0.iter_mut().zip(other.0.iter()) {
+            *mine -= theirs;
+        }
+        self
+    }
+}
+
+impl<const N: usize> Index<usize> for Point<N> {
+    type Output = isize;
+
+    fn index(&self, axis: usize) -> &Self::Output {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Point<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut Self::Output {
+        &mut self.0[axis]
+    }
+}
+
+impl<const N: usize> Display for Point<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "(")?;
+        for (axis, coordinate) in self.0.iter().enumerate() {
+            if axis > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", coordinate)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Iterator over the points adjacent to some origin.  Offsets are counted in
+/// base 3, each digit giving one coordinate's offset (-1, 0, or 1); the
+/// middle count, whose digits are all 1, is the origin itself and is skipped.
+pub struct Neighbors<const N: usize> {
+    origin: Point<N>,
+    index: usize,
+    count: usize,
+}
+
+impl<const N: usize> Iterator for Neighbors<N> {
+    type Item = Point<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.count / 2 {
+            self.index += 1;
+        }
+        if self.index >= self.count {
+            return None;
+        }
+        let mut point = self.origin;
+        let mut digits = self.index;
+        for coordinate in point.0.iter_mut() {
+            *coordinate += (digits % 3) as isize - 1;
+            digits /= 3;
+        }
+        self.index += 1;
+        Some(point)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let skip = usize::from(self.index <= self.count / 2);
+        let len = self.count - self.index - skip;
+        (len, Some(len))
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for Neighbors<N> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn neighbors() {
+        assert_eq!(8, Point::<2>::ORIGIN.neighbors().count());
+        assert_eq!(26, Point::<3>::ORIGIN.neighbors().count());
+        assert_eq!(80, Point::<4>::ORIGIN.neighbors().len());
+        let center = Point([5, -2, 7]);
+        let neighbors: HashSet<_> = center.neighbors().collect();
+        assert_eq!(26, neighbors.len());
+        assert!(!neighbors.contains(&center));
+        assert!(neighbors.contains(&Point([4, -3, 6])));
+        assert!(neighbors.contains(&Point([6, -1, 8])));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let p = Point([1, 2, 3, 4]);
+        let q = Point([4, -3, 2, 1]);
+        assert_eq!(Point([5, -1, 5, 5]), p + q);
+        assert_eq!(Point([-3, 5, 1, 3]), p - q);
+        assert_eq!(Point([1, -3, 2, 1]), p.min(q));
+        assert_eq!(Point([4, 2, 3, 4]), p.max(q));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("(1, -2)", Point::<2>::on_plane(1, -2).to_string());
+        assert_eq!("(1, -2, 0)", Point::<3>::on_plane(1, -2).to_string());
+    }
+}
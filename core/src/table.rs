@@ -0,0 +1,43 @@
+//! Plain-text tables for command-line reports.
+
+/// Renders the specified rows with each column left-aligned and padded to the
+/// width of its widest cell.  The first row is typically a header.
+pub fn render<R, S>(rows: &[R]) -> String
+where
+    R: AsRef<[S]>,
+    S: AsRef<str>,
+{
+    let mut widths: Vec<usize> = Vec::new();
+    for row in rows {
+        for (i, cell) in row.as_ref().iter().enumerate() {
+            let width = cell.as_ref().chars().count();
+            match widths.get_mut(i) {
+                Some(max) => *max = (*max).max(width),
+                None => widths.push(width),
+            }
+        }
+    }
+    let mut text = String::new();
+    for row in rows {
+        let cells: Vec<_> = row
+            .as_ref()
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, &width)| format!("{:width$}", cell.as_ref(), width = width))
+            .collect();
+        text.push_str(cells.join("  ").trim_end());
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn aligned() {
+        let rows = [["a", "bb", "c"], ["dddd", "e", "f"]];
+        assert_eq!("a     bb  c\ndddd  e   f\n", render(&rows));
+    }
+}